@@ -134,11 +134,10 @@ impl ChainConfigs {
         self.chain_configs.clear();
     }
 
-    pub fn set_chain_configs_from_json(&mut self, new_json_data: String) {
-        self.assert_owner();
-        
+    // Replaces the chain configs without an access check, callers check the ConfigManager role or execute a governance proposal
+    pub(crate) fn apply_chain_configs_from_json(&mut self, new_json_data: String) {
         // Clear the current chain_configs
-        self.clear_chain_configs();
+        self.chain_configs.clear();
 
         // Log resetting action
         env::log_str("Resetting ChainConfigs from provided JSON data");
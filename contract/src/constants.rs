@@ -24,6 +24,28 @@ pub mod status {
     pub const BRG_ABTC_BURNT: u8 = 10;
     pub const BRG_ABTC_PENDING_BRIDGE_FROM_ORIGIN_TO_DEST: u8 = 11;
     pub const BRG_ABTC_MINTED_TO_DEST: u8 = 20;
+
+    // Validator reward claims
+    pub const REWARD_CLAIM_PENDING: u8 = 0;
+    pub const REWARD_CLAIM_SIGNED: u8 = 10;
+    pub const REWARD_CLAIM_COMPLETED: u8 = 20;
+    pub const REWARD_CLAIM_FAILED: u8 = 30;
+
+    // Dispute statuses
+    pub const DISPUTE_OPEN: u8 = 0;
+    pub const DISPUTE_DISMISSED: u8 = 10;
+    pub const DISPUTE_UPHELD: u8 = 20;
+
+    // Governance proposal statuses
+    pub const PROPOSAL_PENDING: u8 = 0;
+    pub const PROPOSAL_APPROVED: u8 = 10;
+    pub const PROPOSAL_EXECUTED: u8 = 20;
+    pub const PROPOSAL_REJECTED: u8 = 30;
+
+    // Timelocked operation statuses
+    pub const OPERATION_SCHEDULED: u8 = 0;
+    pub const OPERATION_EXECUTED: u8 = 10;
+    pub const OPERATION_CANCELLED: u8 = 20;
 }
 
 pub mod network_type {
@@ -34,17 +56,189 @@ pub mod network_type {
     pub const NEAR: &'static str = "NEAR";
 }
 
+pub mod evm_gas {
+    // A base fee posted by a validator counts toward the chain's median for 10 minutes
+    pub const BASE_FEE_MAX_AGE: u64 = 10 * 60;
+    // Default max_fee_per_gas is the median base fee times this multiplier plus the tip
+    pub const BASE_FEE_MULTIPLIER: u64 = 2;
+}
+
+pub mod mint_attempt {
+    // Kinds of EVM transactions signed for a deposit's mint
+    pub const MINT_ATTEMPT_MINT: &'static str = "mint";
+    pub const MINT_ATTEMPT_SPEED_UP: &'static str = "speed_up";
+    pub const MINT_ATTEMPT_CANCEL: &'static str = "cancel";
+    // Mempools only accept a replacement at the same nonce with fees at least 10% higher
+    pub const REPLACEMENT_FEE_BUMP_BPS: u128 = 1000;
+}
+
 pub mod delimiter {
     // delimiters
     pub const COMMA: &'static str = ",";
 }
 
+pub mod btc_light_client {
+    // BTC difficulty adjustment parameters
+    pub const BTC_RETARGET_INTERVAL: u64 = 2016;
+    pub const BTC_TARGET_TIMESPAN: u64 = 14 * 24 * 60 * 60; // two weeks in seconds
+    pub const BTC_MAINNET_POW_LIMIT_BITS: u32 = 0x1d00ffff;
+    pub const BTC_SIGNET_POW_LIMIT_BITS: u32 = 0x1e0377ae;
+    pub const BTC_MEDIAN_TIME_SPAN: usize = 11; // previous blocks a header time has to be later than the median of
+    pub const DEFAULT_BTC_CONFIRMATION_DEPTH: u64 = 6;
+}
+
+pub mod attestation {
+    // Key types a validator can register for off-chain attestations
+    pub const ATTESTATION_KEY_ED25519: &'static str = "ed25519";
+    pub const ATTESTATION_KEY_SECP256K1: &'static str = "secp256k1";
+    // Actions covered by an attestation, part of the signed message
+    pub const ATTEST_DEPOSIT_VERIFIED: &'static str = "deposit_verified";
+    pub const ATTEST_DEPOSIT_MINTED_TXN_HASH: &'static str = "deposit_minted_txn_hash";
+    pub const ATTEST_REDEMPTION_VERIFIED: &'static str = "redemption_verified";
+    pub const ATTEST_REDEMPTION_BTC_TXN_HASH: &'static str = "redemption_btc_txn_hash";
+}
+
+pub mod validator_bond {
+    pub const DEFAULT_UNBONDING_PERIOD: u64 = 7 * 24 * 60 * 60; // one week in seconds
+    pub const DEFAULT_CHALLENGER_REWARD_BPS: u16 = 5000; // 50% of the slashed bond
+    pub const BPS_DENOMINATOR: u128 = 10000;
+    pub const BOND_TRANSFER_MSG: &'static str = "bond"; // ft_transfer_call msg that locks atBTC as a bond
+    pub const REWARD_FUNDING_MSG: &'static str = "rewards"; // ft_transfer_call msg that funds validator rewards
+}
+
+pub mod validator_weight {
+    pub const DEFAULT_VALIDATOR_WEIGHT: u64 = 1;
+    // A weight threshold is either an absolute weight or a fraction of the chain's total weight in basis points
+    pub const WEIGHT_THRESHOLD_ABSOLUTE: &'static str = "absolute";
+    pub const WEIGHT_THRESHOLD_FRACTION: &'static str = "fraction";
+}
+
+pub mod vote_type {
+    // Kinds of validator votes kept in the validator_votes reverse index
+    pub const VOTE_DEPOSIT_VERIFIED: &'static str = "deposit_verified";
+    pub const VOTE_DEPOSIT_MINTED_TXN_HASH: &'static str = "deposit_minted_txn_hash";
+    pub const VOTE_REDEMPTION_VERIFIED: &'static str = "redemption_verified";
+    pub const VOTE_REDEMPTION_BTC_TXN_HASH: &'static str = "redemption_btc_txn_hash";
+    pub const VOTE_BRIDGING_VERIFIED: &'static str = "bridging_verified";
+    pub const VOTE_BRIDGING_DEST_TXN_HASH: &'static str = "bridging_dest_txn_hash";
+    pub const VOTE_EVM_BLOCK_HEADER: &'static str = "evm_block_header";
+}
+
+pub mod validator_stats {
+    pub const VALIDATOR_STATS_BUCKET_SECONDS: u64 = 60 * 60; // votes are counted in hourly buckets
+    pub const MAX_PARTICIPATION_WINDOW: u64 = 30 * 24 * 60 * 60; // 30 days in seconds
+    // Buckets kept per chain and per validator, a bucket is overwritten once it falls out of the longest window
+    pub const VOTE_BUCKET_SLOTS: u64 = MAX_PARTICIPATION_WINDOW / VALIDATOR_STATS_BUCKET_SECONDS;
+}
+
+pub mod dispute {
+    // Record types that can be disputed
+    pub const DISPUTE_RECORD_DEPOSIT: &'static str = "deposit";
+    pub const DISPUTE_RECORD_REDEMPTION: &'static str = "redemption";
+    pub const DISPUTE_RECORD_BRIDGING: &'static str = "bridging";
+}
+
+pub mod governance {
+    // Council types
+    pub const COUNCIL_VALIDATORS: &'static str = "validators"; // every bonded validator of any chain
+    pub const COUNCIL_MEMBERS: &'static str = "members"; // the council_members list
+
+    // Proposal types, the proposal value is parsed according to its type
+    pub const PROPOSAL_SET_MPC_CONTRACT: &'static str = "set_mpc_contract"; // account ID
+    pub const PROPOSAL_UPDATE_FEE_DEPOSIT_BPS: &'static str = "update_fee_deposit_bps"; // basis points
+    pub const PROPOSAL_UPDATE_FEE_REDEMPTION_BPS: &'static str = "update_fee_redemption_bps";
+    pub const PROPOSAL_UPDATE_FEE_BRIDGING_BPS: &'static str = "update_fee_bridging_bps";
+    pub const PROPOSAL_UPDATE_FEE_BABYLON_REWARDS_BPS: &'static str = "update_fee_babylon_rewards_bps";
+    pub const PROPOSAL_UPDATE_FEE_VALIDATOR_REWARDS_BPS: &'static str = "update_fee_validator_rewards_bps";
+    pub const PROPOSAL_UPDATE_BTC_STAKING_CAP: &'static str = "update_btc_staking_cap"; // sats
+    pub const PROPOSAL_UPDATE_BTC_MAX_STAKING_AMOUNT: &'static str = "update_btc_max_staking_amount";
+    pub const PROPOSAL_UPDATE_BTC_MIN_STAKING_AMOUNT: &'static str = "update_btc_min_staking_amount";
+    pub const PROPOSAL_UPDATE_TREASURY_ADDRESS: &'static str = "update_treasury_address"; // address string
+    pub const PROPOSAL_UPDATE_BTC_ATLAS_DEPOSIT_ADDRESS: &'static str = "update_btc_atlas_deposit_address";
+    pub const PROPOSAL_SET_CHAIN_CONFIGS: &'static str = "set_chain_configs"; // chain configs JSON
+    pub const PROPOSAL_SET_GOVERNANCE_PARAMS: &'static str = "set_governance_params"; // GovernanceParams JSON
+}
+
+pub mod timelock {
+    // Operations that are not governance proposal types, the others reuse the proposal type and value format
+    pub const OPERATION_UPDATE_CONTRACT: &'static str = "update_contract"; // hex sha256 of the contract code
+    pub const OPERATION_SET_OPERATION_DELAY: &'static str = "set_operation_delay"; // <operation_type>,<seconds>
+}
+
+pub mod role {
+    // Roles granted by the owner, an account can hold several
+    pub const ROLE_PAUSE_GUARDIAN: &'static str = "PauseGuardian"; // pause and unpause
+    pub const ROLE_DEPOSIT_OPERATOR: &'static str = "DepositOperator"; // deposit and bridging records and minting
+    pub const ROLE_REDEMPTION_OPERATOR: &'static str = "RedemptionOperator"; // redemption records
+    pub const ROLE_REFUND_OPERATOR: &'static str = "RefundOperator"; // refunds of failed deposits
+    pub const ROLE_CONFIG_MANAGER: &'static str = "ConfigManager"; // chain configs, MPC contract and global params other than fees
+    pub const ROLE_FEE_MANAGER: &'static str = "FeeManager"; // fee basis points
+    pub const ROLE_UPGRADER: &'static str = "Upgrader"; // update_contract
+
+    pub const ROLES: [&'static str; 7] = [
+        ROLE_PAUSE_GUARDIAN,
+        ROLE_DEPOSIT_OPERATOR,
+        ROLE_REDEMPTION_OPERATOR,
+        ROLE_REFUND_OPERATOR,
+        ROLE_CONFIG_MANAGER,
+        ROLE_FEE_MANAGER,
+        ROLE_UPGRADER,
+    ];
+}
+
+pub mod pause_scope {
+    // Flows that can be paused on their own, on every chain or on a single chain
+    pub const PAUSE_DEPOSITS: &'static str = "deposits"; // chain: receiving chain
+    pub const PAUSE_REDEMPTIONS: &'static str = "redemptions"; // chain: redemption chain
+    pub const PAUSE_BRIDGING: &'static str = "bridging"; // chain: destination chain
+    pub const PAUSE_REFUNDS: &'static str = "refunds"; // chain: receiving chain of the refunded deposit
+    pub const PAUSE_MINTING: &'static str = "minting"; // chain: chain the atBTC is minted on
+    pub const PAUSE_VALIDATOR_VOTING: &'static str = "validator_voting"; // chain: chain the validator votes for
+
+    pub const PAUSE_SCOPES: [&'static str; 6] = [
+        PAUSE_DEPOSITS,
+        PAUSE_REDEMPTIONS,
+        PAUSE_BRIDGING,
+        PAUSE_REFUNDS,
+        PAUSE_MINTING,
+        PAUSE_VALIDATOR_VOTING,
+    ];
+}
+
+pub mod mint_limit {
+    // Rolling windows mints are counted over, in seconds
+    pub const MINT_HOURLY_WINDOW: u64 = 3600;
+    pub const MINT_DAILY_WINDOW: u64 = 86400;
+}
+
+pub mod reserve_attestation {
+    // EIP-712 domain and type of the signed reserves statement, without chainId so it verifies on any EVM chain
+    // The salt is keccak256 of the NEAR account of the contract, so the domain is bound to this deployment
+    pub const EIP712_DOMAIN_TYPE: &'static str = "EIP712Domain(string name,string version,bytes32 salt)";
+    pub const EIP712_DOMAIN_NAME: &'static str = "Atlas";
+    pub const EIP712_DOMAIN_VERSION: &'static str = "1";
+    pub const RESERVE_ATTESTATION_TYPE: &'static str = "ReserveAttestation(uint256 deposited,uint256 redeemed,uint256 refunded,uint256 minted,uint256 burned,uint256 blockHeight,uint256 timestamp)";
+}
+
+pub mod events {
+    // NEP-297 event standard emitted by the Atlas contract
+    pub const EVENT_STANDARD: &'static str = "atlas";
+    pub const EVENT_STANDARD_VERSION: &'static str = "1.0.0";
+}
+
 pub mod near_gas {
     use near_sdk::{Gas, NearToken};
 
     pub const SIGN_CALLBACK_GAS: Gas = Gas::from_tgas(10);
     pub const GAS_FOR_STORAGE_DEPOSIT: Gas = Gas::from_tgas(10); // Gas for storage deposit call
     pub const GAS_FOR_MINT_CALL: Gas = Gas::from_tgas(100); // Gas for minting call
+    pub const GAS_FOR_FT_TRANSFER: Gas = Gas::from_tgas(10); // Gas for returning bonded atBTC
+    pub const GAS_FOR_BOND_TRANSFER_CALLBACK: Gas = Gas::from_tgas(10); // Gas for restoring bonded atBTC that failed to transfer
+    pub const GAS_FOR_REWARD_CLAIM_CALLBACK: Gas = Gas::from_tgas(10); // Gas for settling a reward claim
+    pub const GAS_FOR_BRIDGING_MINT_CALLBACK: Gas = Gas::from_tgas(10); // Gas for settling a NEAR bridge mint
+    pub const GAS_FOR_FT_TOTAL_SUPPLY: Gas = Gas::from_tgas(5); // Gas for reading the NEAR atBTC supply
+    pub const GAS_FOR_SUPPLY_RECONCILIATION_CALLBACK: Gas = Gas::from_tgas(10); // Gas for comparing the supply with the recorded supply
+    pub const ONE_YOCTO: NearToken = NearToken::from_yoctonear(1); // Required attachment for ft_transfer
     pub const MIN_STORAGE_DEPOSIT: NearToken = NearToken::from_yoctonear(1250000000000000000000);
     // 0.00125 NEAR in yoctoNEAR
 }
@@ -11,15 +11,34 @@ pub struct GlobalParams {
     fee_redemption_bps: u16,
     fee_bridging_bps: u16,
     fee_babylon_rewards_bps: u16,
+    fee_validator_rewards_bps: u16, // share of collected fees credited to validators
     btc_staking_cap: u64,
     btc_max_staking_amount: u64,
     btc_min_staking_amount: u64,
     treasury_address: String,
+    btc_atlas_deposit_address: String,
     owner_id: AccountId,
     proposed_owner_id: Option<AccountId>, // Proposed owner for two-step ownership transfer
     max_retry_count: u8,
 }
 
+// Layout of GlobalParams before fee_validator_rewards_bps and btc_atlas_deposit_address, read by Atlas::migrate
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OldGlobalParams {
+    pub mpc_contract: AccountId,
+    pub fee_deposit_bps: u16,
+    pub fee_redemption_bps: u16,
+    pub fee_bridging_bps: u16,
+    pub fee_babylon_rewards_bps: u16,
+    pub btc_staking_cap: u64,
+    pub btc_max_staking_amount: u64,
+    pub btc_min_staking_amount: u64,
+    pub treasury_address: String,
+    pub owner_id: AccountId,
+    pub proposed_owner_id: Option<AccountId>,
+    pub max_retry_count: u8,
+}
+
 impl GlobalParams {
     pub fn init_global_params(owner_id: AccountId, treasury_address: String) -> Self {
         // Validate inputs
@@ -39,10 +58,12 @@ impl GlobalParams {
             fee_redemption_bps: 0,
             fee_bridging_bps: 0,
             fee_babylon_rewards_bps: 0,
+            fee_validator_rewards_bps: 0,
             btc_staking_cap: 50000000000,
             btc_max_staking_amount: 5000000,
             btc_min_staking_amount: 2000,
             treasury_address: treasury_address,
+            btc_atlas_deposit_address: "".to_string(),
             owner_id: owner_id,
             proposed_owner_id: None,
             max_retry_count: 3,
@@ -102,12 +123,12 @@ impl GlobalParams {
     }
 
     // Getter and Setter methods
+    // The apply_* setters skip access checks, callers check the FeeManager or ConfigManager role or execute a governance proposal
     pub fn get_mpc_contract(&self) -> AccountId {
         self.mpc_contract.clone()
     }
 
-    pub fn set_mpc_contract(&mut self, new_mpc_contract: AccountId) {
-        self.assert_owner();
+    pub(crate) fn apply_mpc_contract(&mut self, new_mpc_contract: AccountId) {
         assert!(
             !new_mpc_contract.to_string().is_empty(),
             "Invalid MPC contract ID"
@@ -126,8 +147,7 @@ impl GlobalParams {
         ));
     }
 
-    pub fn update_fee_deposit_bps(&mut self, fee_deposit_bps: u16) {
-        self.assert_owner();
+    pub(crate) fn apply_fee_deposit_bps(&mut self, fee_deposit_bps: u16) {
         // setting max fee to 3%
         assert!(
             fee_deposit_bps <= 300,
@@ -136,8 +156,7 @@ impl GlobalParams {
         self.fee_deposit_bps = fee_deposit_bps;
     }
 
-    pub fn update_fee_redemption_bps(&mut self, fee_redemption_bps: u16) {
-        self.assert_owner();
+    pub(crate) fn apply_fee_redemption_bps(&mut self, fee_redemption_bps: u16) {
         // setting max fee to 3%
         assert!(
             fee_redemption_bps <= 300,
@@ -146,8 +165,7 @@ impl GlobalParams {
         self.fee_redemption_bps = fee_redemption_bps;
     }
 
-    pub fn update_fee_bridging_bps(&mut self, fee_bridging_bps: u16) {
-        self.assert_owner();
+    pub(crate) fn apply_fee_bridging_bps(&mut self, fee_bridging_bps: u16) {
         // setting max fee to 3%
         assert!(
             fee_bridging_bps <= 300,
@@ -156,8 +174,7 @@ impl GlobalParams {
         self.fee_bridging_bps = fee_bridging_bps;
     }
 
-    pub fn update_fee_babylon_rewards_bps(&mut self, fee_babylon_rewards_bps: u16) {
-        self.assert_owner();
+    pub(crate) fn apply_fee_babylon_rewards_bps(&mut self, fee_babylon_rewards_bps: u16) {
         // setting max fee to 10%
         assert!(
             fee_babylon_rewards_bps <= 1000,
@@ -166,29 +183,65 @@ impl GlobalParams {
         self.fee_babylon_rewards_bps = fee_babylon_rewards_bps;
     }
 
-    pub fn update_btc_staking_cap(&mut self, btc_staking_cap: u64) {
-        self.assert_owner();
+    pub(crate) fn apply_fee_validator_rewards_bps(&mut self, fee_validator_rewards_bps: u16) {
+        // share of the fee, up to all of it
+        assert!(
+            fee_validator_rewards_bps <= 10000,
+            "Invalid fee: must be between 0 and 10000 basis points"
+        );
+        self.fee_validator_rewards_bps = fee_validator_rewards_bps;
+    }
+
+    pub fn get_fee_redemption_bps(&self) -> u16 {
+        self.fee_redemption_bps
+    }
+
+    pub fn get_fee_bridging_bps(&self) -> u16 {
+        self.fee_bridging_bps
+    }
+
+    pub fn get_max_retry_count(&self) -> u8 {
+        self.max_retry_count
+    }
+
+    pub fn get_fee_validator_rewards_bps(&self) -> u16 {
+        self.fee_validator_rewards_bps
+    }
+
+    pub(crate) fn apply_btc_staking_cap(&mut self, btc_staking_cap: u64) {
         self.btc_staking_cap = btc_staking_cap;
     }
 
-    pub fn update_btc_max_staking_amount(&mut self, btc_max_staking_amount: u64) {
-        self.assert_owner();
+    pub(crate) fn apply_btc_max_staking_amount(&mut self, btc_max_staking_amount: u64) {
         self.btc_max_staking_amount = btc_max_staking_amount;
     }
 
-    pub fn update_btc_min_staking_amount(&mut self, btc_min_staking_amount: u64) {
-        self.assert_owner();
+    pub(crate) fn apply_btc_min_staking_amount(&mut self, btc_min_staking_amount: u64) {
         self.btc_min_staking_amount = btc_min_staking_amount;
     }
 
-    pub fn update_treasury_address(&mut self, treasury_address: String) {
-        self.assert_owner();
+    pub(crate) fn apply_treasury_address(&mut self, treasury_address: String) {
         assert!(!treasury_address.is_empty(), "Invalid treasury address");
         self.treasury_address = treasury_address;
     }
 
-    pub fn update_max_retry_count(&mut self, max_retry_count: u8) {
-        self.assert_owner();
+    pub fn get_treasury_address(&self) -> String {
+        self.treasury_address.clone()
+    }
+
+    pub fn get_btc_atlas_deposit_address(&self) -> String {
+        self.btc_atlas_deposit_address.clone()
+    }
+
+    pub(crate) fn apply_btc_atlas_deposit_address(&mut self, btc_atlas_deposit_address: String) {
+        assert!(
+            !btc_atlas_deposit_address.is_empty(),
+            "Invalid BTC atlas deposit address"
+        );
+        self.btc_atlas_deposit_address = btc_atlas_deposit_address;
+    }
+
+    pub(crate) fn apply_max_retry_count(&mut self, max_retry_count: u8) {
         assert!(
             max_retry_count > 0,
             "Max retry count must be greater than ZERO"
@@ -199,4 +252,24 @@ impl GlobalParams {
     pub fn owner_id(&self) -> &AccountId {
         &self.owner_id
     }
+
+    // Carries params stored in the old layout over, validator rewards and the deposit address start unset
+    pub fn migrate_global_params(old: OldGlobalParams) -> Self {
+        Self {
+            mpc_contract: old.mpc_contract,
+            fee_deposit_bps: old.fee_deposit_bps,
+            fee_redemption_bps: old.fee_redemption_bps,
+            fee_bridging_bps: old.fee_bridging_bps,
+            fee_babylon_rewards_bps: old.fee_babylon_rewards_bps,
+            fee_validator_rewards_bps: 0,
+            btc_staking_cap: old.btc_staking_cap,
+            btc_max_staking_amount: old.btc_max_staking_amount,
+            btc_min_staking_amount: old.btc_min_staking_amount,
+            treasury_address: old.treasury_address,
+            btc_atlas_deposit_address: "".to_string(),
+            owner_id: old.owner_id,
+            proposed_owner_id: old.proposed_owner_id,
+            max_retry_count: old.max_retry_count,
+        }
+    }
 }
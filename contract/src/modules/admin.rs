@@ -1,11 +1,19 @@
 use crate::atlas::Atlas;
 use crate::chain_configs::ChainConfigs;
+use crate::constants::btc_light_client::DEFAULT_BTC_CONFIRMATION_DEPTH;
+use crate::constants::role::*;
+use crate::constants::validator_bond::*;
 use crate::constants::near_gas::*;
 use crate::global_params::GlobalParams;
 use crate::modules::signer::*;
+use crate::modules::structs::{
+    GovernanceParams, MintLimitRecord, OldAtlas, ReserveTotalsRecord, RewardPoolRecord,
+    ValidatorBondParams,
+};
 use crate::AtlasExt;
 use hex::FromHex;
 use near_sdk::env::keccak256;
+use near_sdk::json_types::U128;
 use near_sdk::{
     env, log, near_bindgen, store::IterableMap, AccountId, Gas, NearToken, Promise, PromiseError,
     PromiseOrValue,
@@ -52,7 +60,7 @@ impl Atlas {
             "Chain configs owner ID cannot be empty"
         );
 
-        Self {
+        let mut atlas = Self {
             deposits: IterableMap::new(b"d"),
             redemptions: IterableMap::new(b"r"),
             owner_id: atlas_owner_id,
@@ -66,10 +74,66 @@ impl Atlas {
             chain_configs: ChainConfigs::init_chain_configs(chain_configs_owner_id),
             validators: IterableMap::new(b"v"),
             verifications: IterableMap::new(b"f"),
+            btc_headers: IterableMap::new(b"h"),
+            btc_main_chain: IterableMap::new(b"m"),
+            btc_deposit_proofs: IterableMap::new(b"p"),
+            evm_headers: IterableMap::new(b"e"),
+            evm_receipt_proofs: IterableMap::new(b"x"),
+            attestation_keys: IterableMap::new(b"k"),
+            validator_bonds: IterableMap::new(b"b"),
+            validator_weights: IterableMap::new(b"w"),
+            weight_thresholds: IterableMap::new(b"t"),
+            verified_weights: IterableMap::new(b"g"),
+            validator_votes: IterableMap::new(b"o"),
+            validator_stats: IterableMap::new(b"s"),
+            vote_buckets: IterableMap::new(b"u"),
+            validator_rewards: IterableMap::new(b"a"),
+            reward_claims: IterableMap::new(b"q"),
+            dispute_windows: IterableMap::new(b"l"),
+            dispute_window_starts: IterableMap::new(b"j"),
+            disputes: IterableMap::new(b"y"),
+            proposals: IterableMap::new(b"n"),
+            operation_delays: IterableMap::new(b"i"),
+            scheduled_operations: IterableMap::new(b"z"),
+            roles: IterableMap::new(b"R"),
+            paused_scopes: IterableMap::new(b"P"),
+            mint_limits: IterableMap::new(b"M"),
+            mint_volumes: IterableMap::new(b"V"),
+            mint_allowances: IterableMap::new(b"L"),
+            chain_reserves: IterableMap::new(b"S"),
+            evm_nonces: IterableMap::new(b"N"),
+            bridgings: IterableMap::new(b"B"),
+            evm_admin_functions: IterableMap::new(b"A"),
+            evm_base_fees: IterableMap::new(b"F"),
+            mint_attempts: IterableMap::new(b"T"),
+            evm_gas_bounds: IterableMap::new(b"G"),
+            evm_cosigned_headers: IterableMap::new(b"H"),
             last_evm_tx: None, // Initialize with None
             paused: false,
             production_mode: production_mode,
-        }
+            btc_tip_block_hash: "".to_string(),
+            btc_confirmation_depth: DEFAULT_BTC_CONFIRMATION_DEPTH,
+            validator_bond_params: ValidatorBondParams {
+                min_near_bond: U128(0),
+                min_abtc_bond: U128(0),
+                unbonding_period: DEFAULT_UNBONDING_PERIOD,
+                challenger_reward_bps: DEFAULT_CHALLENGER_REWARD_BPS,
+            },
+            slashed_near_amount: U128(0),
+            slashed_abtc_amount: U128(0),
+            reward_pool: RewardPoolRecord::default(),
+            governance_params: GovernanceParams::default(),
+            global_mint_limits: MintLimitRecord::default(),
+            reserve_totals: ReserveTotalsRecord::default(),
+            reserve_attestation: None,
+            supply_drift_threshold: 0,
+            proposal_nonce: 0,
+            operation_nonce: 0,
+        };
+
+        atlas.grant_default_roles();
+
+        atlas
     }
 
     // Atlas owner functions
@@ -115,6 +179,9 @@ impl Atlas {
             self.owner_id, caller
         ));
 
+        let previous_owner_id = self.owner_id.clone();
+        self.transfer_roles(&previous_owner_id, &caller);
+
         self.owner_id = caller;
         self.proposed_owner_id = None;
     }
@@ -167,6 +234,9 @@ impl Atlas {
             self.admin_id, caller
         ));
 
+        let previous_admin_id = self.admin_id.clone();
+        self.transfer_roles(&previous_admin_id, &caller);
+
         self.admin_id = caller;
         self.proposed_admin_id = None;
     }
@@ -192,14 +262,14 @@ impl Atlas {
 
     // Function to pause the contract
     pub fn pause(&mut self) {
-        self.assert_owner(); // Only the owner can pause the contract
+        self.assert_role(ROLE_PAUSE_GUARDIAN);
         self.paused = true;
         env::log_str("Contract is paused");
     }
 
-    // Function to unpause the contract
+    // Function to unpause the contract, pause guardians can pause but only the owner unpauses
     pub fn unpause(&mut self) {
-        self.assert_owner(); // Only the owner can unpause the contract
+        self.assert_owner();
         self.paused = false;
         env::log_str("Contract is unpaused");
     }
@@ -222,75 +292,23 @@ impl Atlas {
     pub fn create_abtc_accept_ownership_tx(
         &mut self,
         chain_id: String,
-        nonce: u64,
         gas: u128,
-        max_fee_per_gas: u128,
-        max_priority_fee_per_gas: u128,
+        max_fee_per_gas: Option<u128>,
+        max_priority_fee_per_gas: Option<u128>,
     ) -> PromiseOrValue<String> {
         self.assert_not_paused();
         self.assert_owner();
 
-        // Validate input parameters
-        assert!(!chain_id.is_empty(), "Chain ID cannot be empty");
-        assert!(gas != 0, "Gas cannot be zero");
-
-        if !self
-            .chain_configs
-            .get_chain_config(chain_id.clone())
-            .is_some()
-        {
-            env::panic_str("Chain ID not found");
-        }
-
-        let chain_config = self
-            .chain_configs
-            .get_chain_config(chain_id.clone())
-            .expect("Chain ID not found");
-
-        let to_address_str = chain_config.abtc_address.strip_prefix("0x").unwrap();
-        let to_address = parse_eth_address(to_address_str);
-        let value_as_128 = 0;
-
         let data: Vec<u8> = Self::encode_abtc_accept_ownership_function_call();
 
-        let evm_tx = OmniTransactionBuilder::new::<EVM>()
-            .nonce(nonce)
-            .to(to_address)
-            .value(value_as_128)
-            .input(data.clone())
-            .max_priority_fee_per_gas(max_priority_fee_per_gas)
-            .max_fee_per_gas(max_fee_per_gas)
-            .gas_limit(gas)
-            .chain_id(chain_id.parse::<u64>().unwrap_or_else(|_| {
-                // Handle the error case, e.g., log an error and provide a default value
-                env::panic_str("Invalid chain ID format.");
-            }))
-            .build();
-
-        let evm_tx_encoded = evm_tx.build_for_signing();
-
-        let evm_tx_hash = keccak256(&evm_tx_encoded);
-        log!("Payload: [{}] {:?}", evm_tx_hash.len(), evm_tx_hash);
-
-        let path = chain_config.network_type.clone();
-        // Call MPC
-        return PromiseOrValue::Promise(
-            ext_signer::ext(self.global_params.get_mpc_contract())
-                .with_attached_deposit(NearToken::from_millinear(500))
-                .sign(SignRequest::new(
-                    evm_tx_hash
-                        .try_into()
-                        .unwrap_or_else(|e| panic!("Failed to convert payload {:?}", e)),
-                    path,
-                    0,
-                ))
-                .then(
-                    Self::ext(env::current_account_id())
-                        .with_static_gas(SIGN_CALLBACK_GAS)
-                        .with_unused_gas_weight(0)
-                        .sign_transfer_ownership_callback(evm_tx),
-                ),
-        );
+        self.sign_abtc_call(
+            chain_id,
+            data,
+            "acceptOwnership()",
+            gas,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        )
     }
 
     // Helper function to encode the accept_ownership function call
@@ -342,14 +360,17 @@ impl Atlas {
         }
     }
 
-    pub fn update_contract(&self) -> Promise {
-        self.assert_owner();
+    pub fn update_contract(&mut self) -> Promise {
+        self.assert_role(ROLE_UPGRADER);
 
         // Receive the code directly from the input to avoid the
         // GAS overhead of deserializing parameters
         let code = env::input().expect("Code must be provide").to_vec();
         assert_ne!(code.len(), 0, "Code must not be empty");
 
+        // With a delay set, the code has to match an update scheduled through schedule_operation
+        self.consume_scheduled_contract_update(&code);
+
         // Deploy the contract on self
         Promise::new(env::current_account_id())
             .deploy_contract(code)
@@ -365,23 +386,157 @@ impl Atlas {
     #[private]
     #[init(ignore_state)]
     pub fn migrate() -> Self {
-        let old_state: Atlas = env::state_read().expect("failed");
-        // add migration code here if we need it
+        let old_state: OldAtlas = env::state_read().expect("failed");
 
-        Self {
+        // Everything added since the old layout starts out the way new() initializes it
+        let mut atlas = Self {
             deposits: old_state.deposits,
             redemptions: old_state.redemptions,
             owner_id: old_state.owner_id,
             proposed_owner_id: old_state.proposed_owner_id,
             admin_id: old_state.admin_id,
             proposed_admin_id: old_state.proposed_admin_id,
-            global_params: old_state.global_params,
+            global_params: GlobalParams::migrate_global_params(old_state.global_params),
             chain_configs: old_state.chain_configs,
             validators: old_state.validators,
             verifications: old_state.verifications,
+            btc_headers: IterableMap::new(b"h"),
+            btc_main_chain: IterableMap::new(b"m"),
+            btc_deposit_proofs: IterableMap::new(b"p"),
+            evm_headers: IterableMap::new(b"e"),
+            evm_receipt_proofs: IterableMap::new(b"x"),
+            attestation_keys: IterableMap::new(b"k"),
+            validator_bonds: IterableMap::new(b"b"),
+            validator_weights: IterableMap::new(b"w"),
+            weight_thresholds: IterableMap::new(b"t"),
+            verified_weights: IterableMap::new(b"g"),
+            validator_votes: IterableMap::new(b"o"),
+            validator_stats: IterableMap::new(b"s"),
+            vote_buckets: IterableMap::new(b"u"),
+            validator_rewards: IterableMap::new(b"a"),
+            reward_claims: IterableMap::new(b"q"),
+            dispute_windows: IterableMap::new(b"l"),
+            dispute_window_starts: IterableMap::new(b"j"),
+            disputes: IterableMap::new(b"y"),
+            proposals: IterableMap::new(b"n"),
+            operation_delays: IterableMap::new(b"i"),
+            scheduled_operations: IterableMap::new(b"z"),
+            roles: IterableMap::new(b"R"),
+            paused_scopes: IterableMap::new(b"P"),
+            mint_limits: IterableMap::new(b"M"),
+            mint_volumes: IterableMap::new(b"V"),
+            mint_allowances: IterableMap::new(b"L"),
+            chain_reserves: IterableMap::new(b"S"),
+            evm_nonces: IterableMap::new(b"N"),
+            bridgings: IterableMap::new(b"B"),
+            evm_admin_functions: IterableMap::new(b"A"),
+            evm_base_fees: IterableMap::new(b"F"),
+            mint_attempts: IterableMap::new(b"T"),
+            evm_gas_bounds: IterableMap::new(b"G"),
+            evm_cosigned_headers: IterableMap::new(b"H"),
             last_evm_tx: old_state.last_evm_tx,
             paused: old_state.paused,
             production_mode: old_state.production_mode,
+            btc_tip_block_hash: "".to_string(),
+            btc_confirmation_depth: DEFAULT_BTC_CONFIRMATION_DEPTH,
+            validator_bond_params: ValidatorBondParams {
+                min_near_bond: U128(0),
+                min_abtc_bond: U128(0),
+                unbonding_period: DEFAULT_UNBONDING_PERIOD,
+                challenger_reward_bps: DEFAULT_CHALLENGER_REWARD_BPS,
+            },
+            slashed_near_amount: U128(0),
+            slashed_abtc_amount: U128(0),
+            reward_pool: RewardPoolRecord::default(),
+            governance_params: GovernanceParams::default(),
+            global_mint_limits: MintLimitRecord::default(),
+            reserve_totals: ReserveTotalsRecord::default(),
+            reserve_attestation: None,
+            supply_drift_threshold: 0,
+            proposal_nonce: 0,
+            operation_nonce: 0,
+        };
+
+        // The old layout predates roles, its owner accounts keep the permissions they had
+        atlas.grant_default_roles();
+
+        atlas
+    }
+}
+
+impl Atlas {
+    // Signs a call with the given calldata to the atBTC contract of chain_id, returned signed by sign_transfer_ownership_callback
+    // The nonce is handed out by next_evm_nonce and kept pending under record_key
+    pub(crate) fn sign_abtc_call(
+        &mut self,
+        chain_id: String,
+        data: Vec<u8>,
+        record_key: &str,
+        gas: u128,
+        max_fee_per_gas: Option<u128>,
+        max_priority_fee_per_gas: Option<u128>,
+    ) -> PromiseOrValue<String> {
+        // Validate input parameters
+        assert!(!chain_id.is_empty(), "Chain ID cannot be empty");
+        assert!(gas != 0, "Gas cannot be zero");
+
+        if !self
+            .chain_configs
+            .get_chain_config(chain_id.clone())
+            .is_some()
+        {
+            env::panic_str("Chain ID not found");
         }
+
+        let chain_config = self
+            .chain_configs
+            .get_chain_config(chain_id.clone())
+            .expect("Chain ID not found");
+
+        let to_address_str = chain_config.abtc_address.strip_prefix("0x").unwrap();
+        let to_address = parse_eth_address(to_address_str);
+        let value_as_128 = 0;
+        let (max_fee_per_gas, max_priority_fee_per_gas) =
+            self.resolve_evm_fees(&chain_config, gas, max_fee_per_gas, max_priority_fee_per_gas);
+        let nonce = self.next_evm_nonce(&chain_id, &chain_config.network_type, record_key);
+
+        let evm_tx = OmniTransactionBuilder::new::<EVM>()
+            .nonce(nonce)
+            .to(to_address)
+            .value(value_as_128)
+            .input(data)
+            .max_priority_fee_per_gas(max_priority_fee_per_gas)
+            .max_fee_per_gas(max_fee_per_gas)
+            .gas_limit(gas)
+            .chain_id(chain_id.parse::<u64>().unwrap_or_else(|_| {
+                // Handle the error case, e.g., log an error and provide a default value
+                env::panic_str("Invalid chain ID format.");
+            }))
+            .build();
+
+        let evm_tx_encoded = evm_tx.build_for_signing();
+
+        let evm_tx_hash = keccak256(&evm_tx_encoded);
+        log!("Payload: [{}] {:?}", evm_tx_hash.len(), evm_tx_hash);
+
+        let path = chain_config.network_type.clone();
+        // Call MPC
+        return PromiseOrValue::Promise(
+            ext_signer::ext(self.global_params.get_mpc_contract())
+                .with_attached_deposit(NearToken::from_millinear(500))
+                .sign(SignRequest::new(
+                    evm_tx_hash
+                        .try_into()
+                        .unwrap_or_else(|e| panic!("Failed to convert payload {:?}", e)),
+                    path,
+                    0,
+                ))
+                .then(
+                    Self::ext(env::current_account_id())
+                        .with_static_gas(SIGN_CALLBACK_GAS)
+                        .with_unused_gas_weight(0)
+                        .sign_transfer_ownership_callback(evm_tx),
+                ),
+        );
     }
 }
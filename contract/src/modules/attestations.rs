@@ -0,0 +1,329 @@
+use crate::atlas::Atlas;
+use crate::constants::attestation::*;
+use crate::modules::structs::{
+    AttestationKeyRecord, DepositRecord, RedemptionRecord, ValidatorAttestation,
+    VerificationResult,
+};
+use crate::AtlasExt;
+use near_sdk::borsh;
+use near_sdk::{env, log, near_bindgen, AccountId};
+
+// Builds the canonical message for an attestation and returns its sha256 hash
+// The message is the borsh encoding of (contract_id, action, fields), strings are length-prefixed so fields cannot run into each other
+// The contract account id is part of the message so an attestation cannot be replayed against another deployment
+fn attestation_hash(contract_id: &AccountId, action: &str, fields: &[String]) -> Vec<u8> {
+    let message = borsh::to_vec(&(contract_id.as_str(), action, fields))
+        .unwrap_or_else(|_| env::panic_str("Failed to encode attestation message"));
+    env::sha256(&message)
+}
+
+fn deposit_attestation_fields(deposit: &DepositRecord) -> Vec<String> {
+    vec![
+        deposit.btc_txn_hash.clone(),
+        deposit.btc_sender_address.clone(),
+        deposit.receiving_chain_id.clone(),
+        deposit.receiving_address.clone(),
+        deposit.btc_amount.to_string(),
+        deposit.fee_amount.to_string(),
+        deposit.timestamp.to_string(),
+        deposit.remarks.clone(),
+    ]
+}
+
+fn redemption_attestation_fields(redemption: &RedemptionRecord) -> Vec<String> {
+    vec![
+        redemption.txn_hash.clone(),
+        redemption.abtc_redemption_address.clone(),
+        redemption.abtc_redemption_chain_id.clone(),
+        redemption.btc_receiving_address.clone(),
+        redemption.abtc_amount.to_string(),
+        redemption.timestamp.to_string(),
+        redemption.remarks.clone(),
+    ]
+}
+
+// Checks a signature over message_hash against the registered attestation key
+fn verify_attestation_signature(key: &AttestationKeyRecord, message_hash: &[u8], signature: &[u8]) -> bool {
+    let public_key = match hex::decode(&key.public_key) {
+        Ok(public_key) => public_key,
+        Err(_) => return false,
+    };
+
+    match key.key_type.as_str() {
+        ATTESTATION_KEY_ED25519 => {
+            let (Ok(signature), Ok(public_key)) = (
+                <[u8; 64]>::try_from(signature),
+                <[u8; 32]>::try_from(public_key.as_slice()),
+            ) else {
+                return false;
+            };
+            env::ed25519_verify(&signature, message_hash, &public_key)
+        }
+        ATTESTATION_KEY_SECP256K1 => {
+            if signature.len() != 65 {
+                return false;
+            }
+            // Accept both 0/1 and 27/28 recovery ids
+            let v = if signature[64] >= 27 { signature[64] - 27 } else { signature[64] };
+            match env::ecrecover(message_hash, &signature[..64], v, true) {
+                Some(recovered) => recovered.as_slice() == public_key.as_slice(),
+                None => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+#[near_bindgen]
+impl Atlas {
+    // Registers or replaces the caller's off-chain attestation key
+    // Caller of this function has to be an authorized validator for at least one chain
+    // key_type is ed25519 (32 byte public key) or secp256k1 (64 byte uncompressed point, an optional 04 prefix is stripped)
+    pub fn register_attestation_key(&mut self, key_type: String, public_key: String) {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+        assert!(
+            self.validators.get(&caller).is_some(),
+            "Only a validator can register an attestation key"
+        );
+
+        let mut public_key_bytes =
+            hex::decode(public_key.trim_start_matches("0x")).expect("Invalid public key hex");
+
+        match key_type.as_str() {
+            ATTESTATION_KEY_ED25519 => {
+                assert!(public_key_bytes.len() == 32, "Invalid ed25519 public key length");
+            }
+            ATTESTATION_KEY_SECP256K1 => {
+                if public_key_bytes.len() == 65 && public_key_bytes[0] == 0x04 {
+                    public_key_bytes.remove(0);
+                }
+                assert!(public_key_bytes.len() == 64, "Invalid secp256k1 public key length");
+            }
+            _ => env::panic_str("Invalid attestation key type"),
+        }
+
+        self.attestation_keys.insert(
+            caller.clone(),
+            AttestationKeyRecord {
+                key_type: key_type.clone(),
+                public_key: hex::encode(public_key_bytes),
+            },
+        );
+
+        log!("Registered {} attestation key for validator {}", key_type, caller);
+    }
+
+    // Removes the attestation key of a validator, callable by the validator itself or the owner
+    pub fn remove_attestation_key(&mut self, account_id: AccountId) {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+        assert!(
+            caller == account_id || caller == self.owner_id,
+            "Only the validator or the owner can remove an attestation key"
+        );
+
+        if self.attestation_keys.remove(&account_id).is_none() {
+            log!("No attestation key registered for validator {}", account_id);
+        }
+    }
+
+    pub fn get_attestation_key(&self, account_id: AccountId) -> Option<AttestationKeyRecord> {
+        self.attestation_keys.get(&account_id).cloned()
+    }
+
+    // Canonical hashes validators sign off-chain, returned as hex
+    pub fn get_deposit_attestation_hash(&self, mempool_deposit: DepositRecord) -> String {
+        hex::encode(attestation_hash(
+            &env::current_account_id(),
+            ATTEST_DEPOSIT_VERIFIED,
+            &deposit_attestation_fields(&mempool_deposit),
+        ))
+    }
+
+    pub fn get_deposit_minted_txn_hash_attestation_hash(
+        &self,
+        btc_txn_hash: String,
+        minted_txn_hash: String,
+    ) -> String {
+        hex::encode(attestation_hash(
+            &env::current_account_id(),
+            ATTEST_DEPOSIT_MINTED_TXN_HASH,
+            &[btc_txn_hash, minted_txn_hash],
+        ))
+    }
+
+    pub fn get_redemption_attestation_hash(&self, mempool_redemption: RedemptionRecord) -> String {
+        hex::encode(attestation_hash(
+            &env::current_account_id(),
+            ATTEST_REDEMPTION_VERIFIED,
+            &redemption_attestation_fields(&mempool_redemption),
+        ))
+    }
+
+    pub fn get_redemption_btc_txn_hash_attestation_hash(
+        &self,
+        txn_hash: String,
+        btc_txn_hash: String,
+    ) -> String {
+        hex::encode(attestation_hash(
+            &env::current_account_id(),
+            ATTEST_REDEMPTION_BTC_TXN_HASH,
+            &[txn_hash, btc_txn_hash],
+        ))
+    }
+
+    // Relayed version of increment_deposit_verified_count
+    // Any account can submit the bundle, each attestation counts as a vote of its validator once its signature is verified
+    // Returns one result per attestation, keyed by validator account id
+    pub fn submit_deposit_attestations(
+        &mut self,
+        mempool_deposit: DepositRecord,
+        attestations: Vec<ValidatorAttestation>,
+    ) -> Vec<VerificationResult> {
+        self.assert_not_paused();
+
+        let message_hash = attestation_hash(
+            &env::current_account_id(),
+            ATTEST_DEPOSIT_VERIFIED,
+            &deposit_attestation_fields(&mempool_deposit),
+        );
+
+        attestations
+            .into_iter()
+            .map(|attestation| {
+                let outcome = self
+                    .check_attestation(&attestation, &message_hash)
+                    .and_then(|_| {
+                        self.apply_deposit_verification(&attestation.validator_id, mempool_deposit.clone())
+                    });
+                VerificationResult::from_outcome(attestation.validator_id.to_string(), outcome)
+            })
+            .collect()
+    }
+
+    // Relayed version of increment_deposit_minted_txn_hash_verified_count
+    pub fn submit_deposit_minted_txn_hash_attestations(
+        &mut self,
+        btc_txn_hash: String,
+        minted_txn_hash: String,
+        attestations: Vec<ValidatorAttestation>,
+    ) -> Vec<VerificationResult> {
+        self.assert_not_paused();
+
+        let message_hash = attestation_hash(
+            &env::current_account_id(),
+            ATTEST_DEPOSIT_MINTED_TXN_HASH,
+            &[btc_txn_hash.clone(), minted_txn_hash.clone()],
+        );
+
+        attestations
+            .into_iter()
+            .map(|attestation| {
+                let outcome = self
+                    .check_attestation(&attestation, &message_hash)
+                    .and_then(|_| {
+                        self.apply_deposit_minted_txn_hash_verification(
+                            &attestation.validator_id,
+                            btc_txn_hash.clone(),
+                            minted_txn_hash.clone(),
+                        )
+                    });
+                VerificationResult::from_outcome(attestation.validator_id.to_string(), outcome)
+            })
+            .collect()
+    }
+
+    // Relayed version of increment_redemption_verified_count
+    pub fn submit_redemption_attestations(
+        &mut self,
+        mempool_redemption: RedemptionRecord,
+        attestations: Vec<ValidatorAttestation>,
+    ) -> Vec<VerificationResult> {
+        self.assert_not_paused();
+
+        let message_hash = attestation_hash(
+            &env::current_account_id(),
+            ATTEST_REDEMPTION_VERIFIED,
+            &redemption_attestation_fields(&mempool_redemption),
+        );
+
+        attestations
+            .into_iter()
+            .map(|attestation| {
+                let outcome = self
+                    .check_attestation(&attestation, &message_hash)
+                    .and_then(|_| {
+                        self.apply_redemption_verification(
+                            &attestation.validator_id,
+                            mempool_redemption.clone(),
+                        )
+                    });
+                VerificationResult::from_outcome(attestation.validator_id.to_string(), outcome)
+            })
+            .collect()
+    }
+
+    // Relayed version of increment_redemption_btc_txn_hash_verified_count
+    pub fn submit_redemption_btc_txn_hash_attestations(
+        &mut self,
+        txn_hash: String,
+        btc_txn_hash: String,
+        attestations: Vec<ValidatorAttestation>,
+    ) -> Vec<VerificationResult> {
+        self.assert_not_paused();
+
+        let message_hash = attestation_hash(
+            &env::current_account_id(),
+            ATTEST_REDEMPTION_BTC_TXN_HASH,
+            &[txn_hash.clone(), btc_txn_hash.clone()],
+        );
+
+        attestations
+            .into_iter()
+            .map(|attestation| {
+                let outcome = self
+                    .check_attestation(&attestation, &message_hash)
+                    .and_then(|_| {
+                        self.apply_redemption_btc_txn_hash_verification(
+                            &attestation.validator_id,
+                            txn_hash.clone(),
+                            btc_txn_hash.clone(),
+                        )
+                    });
+                VerificationResult::from_outcome(attestation.validator_id.to_string(), outcome)
+            })
+            .collect()
+    }
+
+    // Verifies an attestation against the validator's registered key, returns the reason if it is rejected
+    fn check_attestation(
+        &self,
+        attestation: &ValidatorAttestation,
+        message_hash: &[u8],
+    ) -> Result<(), String> {
+        let key = self
+            .attestation_keys
+            .get(&attestation.validator_id)
+            .ok_or_else(|| {
+                format!(
+                    "No attestation key registered for validator {}",
+                    attestation.validator_id
+                )
+            })?;
+
+        let signature = hex::decode(attestation.signature.trim_start_matches("0x"))
+            .map_err(|_| format!("Invalid signature hex from validator {}", attestation.validator_id))?;
+
+        if !verify_attestation_signature(key, message_hash, &signature) {
+            return Err(format!(
+                "Invalid attestation signature from validator {}",
+                attestation.validator_id
+            ));
+        }
+
+        Ok(())
+    }
+}
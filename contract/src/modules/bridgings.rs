@@ -0,0 +1,745 @@
+use crate::atlas::Atlas;
+use crate::constants::delimiter::COMMA;
+use crate::constants::mint_attempt::MINT_ATTEMPT_MINT;
+use crate::constants::near_gas::*;
+use crate::constants::network_type::*;
+use crate::constants::pause_scope::*;
+use crate::constants::role::*;
+use crate::constants::status::*;
+use crate::constants::vote_type::*;
+use crate::modules::structs::{BridgingRecord, MintAttemptRecord};
+use crate::AtlasExt;
+use crate::VerificationResult;
+use ethers_core::types::{H160, U256};
+use near_sdk::env::keccak256;
+use near_sdk::json_types::U128;
+use near_sdk::{env, log, near_bindgen, AccountId, NearToken, Promise, PromiseError, PromiseOrValue};
+use omni_transaction::evm::utils::parse_eth_address;
+use omni_transaction::transaction_builder::{
+    TransactionBuilder as OmniTransactionBuilder, TxBuilder,
+};
+use omni_transaction::types::EVM as OmniEVM;
+use serde_json::json;
+use std::str::FromStr;
+
+#[near_bindgen]
+impl Atlas {
+    // Records a burnBridge of abtc_amount on origin_chain_id, to be minted less the bridging fee on dest_chain_id
+    pub fn insert_bridging_abtc(
+        &mut self,
+        txn_hash: String,
+        origin_chain_id: String,
+        origin_chain_address: String,
+        dest_chain_id: String,
+        dest_chain_address: String,
+        abtc_amount: u64,
+        timestamp: u64,
+        date_created: u64,
+    ) {
+        self.assert_not_paused();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
+        self.assert_scope_not_paused(PAUSE_BRIDGING, &dest_chain_id);
+
+        // Input validation
+        assert!(
+            txn_hash.starts_with(&format!("{}{}", origin_chain_id, COMMA)),
+            "Transaction hash must start with the origin chain ID"
+        );
+        assert!(
+            !origin_chain_address.is_empty(),
+            "Origin chain address cannot be empty"
+        );
+        assert!(
+            !dest_chain_address.is_empty(),
+            "Destination chain address cannot be empty"
+        );
+        assert!(
+            origin_chain_id != dest_chain_id,
+            "Origin and destination chain IDs must differ"
+        );
+        assert!(abtc_amount > 0, "atBTC amount must be greater than zero");
+        assert!(timestamp > 0, "Timestamp must be greater than zero");
+        assert!(date_created > 0, "Date created must be greater than zero");
+
+        for chain_id in [&origin_chain_id, &dest_chain_id] {
+            let chain_config = self
+                .chain_configs
+                .get_chain_config(chain_id.clone())
+                .expect("Chain ID not found");
+            assert!(
+                chain_config.network_type == EVM || chain_config.network_type == NEAR,
+                "atBTC can only be bridged between EVM and NEAR chains"
+            );
+        }
+
+        // Check for existing bridging
+        assert!(
+            self.bridgings.get(&txn_hash).is_none(),
+            "Bridging with this transaction hash already exists"
+        );
+
+        let fee_amount =
+            abtc_amount * self.global_params.get_fee_bridging_bps() as u64 / 10000;
+
+        let record = BridgingRecord {
+            txn_hash: txn_hash.clone(),
+            origin_chain_id,
+            origin_chain_address,
+            dest_chain_id,
+            dest_chain_address,
+            dest_txn_hash: "".to_string(),
+            abtc_amount,
+            fee_amount,
+            timestamp,
+            status: BRG_ABTC_BURNT,
+            remarks: "".to_string(),
+            date_created,
+            verified_count: 0,
+            retry_count: 0,
+            dest_txn_hash_verified_count: 0,
+        };
+
+        self.update_chain_reserve(&record.origin_chain_id, |reserve| {
+            reserve.burned += record.abtc_amount
+        });
+        self.bridgings.insert(txn_hash, record);
+    }
+
+    pub fn get_bridging_by_txn_hash(&self, txn_hash: String) -> Option<BridgingRecord> {
+        assert!(!txn_hash.is_empty(), "Transaction hash cannot be empty");
+
+        self.bridgings.get(&txn_hash).cloned()
+    }
+
+    pub fn get_bridgings_count(&self) -> u64 {
+        self.bridgings.len() as u64
+    }
+
+    // Increments bridging record's verified_count by 1
+    // Caller of this function has to be an authorized validator for the origin chain of the bridging record
+    pub fn increment_bridging_verified_count(&mut self, mempool_bridging: BridgingRecord) -> bool {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+
+        match self.apply_bridging_verification(&caller, mempool_bridging) {
+            Ok(()) => true,
+            Err(reason) => {
+                log!("{}", reason);
+                false
+            }
+        }
+    }
+
+    // Batch version of increment_bridging_verified_count, one result per mempool_bridging in the same order
+    pub fn batch_increment_bridging_verified_count(
+        &mut self,
+        mempool_bridgings: Vec<BridgingRecord>,
+    ) -> Vec<VerificationResult> {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+
+        mempool_bridgings
+            .into_iter()
+            .map(|mempool_bridging| {
+                let key = mempool_bridging.txn_hash.clone();
+                VerificationResult::from_outcome(
+                    key,
+                    self.apply_bridging_verification(&caller, mempool_bridging),
+                )
+            })
+            .collect()
+    }
+
+    // Signs the mintBridge transaction of a verified bridging to an EVM chain through the MPC signer
+    // Gated like create_mint_abtc_signed_tx: the burn has to reach the origin chain's threshold and is signed once
+    // The mint counts toward the destination supply once its txn hash is verified, see update_bridging_minted
+    pub fn create_bridging_abtc_signed_tx(
+        &mut self,
+        txn_hash: String,
+        gas: u128,
+        max_fee_per_gas: Option<u128>,
+        max_priority_fee_per_gas: Option<u128>,
+    ) -> PromiseOrValue<String> {
+        self.assert_not_paused();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
+
+        assert!(gas != 0, "Gas cannot be zero");
+
+        let mut bridging = match self.get_bridging_ready_to_mint(&txn_hash) {
+            Ok(bridging) => bridging,
+            Err(reason) => return PromiseOrValue::Value(reason),
+        };
+
+        let dest_chain_config = match self
+            .chain_configs
+            .get_chain_config(bridging.dest_chain_id.clone())
+        {
+            Some(chain_config) => chain_config,
+            None => return PromiseOrValue::Value("Chain config not found.".to_string()),
+        };
+        assert!(
+            dest_chain_config.network_type == EVM,
+            "Destination chain is not an EVM chain"
+        );
+        assert!(
+            Self::is_valid_eth_address(bridging.dest_chain_address.clone()),
+            "Invalid destination EVM address"
+        );
+
+        // A bridge mint over the destination chain's mint limits trips the circuit breaker instead of being signed
+        if !self.record_mint_volume(&bridging.dest_chain_id, &txn_hash, bridging.abtc_amount - bridging.fee_amount) {
+            return PromiseOrValue::Value("Mint limit exceeded, minting is paused.".to_string());
+        }
+
+        let to_address = parse_eth_address(dest_chain_config.abtc_address.trim_start_matches("0x"));
+        let data = Self::encode_bridging_mint_function_call(&bridging);
+        let (max_fee_per_gas, max_priority_fee_per_gas) = self.resolve_evm_fees(
+            &dest_chain_config,
+            gas,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        );
+        let nonce = self.next_evm_nonce(
+            &bridging.dest_chain_id,
+            &dest_chain_config.network_type,
+            &bridging.txn_hash,
+        );
+
+        let evm_tx = OmniTransactionBuilder::new::<OmniEVM>()
+            .nonce(nonce)
+            .to(to_address)
+            .value(0)
+            .input(data)
+            .max_priority_fee_per_gas(max_priority_fee_per_gas)
+            .max_fee_per_gas(max_fee_per_gas)
+            .gas_limit(gas)
+            .chain_id(
+                bridging
+                    .dest_chain_id
+                    .parse::<u64>()
+                    .unwrap_or_else(|_| env::panic_str("Invalid chain ID format.")),
+            )
+            .build();
+
+        let current_timestamp = env::block_timestamp() / 1_000_000_000;
+        bridging.status = BRG_ABTC_PENDING_BRIDGE_FROM_ORIGIN_TO_DEST;
+        bridging.timestamp = current_timestamp;
+        self.bridgings.insert(txn_hash.clone(), bridging.clone());
+
+        let attempt = MintAttemptRecord {
+            attempt_type: MINT_ATTEMPT_MINT.to_string(),
+            chain_id: bridging.dest_chain_id.clone(),
+            nonce,
+            gas: U128(gas),
+            max_fee_per_gas: U128(max_fee_per_gas),
+            max_priority_fee_per_gas: U128(max_priority_fee_per_gas),
+            tx_hash: String::new(),
+            from_address: String::new(),
+            evm_tx: String::new(),
+            signed_at: current_timestamp,
+        };
+
+        // The signed transaction is kept as a mint attempt of the bridging so it can be sped up or cancelled
+        PromiseOrValue::Promise(self.sign_mint_attempt(
+            txn_hash,
+            attempt,
+            evm_tx,
+            dest_chain_config.network_type,
+        ))
+    }
+
+    // Mints a verified bridging to NEAR through storage_deposit and mint_bridge on the NEAR atBTC token
+    // A failed mint is put back to be retried until max_retry_count, see bridging_mint_callback
+    pub fn create_bridging_abtc_near_mint(&mut self, txn_hash: String) -> PromiseOrValue<String> {
+        self.assert_not_paused();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
+
+        let mut bridging = match self.get_bridging_ready_to_mint(&txn_hash) {
+            Ok(bridging) => bridging,
+            Err(reason) => return PromiseOrValue::Value(reason),
+        };
+
+        let dest_chain_config = match self
+            .chain_configs
+            .get_chain_config(bridging.dest_chain_id.clone())
+        {
+            Some(chain_config) => chain_config,
+            None => return PromiseOrValue::Value("Chain config not found.".to_string()),
+        };
+        assert!(
+            dest_chain_config.network_type == NEAR,
+            "Destination chain is not a NEAR chain"
+        );
+        let abtc_account_id =
+            AccountId::from_str(&dest_chain_config.abtc_address).expect("Invalid NEAR account ID");
+        let receiver_id = AccountId::from_str(&bridging.dest_chain_address)
+            .expect("Invalid destination NEAR account ID");
+
+        // A bridge mint over the NEAR chain's mint limits trips the circuit breaker instead of being called
+        if !self.record_mint_volume(&bridging.dest_chain_id, &txn_hash, bridging.abtc_amount - bridging.fee_amount) {
+            return PromiseOrValue::Value("Mint limit exceeded, minting is paused.".to_string());
+        }
+
+        let origin_txn_hash = bridging.txn_hash[bridging.origin_chain_id.len() + COMMA.len()..].to_string();
+        let mint_amount = bridging.abtc_amount - bridging.fee_amount;
+
+        bridging.status = BRG_ABTC_PENDING_BRIDGE_FROM_ORIGIN_TO_DEST;
+        bridging.timestamp = env::block_timestamp() / 1_000_000_000;
+        self.bridgings.insert(txn_hash.clone(), bridging.clone());
+
+        let storage_deposit_args = json!({
+            "account_id": receiver_id
+        })
+        .to_string()
+        .into_bytes();
+
+        let mint_args = json!({
+            "account_id": receiver_id,
+            "amount": mint_amount.to_string(),
+            "origin_chain_id": bridging.origin_chain_id,
+            "origin_chain_address": bridging.origin_chain_address,
+            "origin_txn_hash": origin_txn_hash
+        })
+        .to_string()
+        .into_bytes();
+
+        PromiseOrValue::Promise(
+            Promise::new(abtc_account_id.clone())
+                .function_call(
+                    "storage_deposit".to_string(),
+                    storage_deposit_args,
+                    MIN_STORAGE_DEPOSIT,
+                    GAS_FOR_STORAGE_DEPOSIT,
+                )
+                .then(Promise::new(abtc_account_id).function_call(
+                    "mint_bridge".to_string(),
+                    mint_args,
+                    NearToken::from_yoctonear(0),
+                    GAS_FOR_MINT_CALL,
+                ))
+                .then(
+                    Self::ext(env::current_account_id())
+                        .with_static_gas(GAS_FOR_BRIDGING_MINT_CALLBACK)
+                        .bridging_mint_callback(txn_hash),
+                ),
+        )
+    }
+
+    // Settles a NEAR bridge mint, the bridging is minted on success and put back to BRG_ABTC_BURNT on failure
+    // Once max_retry_count mints have failed the bridging keeps a remark and is no longer minted until it is looked into
+    #[private]
+    pub fn bridging_mint_callback(
+        &mut self,
+        txn_hash: String,
+        #[callback_result] result: Result<(), PromiseError>,
+    ) {
+        let mut bridging = self
+            .bridgings
+            .get(&txn_hash)
+            .cloned()
+            .expect("Bridging not found");
+
+        if result.is_ok() {
+            bridging.status = BRG_ABTC_MINTED_TO_DEST;
+            bridging.timestamp = env::block_timestamp() / 1_000_000_000;
+            self.update_chain_reserve(&bridging.dest_chain_id, |reserve| {
+                reserve.minted += bridging.abtc_amount - bridging.fee_amount
+            });
+        } else {
+            bridging.status = BRG_ABTC_BURNT;
+            bridging.retry_count += 1;
+            if bridging.retry_count >= self.global_params.get_max_retry_count() {
+                bridging.remarks = format!("NEAR mint_bridge failed {} times", bridging.retry_count);
+            }
+            log!("NEAR mint_bridge failed for bridging {}, attempt {}", txn_hash, bridging.retry_count);
+        }
+
+        self.bridgings.insert(txn_hash, bridging);
+    }
+
+    // Records the txn hash of an EVM bridge mint, it has to be the hash of one of the bridging's mint or speed-up attempts
+    pub fn update_bridging_dest_txn_hash(&mut self, txn_hash: String, dest_txn_hash: String) {
+        self.assert_not_paused();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
+
+        assert!(!txn_hash.is_empty(), "Transaction hash cannot be empty");
+        assert!(!dest_txn_hash.is_empty(), "Destination transaction hash cannot be empty");
+
+        let mut bridging = self
+            .bridgings
+            .get(&txn_hash)
+            .cloned()
+            .expect("Bridging record not found");
+
+        if bridging.status == BRG_ABTC_PENDING_BRIDGE_FROM_ORIGIN_TO_DEST
+            && bridging.remarks.is_empty()
+            && bridging.dest_txn_hash.is_empty()
+            && self.is_mint_attempt_txn_hash(&txn_hash, &dest_txn_hash)
+        {
+            bridging.dest_txn_hash = dest_txn_hash.clone();
+            self.bridgings.insert(txn_hash.clone(), bridging);
+            log!("dest txn hash: {} updated for bridging: {}", dest_txn_hash, txn_hash);
+        } else {
+            log!(
+                "Conditions not met for updating dest txn hash for bridging: {}. Status: {}, Remarks: {}, Dest txn hash: {}",
+                txn_hash,
+                bridging.status,
+                bridging.remarks,
+                bridging.dest_txn_hash
+            );
+        }
+    }
+
+    // Caller of this function has to be a new validator of this <txn_hash>,<dest_txn_hash> on the destination chain
+    // Returns true if dest_txn_hash_verified_count incremented successfully and returns false if not incremented
+    pub fn increment_bridging_dest_txn_hash_verified_count(&mut self, txn_hash: String, dest_txn_hash: String) -> bool {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+
+        match self.apply_bridging_dest_txn_hash_verification(&caller, txn_hash, dest_txn_hash) {
+            Ok(()) => true,
+            Err(reason) => {
+                log!("{}", reason);
+                false
+            }
+        }
+    }
+
+    // Batch version of increment_bridging_dest_txn_hash_verified_count taking (txn_hash, dest_txn_hash) pairs
+    pub fn batch_increment_bridging_dest_txn_hash_verified_count(
+        &mut self,
+        dest_txn_hashes: Vec<(String, String)>,
+    ) -> Vec<VerificationResult> {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+
+        dest_txn_hashes
+            .into_iter()
+            .map(|(txn_hash, dest_txn_hash)| {
+                let key = format!("{}{}{}", txn_hash, COMMA, dest_txn_hash);
+                VerificationResult::from_outcome(
+                    key,
+                    self.apply_bridging_dest_txn_hash_verification(&caller, txn_hash, dest_txn_hash),
+                )
+            })
+            .collect()
+    }
+
+    // Completes an EVM bridge mint once the destination chain's validators have verified its txn hash
+    // The minted atBTC only counts toward the destination supply from here on
+    pub fn update_bridging_minted(&mut self, txn_hash: String, dest_txn_hash: String) {
+        self.assert_not_paused();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
+
+        assert!(!txn_hash.is_empty(), "Transaction hash cannot be empty");
+        assert!(!dest_txn_hash.is_empty(), "Destination transaction hash cannot be empty");
+
+        let mut bridging = self
+            .bridgings
+            .get(&txn_hash)
+            .cloned()
+            .expect("Bridging record not found");
+        let dest_chain_config = self
+            .chain_configs
+            .get_chain_config(bridging.dest_chain_id.clone())
+            .expect("Chain configuration not found for destination chain ID");
+
+        if bridging.status == BRG_ABTC_PENDING_BRIDGE_FROM_ORIGIN_TO_DEST
+            && bridging.remarks.is_empty()
+            && bridging.dest_txn_hash == dest_txn_hash
+            && self.is_bridging_mint_verified(&bridging, dest_chain_config.validators_threshold)
+        {
+            bridging.status = BRG_ABTC_MINTED_TO_DEST;
+            bridging.timestamp = env::block_timestamp() / 1_000_000_000;
+            self.update_chain_reserve(&bridging.dest_chain_id, |reserve| {
+                reserve.minted += bridging.abtc_amount - bridging.fee_amount
+            });
+            self.bridgings.insert(txn_hash.clone(), bridging);
+            log!("Bridging status updated to BRG_ABTC_MINTED_TO_DEST for txn_hash: {}", txn_hash);
+        } else {
+            log!(
+                "Conditions not met for updating bridging minted status for txn_hash: {}. Status: {}, Remarks: {}, Dest txn hash: {}, Dest txn hash verified count: {}",
+                txn_hash,
+                bridging.status,
+                bridging.remarks,
+                bridging.dest_txn_hash,
+                bridging.dest_txn_hash_verified_count
+            );
+        }
+    }
+
+    pub fn update_bridging_remarks(&mut self, txn_hash: String, remarks: String) {
+        self.assert_not_paused();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
+
+        assert!(!txn_hash.is_empty(), "Transaction hash cannot be empty");
+        assert!(!remarks.trim().is_empty(), "Remarks cannot be blank");
+
+        let mut bridging = self
+            .bridgings
+            .get(&txn_hash)
+            .cloned()
+            .expect("Bridging record not found");
+        assert!(
+            bridging.status != BRG_ABTC_MINTED_TO_DEST,
+            "Cannot update remarks of a minted bridging"
+        );
+
+        bridging.remarks = remarks;
+        self.bridgings.insert(txn_hash.clone(), bridging);
+        log!("Remarks updated for bridging: {}", txn_hash);
+    }
+
+    // Puts a bridging with remarks whose mint did not land back to BRG_ABTC_BURNT, to be signed again at a new nonce
+    // A signed EVM mint has to be cancelled first so the old transaction can no longer mint twice
+    pub fn rollback_bridging_status_by_txn_hash(&mut self, txn_hash: String) {
+        self.assert_not_paused();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
+
+        assert!(!txn_hash.is_empty(), "Transaction hash cannot be empty");
+
+        let mut bridging = self
+            .bridgings
+            .get(&txn_hash)
+            .cloned()
+            .expect("Bridging record not found");
+        assert!(
+            bridging.status == BRG_ABTC_PENDING_BRIDGE_FROM_ORIGIN_TO_DEST
+                && !bridging.remarks.is_empty()
+                && bridging.dest_txn_hash.is_empty(),
+            "Bridging cannot be rolled back"
+        );
+        assert!(
+            bridging.retry_count < self.global_params.get_max_retry_count(),
+            "Bridging has used up its retries"
+        );
+
+        assert!(
+            self.is_mint_rollback_allowed(&txn_hash),
+            "Signed mint has to be cancelled before the bridging is rolled back"
+        );
+
+        bridging.status = BRG_ABTC_BURNT;
+        bridging.retry_count += 1;
+        bridging.remarks.clear();
+        self.bridgings.insert(txn_hash.clone(), bridging);
+        log!("Bridging {} rolled back to BRG_ABTC_BURNT", txn_hash);
+    }
+}
+
+impl Atlas {
+    // Returns the bridging if it can be minted on its destination chain, or the reason it cannot
+    fn get_bridging_ready_to_mint(&self, txn_hash: &str) -> Result<BridgingRecord, String> {
+        assert!(!txn_hash.is_empty(), "Transaction hash cannot be empty");
+
+        let bridging = self
+            .bridgings
+            .get(txn_hash)
+            .cloned()
+            .ok_or_else(|| "Bridging not found.".to_string())?;
+        if bridging.status != BRG_ABTC_BURNT
+            || !bridging.remarks.is_empty()
+            || !bridging.dest_txn_hash.is_empty()
+        {
+            return Err("Bridging not found or invalid conditions.".to_string());
+        }
+        self.assert_scope_not_paused(PAUSE_BRIDGING, &bridging.dest_chain_id);
+        self.assert_scope_not_paused(PAUSE_MINTING, &bridging.dest_chain_id);
+
+        let origin_chain_config = self
+            .chain_configs
+            .get_chain_config(bridging.origin_chain_id.clone())
+            .ok_or_else(|| "Chain config not found.".to_string())?;
+        if !self.is_bridging_burn_verified(&bridging, origin_chain_config.validators_threshold) {
+            return Err("Validators threshold not met.".to_string());
+        }
+        if !self.is_bridging_dispute_window_passed(&bridging) {
+            return Err("Bridging is disputed or within its dispute window.".to_string());
+        }
+
+        Ok(bridging)
+    }
+
+    // Applies the caller's vote for increment_bridging_verified_count, returns the reason if the vote is rejected
+    pub(crate) fn apply_bridging_verification(
+        &mut self,
+        caller: &AccountId,
+        mempool_bridging: BridgingRecord,
+    ) -> Result<(), String> {
+        let mut bridging = self
+            .bridgings
+            .get(&mempool_bridging.txn_hash)
+            .cloned()
+            .ok_or_else(|| {
+                format!(
+                    "Bridging record not found for txn_hash: {}.",
+                    &mempool_bridging.txn_hash
+                )
+            })?;
+        let chain_id = bridging.origin_chain_id.clone();
+        self.check_validator_voting_not_paused(&chain_id)?;
+
+        if !self.is_validator(caller, &chain_id) {
+            return Err(format!(
+                "Caller {} is not an authorized validator for the chain ID: {}",
+                caller, &chain_id
+            ));
+        }
+
+        let mut validators_list = self.get_validators_by_txn_hash(bridging.txn_hash.clone());
+        if validators_list.contains(caller) {
+            return Err(format!(
+                "Caller {} has already verified the transaction with txn_hash: {}.",
+                caller, &bridging.txn_hash
+            ));
+        }
+
+        // Verify that all fields of bridging and mempool_bridging are equal
+        if bridging.origin_chain_address != mempool_bridging.origin_chain_address
+            || bridging.dest_chain_id != mempool_bridging.dest_chain_id
+            || bridging.dest_chain_address != mempool_bridging.dest_chain_address
+            || bridging.abtc_amount != mempool_bridging.abtc_amount
+            || bridging.timestamp != mempool_bridging.timestamp
+            || bridging.status != BRG_ABTC_BURNT
+            || bridging.remarks != mempool_bridging.remarks
+        {
+            return Err("Mismatch between near_bridging and mempool_bridging records. Verification failed.".to_string());
+        }
+
+        bridging.verified_count += 1;
+        self.bridgings.insert(mempool_bridging.txn_hash.clone(), bridging);
+
+        validators_list.push(caller.clone());
+        self.verifications
+            .insert(mempool_bridging.txn_hash.clone(), validators_list);
+        self.record_validator_vote(
+            caller,
+            VOTE_BRIDGING_VERIFIED,
+            &mempool_bridging.txn_hash,
+            &mempool_bridging.txn_hash,
+            &chain_id,
+        );
+
+        Ok(())
+    }
+
+    pub fn is_bridging_burn_verified(&self, bridging: &BridgingRecord, validators_threshold: u8) -> bool {
+        self.is_verification_threshold_met(
+            &bridging.txn_hash,
+            &bridging.origin_chain_id,
+            bridging.verified_count,
+            validators_threshold,
+        )
+    }
+
+    pub fn is_bridging_mint_verified(&self, bridging: &BridgingRecord, validators_threshold: u8) -> bool {
+        self.is_verification_threshold_met(
+            &format!("{}{}{}", bridging.txn_hash, COMMA, bridging.dest_txn_hash),
+            &bridging.dest_chain_id,
+            bridging.dest_txn_hash_verified_count,
+            validators_threshold,
+        )
+    }
+
+    // Applies the caller's vote for increment_bridging_dest_txn_hash_verified_count, returns the reason if the vote is rejected
+    pub(crate) fn apply_bridging_dest_txn_hash_verification(
+        &mut self,
+        caller: &AccountId,
+        txn_hash: String,
+        dest_txn_hash: String,
+    ) -> Result<(), String> {
+        if txn_hash.is_empty() || dest_txn_hash.is_empty() {
+            return Err("Invalid input: txn_hash or dest_txn_hash is empty".to_string());
+        }
+
+        let mut bridging = self
+            .bridgings
+            .get(&txn_hash)
+            .cloned()
+            .ok_or_else(|| format!("Bridging record not found for txn_hash: {}.", &txn_hash))?;
+        let chain_id = bridging.dest_chain_id.clone();
+        self.check_validator_voting_not_paused(&chain_id)?;
+
+        if !self.is_validator(caller, &chain_id) {
+            return Err(format!(
+                "Caller {} is not an authorized validator for the chain ID: {}",
+                caller, &chain_id
+            ));
+        }
+
+        let verification_key = format!("{}{}{}", txn_hash, COMMA, dest_txn_hash);
+        let mut validators_list = self.get_validators_by_txn_hash(verification_key.clone());
+        if validators_list.contains(caller) {
+            return Err(format!(
+                "Caller {} has already verified the bridging with txn_hash: {} and dest_txn_hash: {}.",
+                caller, &txn_hash, &dest_txn_hash
+            ));
+        }
+
+        if bridging.status != BRG_ABTC_PENDING_BRIDGE_FROM_ORIGIN_TO_DEST || bridging.dest_txn_hash != dest_txn_hash {
+            return Err("Mismatch between bridging record and input parameters. Verification failed.".to_string());
+        }
+
+        bridging.dest_txn_hash_verified_count += 1;
+        self.bridgings.insert(txn_hash.clone(), bridging);
+
+        validators_list.push(caller.clone());
+        self.verifications.insert(verification_key.clone(), validators_list);
+        self.record_validator_vote(
+            caller,
+            VOTE_BRIDGING_DEST_TXN_HASH,
+            &txn_hash,
+            &verification_key,
+            &chain_id,
+        );
+
+        Ok(())
+    }
+
+    // mintBridge call minting the bridging less its fee to the destination address
+    pub(crate) fn encode_bridging_mint_function_call(bridging: &BridgingRecord) -> Vec<u8> {
+        let destination = H160::from_slice(
+            &hex::decode(bridging.dest_chain_address.trim_start_matches("0x"))
+                .expect("Invalid hex address"),
+        );
+        let origin_txn_hash = bridging.txn_hash[bridging.origin_chain_id.len() + COMMA.len()..].to_string();
+
+        Self::encode_mint_bridge_function_call(
+            destination,
+            U256::from(bridging.abtc_amount - bridging.fee_amount),
+            bridging.origin_chain_id.clone(),
+            bridging.origin_chain_address.clone(),
+            origin_txn_hash,
+        )
+    }
+
+    // Helper function to encode the mintBridge function call
+    pub(crate) fn encode_mint_bridge_function_call(
+        to_address: H160,
+        amount: U256,
+        origin_chain_id: String,
+        origin_chain_address: String,
+        origin_txn_hash: String,
+    ) -> Vec<u8> {
+        let mint_bridge_function_signature = "mintBridge(address,uint256,string,string,string)";
+
+        // Compute the Keccak-256 hash of the function signature and take the first 4 bytes
+        let function_selector = &keccak256(mint_bridge_function_signature.as_bytes())[0..4];
+
+        let mut encoded = ethabi::encode(&[
+            ethabi::Token::Address(to_address),
+            ethabi::Token::Uint(amount),
+            ethabi::Token::String(origin_chain_id),
+            ethabi::Token::String(origin_chain_address),
+            ethabi::Token::String(origin_txn_hash),
+        ]);
+
+        let mut function_call_data = function_selector.to_vec();
+        function_call_data.append(&mut encoded);
+        function_call_data
+    }
+}
@@ -0,0 +1,498 @@
+use crate::atlas::Atlas;
+use crate::constants::btc_light_client::*;
+use crate::constants::network_type::*;
+use crate::constants::role::ROLE_DEPOSIT_OPERATOR;
+use crate::constants::status::*;
+use crate::modules::structs::{BtcBlockHeaderRecord, BtcDepositProofRecord, DepositRecord};
+use crate::AtlasExt;
+use ethers_core::types::U256;
+use near_sdk::{env, log, near_bindgen, AccountId};
+
+// Parsed fields of an 80-byte serialized BTC block header
+struct ParsedBtcHeader {
+    block_hash: Vec<u8>,
+    prev_block_hash: Vec<u8>,
+    merkle_root: Vec<u8>,
+    time: u32,
+    bits: u32,
+}
+
+fn double_sha256(data: &[u8]) -> Vec<u8> {
+    env::sha256(&env::sha256(data))
+}
+
+// BTC hashes are displayed in reverse byte order of their internal representation
+fn btc_hash_to_hex(hash: &[u8]) -> String {
+    let mut bytes = hash.to_vec();
+    bytes.reverse();
+    hex::encode(bytes)
+}
+
+fn btc_hex_to_hash(hash_hex: &str) -> Vec<u8> {
+    let mut bytes = hex::decode(hash_hex).expect("Invalid hex in BTC hash");
+    assert_eq!(bytes.len(), 32, "BTC hash must be 32 bytes");
+    bytes.reverse();
+    bytes
+}
+
+fn parse_btc_header(header_hex: &str) -> ParsedBtcHeader {
+    let bytes = hex::decode(header_hex).expect("Invalid hex in BTC block header");
+    assert_eq!(bytes.len(), 80, "BTC block header must be 80 bytes");
+
+    ParsedBtcHeader {
+        block_hash: double_sha256(&bytes),
+        prev_block_hash: bytes[4..36].to_vec(),
+        merkle_root: bytes[36..68].to_vec(),
+        time: u32::from_le_bytes(bytes[68..72].try_into().unwrap()),
+        bits: u32::from_le_bytes(bytes[72..76].try_into().unwrap()),
+    }
+}
+
+// Expands the compact "bits" representation into the full 256-bit target
+fn target_from_bits(bits: u32) -> U256 {
+    let exponent = (bits >> 24) as usize;
+    let mantissa = U256::from(bits & 0x007fffff);
+
+    if exponent <= 3 {
+        mantissa >> (8 * (3 - exponent))
+    } else {
+        mantissa << (8 * (exponent - 3))
+    }
+}
+
+// Compresses a 256-bit target into the compact "bits" representation
+fn bits_from_target(target: U256) -> u32 {
+    let mut size = (target.bits() + 7) / 8;
+    let mut compact = if size <= 3 {
+        (target.low_u64() << (8 * (3 - size))) as u32
+    } else {
+        (target >> (8 * (size - 3))).low_u32()
+    };
+
+    if compact & 0x00800000 != 0 {
+        compact >>= 8;
+        size += 1;
+    }
+
+    compact | ((size as u32) << 24)
+}
+
+// Expected number of hashes to find a block at the given target: 2^256 / (target + 1)
+fn work_from_target(target: U256) -> U256 {
+    (!target / (target + U256::one())) + U256::one()
+}
+
+#[near_bindgen]
+impl Atlas {
+    // Sets the trusted checkpoint the header chain is built on
+    // Only callable once by the owner; every later header has to descend from this checkpoint
+    // The checkpoint has to start a retarget period so the next difficulty adjustment can be computed from it
+    pub fn init_btc_light_client(&mut self, header_hex: String, height: u64) {
+        self.assert_not_paused();
+        self.assert_owner();
+
+        assert!(
+            self.btc_tip_block_hash.is_empty(),
+            "BTC light client is already initialized"
+        );
+        assert!(
+            height % BTC_RETARGET_INTERVAL == 0,
+            "BTC light client checkpoint must be at a retarget height"
+        );
+
+        let header = parse_btc_header(&header_hex);
+        let block_hash = btc_hash_to_hex(&header.block_hash);
+
+        let record = BtcBlockHeaderRecord {
+            block_hash: block_hash.clone(),
+            prev_block_hash: btc_hash_to_hex(&header.prev_block_hash),
+            merkle_root: btc_hash_to_hex(&header.merkle_root),
+            height,
+            time: header.time,
+            bits: header.bits,
+            chain_work: work_from_target(target_from_bits(header.bits)).to_string(),
+        };
+
+        self.btc_headers.insert(block_hash.clone(), record);
+        self.btc_main_chain.insert(height, block_hash.clone());
+        self.btc_tip_block_hash = block_hash.clone();
+
+        log!(
+            "BTC light client initialized at height {} with block hash {}",
+            height,
+            block_hash
+        );
+    }
+
+    // Appends serialized 80-byte headers (hex) to the header chain
+    // Each header must extend a known header, carry the expected difficulty bits and satisfy its proof of work
+    // The chain with the most cumulative work becomes the main chain
+    pub fn submit_btc_block_headers(&mut self, headers_hex: Vec<String>) {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+        let btc_chain_id = if self.is_production_mode() {
+            BITCOIN.to_string()
+        } else {
+            SIGNET.to_string()
+        };
+        assert!(
+            self.has_role(caller.clone(), ROLE_DEPOSIT_OPERATOR.to_string())
+                || self.is_validator(&caller, &btc_chain_id),
+            "Only a deposit operator or a BTC validator can submit block headers"
+        );
+        assert!(
+            !self.btc_tip_block_hash.is_empty(),
+            "BTC light client is not initialized"
+        );
+        assert!(!headers_hex.is_empty(), "Headers cannot be empty");
+
+        for header_hex in headers_hex.iter() {
+            self.submit_btc_block_header(header_hex);
+        }
+    }
+
+    // Marks a deposit as confirmed on the BTC chain by a merkle inclusion proof
+    // raw_tx_hex must decode to the same fields as the deposit record (see parse_deposit_btc_raw_tx)
+    // merkle_proof lists the sibling hashes from the transaction up to the merkle root, tx_index is the position of the transaction in the block
+    // The block must be on the main chain with at least btc_confirmation_depth confirmations
+    // Returns true if the proof is accepted and returns false if not
+    pub fn verify_deposit_btc_inclusion_proof(
+        &mut self,
+        raw_tx_hex: String,
+        block_hash: String,
+        merkle_proof: Vec<String>,
+        tx_index: u64,
+    ) -> bool {
+        self.assert_not_paused();
+
+        let details = self.parse_deposit_btc_raw_tx(raw_tx_hex);
+        let btc_txn_hash = details.btc_txn_hash.clone();
+
+        let deposit = match self.deposits.get(&btc_txn_hash) {
+            Some(deposit) => deposit.clone(),
+            None => {
+                log!("Deposit record not found for btc_txn_hash: {}.", &btc_txn_hash);
+                return false;
+            }
+        };
+
+        if self.btc_deposit_proofs.contains_key(&btc_txn_hash) {
+            log!(
+                "Inclusion proof already recorded for btc_txn_hash: {}.",
+                &btc_txn_hash
+            );
+            return false;
+        }
+
+        // Verify that the deposit record matches the transaction bytes, a sender the inputs do not reveal cannot be checked
+        if (!details.btc_sender_address.is_empty()
+            && deposit.btc_sender_address != details.btc_sender_address)
+            || deposit.receiving_chain_id != details.receiving_chain_id
+            || deposit.receiving_address != details.receiving_address
+            || deposit.btc_amount != details.btc_amount
+            || deposit.fee_amount != details.fee_amount
+            || deposit.status != DEP_BTC_DEPOSITED_INTO_ATLAS
+            || !deposit.remarks.is_empty()
+        {
+            log!("Mismatch between deposit record and raw transaction. Verification failed.");
+            return false;
+        }
+
+        let header = match self.check_btc_inclusion_proof(&btc_txn_hash, &block_hash, &merkle_proof, tx_index) {
+            Ok(header) => header,
+            Err(reason) => {
+                log!("{}", reason);
+                return false;
+            }
+        };
+
+        self.btc_deposit_proofs.insert(
+            btc_txn_hash.clone(),
+            BtcDepositProofRecord {
+                btc_txn_hash: btc_txn_hash.clone(),
+                block_hash,
+                block_height: header.height,
+                timestamp: env::block_timestamp() / 1_000_000_000,
+            },
+        );
+
+        log!(
+            "Deposit confirmed by inclusion proof for btc_txn_hash: {}",
+            btc_txn_hash
+        );
+
+        true
+    }
+
+    pub fn set_btc_confirmation_depth(&mut self, btc_confirmation_depth: u64) {
+        self.assert_not_paused();
+        self.assert_owner();
+
+        assert!(
+            btc_confirmation_depth > 0,
+            "Confirmation depth must be greater than zero"
+        );
+        self.btc_confirmation_depth = btc_confirmation_depth;
+    }
+
+    pub fn get_btc_confirmation_depth(&self) -> u64 {
+        self.btc_confirmation_depth
+    }
+
+    pub fn get_btc_light_client_tip(&self) -> Option<BtcBlockHeaderRecord> {
+        self.btc_headers.get(&self.btc_tip_block_hash).cloned()
+    }
+
+    pub fn get_btc_light_client_height(&self) -> u64 {
+        self.btc_headers
+            .get(&self.btc_tip_block_hash)
+            .map(|header| header.height)
+            .unwrap_or(0)
+    }
+
+    pub fn get_btc_block_header(&self, block_hash: String) -> Option<BtcBlockHeaderRecord> {
+        self.btc_headers.get(&block_hash).cloned()
+    }
+
+    pub fn get_btc_block_hash_by_height(&self, height: u64) -> Option<String> {
+        self.btc_main_chain.get(&height).cloned()
+    }
+
+    pub fn get_btc_deposit_inclusion_proof(
+        &self,
+        btc_txn_hash: String,
+    ) -> Option<BtcDepositProofRecord> {
+        self.btc_deposit_proofs.get(&btc_txn_hash).cloned()
+    }
+}
+
+impl Atlas {
+    // A deposit counts as confirmed on the BTC chain once validators reach the threshold or an inclusion proof is recorded
+    pub fn is_deposit_btc_confirmed(&self, deposit: &DepositRecord, validators_threshold: u8) -> bool {
+        let btc_chain_id = if self.is_production_mode() {
+            BITCOIN.to_string()
+        } else {
+            SIGNET.to_string()
+        };
+
+        self.is_verification_threshold_met(
+            &deposit.btc_txn_hash,
+            &btc_chain_id,
+            deposit.verified_count,
+            validators_threshold,
+        )
+            || self.btc_deposit_proofs.contains_key(&deposit.btc_txn_hash)
+    }
+
+    // Checks a merkle inclusion proof of btc_txn_hash in a main chain block with at least btc_confirmation_depth confirmations
+    // Returns the header of the block, or the reason the proof is rejected
+    pub(crate) fn check_btc_inclusion_proof(
+        &self,
+        btc_txn_hash: &str,
+        block_hash: &str,
+        merkle_proof: &[String],
+        tx_index: u64,
+    ) -> Result<BtcBlockHeaderRecord, String> {
+        let header = self
+            .btc_headers
+            .get(block_hash)
+            .cloned()
+            .ok_or_else(|| format!("Block header not found for block hash: {}.", block_hash))?;
+
+        if self.btc_main_chain.get(&header.height).map(|hash| hash.as_str()) != Some(block_hash) {
+            return Err(format!("Block {} is not on the main chain.", block_hash));
+        }
+
+        let confirmations = self.get_btc_light_client_height() - header.height + 1;
+        if confirmations < self.btc_confirmation_depth {
+            return Err(format!(
+                "Block {} has {} confirmations, {} required.",
+                block_hash, confirmations, self.btc_confirmation_depth
+            ));
+        }
+
+        // Fold the sibling hashes into the merkle root, the index bit decides the concatenation order
+        let mut computed_root = btc_hex_to_hash(btc_txn_hash);
+        let mut index = tx_index;
+        for sibling_hex in merkle_proof.iter() {
+            let sibling = btc_hex_to_hash(sibling_hex);
+            let mut concat = Vec::with_capacity(64);
+            if index & 1 == 0 {
+                concat.extend_from_slice(&computed_root);
+                concat.extend_from_slice(&sibling);
+            } else {
+                concat.extend_from_slice(&sibling);
+                concat.extend_from_slice(&computed_root);
+            }
+            computed_root = double_sha256(&concat);
+            index >>= 1;
+        }
+
+        if index != 0 || btc_hash_to_hex(&computed_root) != header.merkle_root {
+            return Err(
+                "Merkle proof does not match the block's merkle root. Verification failed."
+                    .to_string(),
+            );
+        }
+
+        Ok(header)
+    }
+
+    fn submit_btc_block_header(&mut self, header_hex: &str) {
+        let header = parse_btc_header(header_hex);
+        let block_hash = btc_hash_to_hex(&header.block_hash);
+
+        if self.btc_headers.contains_key(&block_hash) {
+            log!("Block header {} already stored, skipping", block_hash);
+            return;
+        }
+
+        let prev_block_hash = btc_hash_to_hex(&header.prev_block_hash);
+        let parent = self
+            .btc_headers
+            .get(&prev_block_hash)
+            .cloned()
+            .unwrap_or_else(|| env::panic_str("Previous block header not found"));
+        let height = parent.height + 1;
+
+        // Difficulty check
+        let expected_bits = self.expected_btc_bits(&parent, height);
+        assert_eq!(header.bits, expected_bits, "Invalid difficulty bits");
+
+        // Timestamp check, stored ancestors stand in for the full window right after the checkpoint
+        assert!(
+            header.time > self.btc_median_time_past(&parent),
+            "Block time must be later than the median time of the previous blocks"
+        );
+
+        // Proof of work check, the block hash is a little-endian 256-bit number
+        let target = target_from_bits(header.bits);
+        let pow_limit = target_from_bits(self.btc_pow_limit_bits());
+        assert!(target <= pow_limit, "Target exceeds the proof of work limit");
+        assert!(
+            U256::from_little_endian(&header.block_hash) <= target,
+            "Insufficient proof of work"
+        );
+
+        let chain_work = U256::from_dec_str(&parent.chain_work).unwrap() + work_from_target(target);
+
+        self.btc_headers.insert(
+            block_hash.clone(),
+            BtcBlockHeaderRecord {
+                block_hash: block_hash.clone(),
+                prev_block_hash,
+                merkle_root: btc_hash_to_hex(&header.merkle_root),
+                height,
+                time: header.time,
+                bits: header.bits,
+                chain_work: chain_work.to_string(),
+            },
+        );
+
+        let tip = self.btc_headers.get(&self.btc_tip_block_hash).cloned().unwrap();
+        if chain_work > U256::from_dec_str(&tip.chain_work).unwrap() {
+            self.reorg_btc_main_chain(block_hash, height, tip.height);
+        }
+    }
+
+    // Points the main chain at the new tip: drops heights above it and rewrites ancestors until the chains agree
+    fn reorg_btc_main_chain(&mut self, new_tip_hash: String, new_tip_height: u64, old_tip_height: u64) {
+        for stale_height in (new_tip_height + 1)..=old_tip_height {
+            self.btc_main_chain.remove(&stale_height);
+        }
+
+        let mut block_hash = new_tip_hash.clone();
+        let mut height = new_tip_height;
+        while self.btc_main_chain.get(&height) != Some(&block_hash) {
+            self.btc_main_chain.insert(height, block_hash.clone());
+            match self.btc_headers.get(&block_hash) {
+                Some(header) if height > 0 && self.btc_headers.contains_key(&header.prev_block_hash) => {
+                    block_hash = header.prev_block_hash.clone();
+                    height -= 1;
+                }
+                _ => break,
+            }
+        }
+
+        log!(
+            "BTC main chain tip updated to {} at height {}",
+            new_tip_hash,
+            new_tip_height
+        );
+        self.btc_tip_block_hash = new_tip_hash;
+    }
+
+    // Difficulty only changes every retarget interval, based on the time the previous interval took
+    // The period start is the ancestor of the parent, a fork can have a different one than the main chain
+    fn expected_btc_bits(&self, parent: &BtcBlockHeaderRecord, height: u64) -> u32 {
+        if height % BTC_RETARGET_INTERVAL != 0 {
+            return parent.bits;
+        }
+
+        let first = height
+            .checked_sub(BTC_RETARGET_INTERVAL)
+            .and_then(|first_height| self.get_btc_ancestor(parent, first_height))
+            .unwrap_or_else(|| env::panic_str("Retarget period start header not found"));
+
+        let actual_timespan = (parent.time as u64)
+            .saturating_sub(first.time as u64)
+            .clamp(BTC_TARGET_TIMESPAN / 4, BTC_TARGET_TIMESPAN * 4);
+
+        let mut new_target = target_from_bits(parent.bits) * U256::from(actual_timespan)
+            / U256::from(BTC_TARGET_TIMESPAN);
+        let pow_limit = target_from_bits(self.btc_pow_limit_bits());
+        if new_target > pow_limit {
+            new_target = pow_limit;
+        }
+
+        bits_from_target(new_target)
+    }
+
+    // Ancestor of header at height, following parent links until they join the main chain, whose ancestors are indexed by height
+    fn get_btc_ancestor(&self, header: &BtcBlockHeaderRecord, height: u64) -> Option<BtcBlockHeaderRecord> {
+        let mut current = header.clone();
+        loop {
+            if current.height == height {
+                return Some(current);
+            }
+            if current.height < height {
+                return None;
+            }
+            if self.btc_main_chain.get(&current.height) == Some(&current.block_hash) {
+                return self
+                    .btc_main_chain
+                    .get(&height)
+                    .and_then(|block_hash| self.btc_headers.get(block_hash))
+                    .cloned();
+            }
+            current = self.btc_headers.get(&current.prev_block_hash).cloned()?;
+        }
+    }
+
+    // Median time of the parent and its stored ancestors, up to BTC_MEDIAN_TIME_SPAN blocks
+    fn btc_median_time_past(&self, parent: &BtcBlockHeaderRecord) -> u32 {
+        let mut times = vec![parent.time];
+        let mut block_hash = &parent.prev_block_hash;
+        while times.len() < BTC_MEDIAN_TIME_SPAN {
+            match self.btc_headers.get(block_hash) {
+                Some(header) => {
+                    times.push(header.time);
+                    block_hash = &header.prev_block_hash;
+                }
+                None => break,
+            }
+        }
+
+        times.sort_unstable();
+        times[times.len() / 2]
+    }
+
+    fn btc_pow_limit_bits(&self) -> u32 {
+        if self.is_production_mode() {
+            BTC_MAINNET_POW_LIMIT_BITS
+        } else {
+            BTC_SIGNET_POW_LIMIT_BITS
+        }
+    }
+}
@@ -1,26 +1,33 @@
 use crate::atlas::Atlas;
 use crate::chain_configs::ChainConfigRecord;
 use crate::constants::delimiter::COMMA;
+use crate::constants::mint_attempt::MINT_ATTEMPT_MINT;
 use crate::constants::near_gas::*;
 use crate::constants::network_type::*;
+use crate::constants::pause_scope::*;
+use crate::constants::role::*;
 use crate::constants::status::*;
+use crate::constants::vote_type::*;
 use crate::modules::signer::*;
-use crate::modules::structs::DepositRecord;
+use crate::modules::structs::{DepositRecord, MintAttemptRecord};
 use crate::AtlasExt;
+use crate::BtcDepositTxDetails;
 use crate::UtxoInput;
+use crate::VerificationResult;
 use crate::WithDrawFailDepositResult;
+use bitcoin::blockdata::script::{Instruction, Script};
 use bitcoin::blockdata::transaction::{Transaction, TxOut};
-use bitcoin::consensus::encode::serialize;
+use bitcoin::consensus::encode::{deserialize, serialize};
+use bitcoin::network::constants::Network;
 use bitcoin::util::address::Address;
+use bitcoin::util::key::PublicKey;
 use bitcoin::util::psbt::PartiallySignedTransaction as Psbt;
 use ethers_core::types::{H160, U256};
-use hex::FromHex;
 use near_sdk::env::keccak256;
+use near_sdk::json_types::U128;
 use near_sdk::{
-    env, log, near_bindgen, AccountId, NearToken, Promise, PromiseError, PromiseOrValue,
+    env, log, near_bindgen, AccountId, NearToken, Promise, PromiseOrValue,
 };
-use omni_transaction::evm::evm_transaction::EVMTransaction;
-use omni_transaction::evm::types::Signature as OmniSignature;
 use omni_transaction::evm::utils::parse_eth_address;
 use omni_transaction::transaction_builder::{
     TransactionBuilder as OmniTransactionBuilder, TxBuilder,
@@ -45,7 +52,8 @@ impl Atlas {
         date_created: u64,
     ) {
         self.assert_not_paused();
-        self.assert_admin();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
+        self.assert_scope_not_paused(PAUSE_DEPOSITS, &receiving_chain_id);
 
         // Validate mandatory input fields
         assert!(
@@ -103,6 +111,191 @@ impl Atlas {
         self.deposits.insert(btc_txn_hash, record);
     }
 
+    // Inserts a deposit record whose fields are decoded from the raw serialized BTC transaction
+    // instead of being supplied by the deposit operator, see parse_deposit_btc_raw_tx
+    // The sender is only taken from the transaction inputs and is left empty when they do not reveal it
+    pub fn insert_deposit_btc_from_raw_tx(
+        &mut self,
+        raw_tx_hex: String,
+        timestamp: u64,
+        date_created: u64,
+    ) {
+        self.assert_not_paused();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
+
+        // Validate mandatory input fields
+        assert!(timestamp > 0, "Timestamp must be greater than zero");
+        assert!(date_created > 0, "Date created must be greater than zero");
+
+        let details = self.parse_deposit_btc_raw_tx(raw_tx_hex);
+        self.assert_scope_not_paused(PAUSE_DEPOSITS, &details.receiving_chain_id);
+
+        // Check for duplicate transaction hash
+        if self.deposits.contains_key(&details.btc_txn_hash) {
+            env::panic_str("Deposit with this transaction hash already exists");
+        }
+
+        let record = DepositRecord {
+            btc_txn_hash: details.btc_txn_hash.clone(),
+            btc_sender_address: details.btc_sender_address,
+            receiving_chain_id: details.receiving_chain_id,
+            receiving_address: details.receiving_address,
+            btc_amount: details.btc_amount,
+            fee_amount: details.fee_amount,
+            minted_txn_hash: "".to_string(),
+            timestamp,
+            status: DEP_BTC_PENDING_MEMPOOL,
+            remarks: "".to_string(),
+            date_created,
+            verified_count: 0,
+            retry_count: 0,
+            minted_txn_hash_verified_count: 0,
+            custody_txn_id: "".to_string(),
+        };
+
+        log!(
+            "Deposit inserted from raw transaction for btc_txn_hash: {}",
+            details.btc_txn_hash
+        );
+
+        self.deposits.insert(details.btc_txn_hash, record);
+    }
+
+    // Decodes a raw serialized BTC deposit transaction into the fields of a deposit record
+    // btc_amount is the sum of the outputs paying the atlas deposit address and the treasury address, fee_amount is the treasury output
+    // receiving_chain_id and receiving_address are read from the OP_RETURN memo "<receiving_chain_id>,<receiving_address>"
+    // btc_sender_address is derived from the public key spent by the first input (P2WPKH, P2SH-P2WPKH or P2PKH)
+    // and is empty for other inputs such as taproot, multisig or P2WSH spends
+    pub fn parse_deposit_btc_raw_tx(&self, raw_tx_hex: String) -> BtcDepositTxDetails {
+        assert!(!raw_tx_hex.is_empty(), "Raw transaction cannot be empty");
+
+        let deposit_address = self.global_params.get_btc_atlas_deposit_address();
+        assert!(
+            !deposit_address.is_empty(),
+            "BTC atlas deposit address is not configured"
+        );
+
+        let raw_tx_bytes = hex::decode(&raw_tx_hex).expect("Invalid hex in raw transaction");
+        let tx: Transaction =
+            deserialize(&raw_tx_bytes).expect("Failed to deserialize raw BTC transaction");
+
+        // A 64-byte transaction hashes like an inner merkle node, so its txid could pass an inclusion proof for the node
+        let mut stripped_tx = tx.clone();
+        stripped_tx.input.iter_mut().for_each(|input| input.witness.clear());
+        assert!(
+            serialize(&stripped_tx).len() != 64,
+            "BTC transactions of 64 bytes are not accepted"
+        );
+
+        let network = if self.is_production_mode() {
+            Network::Bitcoin
+        } else {
+            Network::Signet
+        };
+
+        let deposit_script = Address::from_str(&deposit_address)
+            .expect("Invalid BTC atlas deposit address")
+            .script_pubkey();
+        let treasury_script = Address::from_str(&self.global_params.get_treasury_address())
+            .ok()
+            .map(|address| address.script_pubkey());
+
+        let mut deposit_amount = 0u64;
+        let mut fee_amount = 0u64;
+        let mut memo: Option<String> = None;
+
+        for output in tx.output.iter() {
+            if output.script_pubkey == deposit_script {
+                deposit_amount += output.value;
+            } else if Some(&output.script_pubkey) == treasury_script.as_ref() {
+                fee_amount += output.value;
+            } else if output.script_pubkey.is_op_return() && memo.is_none() {
+                memo = Self::decode_op_return_memo(&output.script_pubkey);
+            }
+        }
+
+        assert!(
+            deposit_amount > 0,
+            "No output paying the BTC atlas deposit address"
+        );
+
+        let memo = memo.unwrap_or_else(|| env::panic_str("OP_RETURN memo not found"));
+        let (receiving_chain_id, receiving_address) = memo
+            .split_once(COMMA)
+            .unwrap_or_else(|| env::panic_str("Invalid OP_RETURN memo format"));
+        assert!(
+            !receiving_chain_id.is_empty(),
+            "Receiving chain ID cannot be empty"
+        );
+        assert!(
+            !receiving_address.is_empty(),
+            "Receiving address cannot be empty"
+        );
+
+        BtcDepositTxDetails {
+            btc_txn_hash: tx.txid().to_string(),
+            btc_sender_address: Self::derive_btc_sender_address(&tx, network),
+            receiving_chain_id: receiving_chain_id.to_string(),
+            receiving_address: receiving_address.to_string(),
+            btc_amount: deposit_amount + fee_amount,
+            fee_amount,
+        }
+    }
+
+    // Helper function to read the first data push following OP_RETURN as a UTF-8 memo
+    fn decode_op_return_memo(script: &Script) -> Option<String> {
+        script
+            .instructions()
+            .skip(1)
+            .find_map(|instruction| match instruction {
+                Ok(Instruction::PushBytes(data)) => String::from_utf8(data.to_vec()).ok(),
+                _ => None,
+            })
+    }
+
+    // Helper function to derive the sender address from the public key revealed by the first input
+    // Returns an empty string when the input does not reveal a single public key
+    fn derive_btc_sender_address(tx: &Transaction, network: Network) -> String {
+        let input = tx
+            .input
+            .first()
+            .unwrap_or_else(|| env::panic_str("Raw BTC transaction has no inputs"));
+
+        // Segwit spends carry [signature, public key] in the witness, legacy spends push them in script_sig
+        let is_segwit = !input.witness.is_empty();
+        let pubkey_bytes = if is_segwit {
+            if input.witness.len() != 2 {
+                return "".to_string();
+            }
+            input.witness[1].clone()
+        } else {
+            input
+                .script_sig
+                .instructions()
+                .filter_map(|instruction| match instruction {
+                    Ok(Instruction::PushBytes(data)) => Some(data.to_vec()),
+                    _ => None,
+                })
+                .last()
+                .unwrap_or_default()
+        };
+
+        let pubkey = match PublicKey::from_slice(&pubkey_bytes) {
+            Ok(pubkey) => pubkey,
+            Err(_) => return "".to_string(),
+        };
+
+        let address = if is_segwit && input.script_sig.is_empty() {
+            Address::p2wpkh(&pubkey, network).ok()
+        } else if is_segwit {
+            Address::p2shwpkh(&pubkey, network).ok()
+        } else {
+            Some(Address::p2pkh(&pubkey, network))
+        };
+
+        address.map(|address| address.to_string()).unwrap_or_default()
+    }
+
     pub fn get_deposit_by_btc_txn_hash(&self, btc_txn_hash: String) -> Option<DepositRecord> {
         // Validate that the btc_txn_hash is not empty
         assert!(
@@ -139,7 +332,7 @@ impl Atlas {
 
     pub fn update_deposit_btc_deposited(&mut self, btc_txn_hash: String, timestamp: u64) {
         self.assert_not_paused();
-        self.assert_admin();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
 
         // Validate input parameters
         assert!(
@@ -150,6 +343,8 @@ impl Atlas {
 
         // Check if the deposit exists for the given btc_txn_hash
         if let Some(mut deposit) = self.deposits.get(&btc_txn_hash).cloned() {
+            self.assert_scope_not_paused(PAUSE_DEPOSITS, &deposit.receiving_chain_id);
+
             // Check all specified conditions
             if deposit.status == DEP_BTC_PENDING_MEMPOOL
                 && deposit.remarks.is_empty()
@@ -158,6 +353,7 @@ impl Atlas {
                 // All conditions are met, proceed to update the deposit status
                 deposit.status = DEP_BTC_DEPOSITED_INTO_ATLAS;
                 deposit.timestamp = timestamp;
+                self.reserve_totals.deposited += deposit.btc_amount;
                 self.deposits.insert(btc_txn_hash.clone(), deposit);
                 log!(
                     "Deposit status updated to DEP_BTC_DEPOSITED_INTO_ATLAS for btc_txn_hash: {}",
@@ -185,7 +381,7 @@ impl Atlas {
         minted_txn_hash: String,
     ) {
         self.assert_not_paused();
-        self.assert_admin();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
 
         // Validate input parameters
         assert!(
@@ -209,9 +405,10 @@ impl Atlas {
             if let Some(chain_config) = self.chain_configs.get_chain_config(chain_id.clone()) {
                 // Check all specified conditions
                 if (deposit.status == DEP_BTC_PENDING_MINTED_INTO_ABTC)
-                    && deposit.verified_count >= chain_config.validators_threshold
+                    && self.is_deposit_btc_confirmed(&deposit, chain_config.validators_threshold)
                     && deposit.remarks.is_empty()
                     && deposit.minted_txn_hash.is_empty()
+                    && self.is_mint_attempt_txn_hash(&btc_txn_hash, &minted_txn_hash)
                 {
                     // All conditions are met, proceed to update the minted transaction hash
                     deposit.minted_txn_hash = minted_txn_hash.clone();
@@ -243,7 +440,7 @@ impl Atlas {
 
     pub fn update_deposit_minted(&mut self, btc_txn_hash: String, minted_txn_hash: String) {
         self.assert_not_paused();
-        self.assert_admin();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
 
         // Validate input parameters
         assert!(
@@ -274,15 +471,27 @@ impl Atlas {
                 {
                     // Check all specified conditions
                     if (deposit.status == DEP_BTC_PENDING_MINTED_INTO_ABTC)
-                        && deposit.verified_count >= btc_chain_config.validators_threshold
-                        && deposit.minted_txn_hash_verified_count
-                            >= chain_config.validators_threshold
+                        && self.is_deposit_btc_confirmed(&deposit, btc_chain_config.validators_threshold)
+                        && self.is_deposit_mint_verified(&deposit, chain_config.validators_threshold)
                         && deposit.remarks.is_empty()
                         && deposit.minted_txn_hash == minted_txn_hash
                     {
                         // All conditions are met, proceed to update the deposit status
                         deposit.status = DEP_BTC_MINTED_INTO_ABTC;
+                        let fee_amount = deposit.fee_amount;
+                        let receiving_chain_id = deposit.receiving_chain_id.clone();
+                        self.update_chain_reserve(&receiving_chain_id, |reserve| {
+                            reserve.minted += deposit.btc_amount
+                        });
                         self.deposits.insert(btc_txn_hash.clone(), deposit);
+                        self.credit_validator_rewards(
+                            &btc_txn_hash,
+                            fee_amount,
+                            &[
+                                (btc_txn_hash.clone(), btc_chain_id.clone()),
+                                (format!("{}{}{}", btc_txn_hash, COMMA, minted_txn_hash), receiving_chain_id),
+                            ],
+                        );
                         log!(
                             "Deposit status updated to DEP_BTC_MINTED_INTO_ABTC for btc_txn_hash: {}",
                             btc_txn_hash
@@ -313,7 +522,7 @@ impl Atlas {
 
     pub fn update_deposit_remarks(&mut self, btc_txn_hash: String, remarks: String) {
         self.assert_not_paused();
-        self.assert_admin();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
 
         // Validate input parameters
         assert!(
@@ -357,8 +566,14 @@ impl Atlas {
                     .chain_configs
                     .get_chain_config(deposit.receiving_chain_id.clone())
                 {
-                    // Check if the verified_count meets or exceeds the validators_threshold
-                    if deposit.verified_count >= chain_config.validators_threshold {
+                    // Check if the verified_count meets or exceeds the validators_threshold, or an inclusion proof is recorded
+                    if self.is_deposit_btc_confirmed(deposit, chain_config.validators_threshold)
+                        && self.is_deposit_dispute_window_passed(deposit)
+                        && !self.is_scope_paused(
+                            PAUSE_MINTING.to_string(),
+                            deposit.receiving_chain_id.clone(),
+                        )
+                    {
                         log!(
                             "Deposit's verified_count ({}) meets or exceeds the validators_threshold ({})",
                             deposit.verified_count,
@@ -373,8 +588,10 @@ impl Atlas {
         None // If no matching deposit or chain config is found, return None
     }
 
+    // Deposits pending their mint whose signed mint is not cancelled yet are skipped, see is_mint_rollback_allowed
     pub fn rollback_all_deposit_status(&mut self) {
         self.assert_not_paused();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
 
         let global_params = self.get_all_global_params();
         let global_params_json = serde_json::to_value(&global_params).unwrap();
@@ -414,6 +631,10 @@ impl Atlas {
                             Some((key.clone(), deposit)) // Clone the key and return the updated deposit
                         }
                         DEP_BTC_PENDING_MINTED_INTO_ABTC => {
+                            if !self.is_mint_rollback_allowed(key) {
+                                log!("Signed mint of deposit {} has to be cancelled before it is rolled back", key);
+                                return None;
+                            }
                             deposit.status = DEP_BTC_DEPOSITED_INTO_ATLAS;
                             deposit.retry_count += 1;
                             deposit.remarks.clear();
@@ -434,8 +655,10 @@ impl Atlas {
     }
 
     // to create functions to rollback status for records with error messages
+    // A signed EVM mint has to be cancelled first so the old transaction can no longer mint twice
     pub fn rollback_deposit_status_by_btc_txn_hash(&mut self, btc_txn_hash: String) {
         self.assert_not_paused();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
 
         if btc_txn_hash.is_empty() {
             env::panic_str("BTC transaction hash cannot be empty");
@@ -474,6 +697,10 @@ impl Atlas {
                         deposit.remarks.clear();
                     }
                     DEP_BTC_PENDING_MINTED_INTO_ABTC => {
+                        assert!(
+                            self.is_mint_rollback_allowed(&btc_txn_hash),
+                            "Signed mint has to be cancelled before the deposit is rolled back"
+                        );
                         deposit.status = DEP_BTC_DEPOSITED_INTO_ATLAS;
                         deposit.retry_count += 1;
                         deposit.remarks.clear();
@@ -494,13 +721,12 @@ impl Atlas {
     pub fn create_mint_abtc_signed_tx(
         &mut self,
         btc_txn_hash: String,
-        nonce: u64,
         gas: u128,
-        max_fee_per_gas: u128,
-        max_priority_fee_per_gas: u128,
+        max_fee_per_gas: Option<u128>,
+        max_priority_fee_per_gas: Option<u128>,
     ) -> PromiseOrValue<String> {
         self.assert_not_paused();
-        self.assert_admin();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
 
         // Validate input parameters
         assert!(
@@ -524,14 +750,29 @@ impl Atlas {
                     .chain_configs
                     .get_chain_config(deposit.receiving_chain_id.clone())
                 {
-                    // Ensure the deposit's verified_count meets or exceeds the chain's validators_threshold
-                    if deposit.verified_count >= chain_config.validators_threshold {
+                    // Ensure the deposit's verified_count meets or exceeds the chain's validators_threshold, or an inclusion proof is recorded
+                    if self.is_deposit_btc_confirmed(&deposit, chain_config.validators_threshold) {
                         log!(
                             "Deposit's verified_count ({}) meets or exceeds the validators_threshold ({})",
                             deposit.verified_count,
                             chain_config.validators_threshold
                         );
 
+                        // Mints stay blocked while the deposit is disputed or within its dispute window
+                        if !self.is_deposit_dispute_window_passed(&deposit) {
+                            return PromiseOrValue::Value(
+                                "Deposit is disputed or within its dispute window.".to_string(),
+                            );
+                        }
+                        self.assert_scope_not_paused(PAUSE_MINTING, &deposit.receiving_chain_id);
+
+                        // A mint over the hourly or daily mint limits trips the circuit breaker instead of being signed
+                        if !self.record_mint_volume(&deposit.receiving_chain_id, &btc_txn_hash, deposit.btc_amount) {
+                            return PromiseOrValue::Value(
+                                "Mint limit exceeded, minting is paused.".to_string(),
+                            );
+                        }
+
                         // Get the "path" dynamically from the chain config (e.g., "EVM", "NEAR")
                         let path = chain_config.network_type.clone(); // Assuming network_type represents the path
                         let current_timestamp = env::block_timestamp() / 1_000_000_000;
@@ -556,6 +797,18 @@ impl Atlas {
                                     .expect("Invalid hex address"),
                             );
                             let value_as_128 = 0;
+                            let (max_fee_per_gas, max_priority_fee_per_gas) = self
+                                .resolve_evm_fees(
+                                    &chain_config,
+                                    gas,
+                                    max_fee_per_gas,
+                                    max_priority_fee_per_gas,
+                                );
+                            let nonce = self.next_evm_nonce(
+                                &deposit.receiving_chain_id,
+                                &path,
+                                &btc_txn_hash,
+                            );
 
                             let data: Vec<u8> = Self::encode_mint_function_call(
                                 destination,
@@ -583,37 +836,26 @@ impl Atlas {
                                 )
                                 .build();
 
-                            let evm_tx_encoded = evm_tx.build_for_signing();
-
-                            // Serialize the transaction to JSON or to bytes
-                            let evm_tx_json = serde_json::to_string(&evm_tx)
-                                .expect("Failed to serialize transaction");
-
-                            // Store the serialized JSON in self.last_evm_tx (as a string or Vec<u8>)
-                            self.last_evm_tx = Some(evm_tx_json.into_bytes());
-
-                            let evm_tx_hash = keccak256(&evm_tx_encoded);
-
-                            log!("Payload: [{}] {:?}", evm_tx_hash.len(), evm_tx_hash);
-
-                            // Call MPC
-                            return PromiseOrValue::Promise(
-                                ext_signer::ext(self.global_params.get_mpc_contract())
-                                    .with_attached_deposit(NearToken::from_millinear(500))
-                                    .sign(SignRequest::new(
-                                        evm_tx_hash.try_into().unwrap_or_else(|e| {
-                                            panic!("Failed to convert payload {:?}", e)
-                                        }),
-                                        path,
-                                        0,
-                                    ))
-                                    .then(
-                                        Self::ext(env::current_account_id())
-                                            .with_static_gas(SIGN_CALLBACK_GAS)
-                                            .with_unused_gas_weight(0)
-                                            .sign_callback(),
-                                    ),
-                            );
+                            let attempt = MintAttemptRecord {
+                                attempt_type: MINT_ATTEMPT_MINT.to_string(),
+                                chain_id: deposit.receiving_chain_id.clone(),
+                                nonce,
+                                gas: U128(gas),
+                                max_fee_per_gas: U128(max_fee_per_gas),
+                                max_priority_fee_per_gas: U128(max_priority_fee_per_gas),
+                                tx_hash: String::new(),
+                                from_address: String::new(),
+                                evm_tx: String::new(),
+                                signed_at: current_timestamp,
+                            };
+
+                            // Call MPC, the signed transaction is kept as a mint attempt of the deposit so it can be sped up or cancelled
+                            return PromiseOrValue::Promise(self.sign_mint_attempt(
+                                btc_txn_hash.clone(),
+                                attempt,
+                                evm_tx,
+                                path,
+                            ));
                         } else if path == NEAR.to_string() {
                             log!(
                                 "Minting aBTC on NEAR chain for deposit with btc_txn_hash: {}",
@@ -688,52 +930,8 @@ impl Atlas {
         PromiseOrValue::Value("Deposit not found or invalid conditions.".to_string())
     }
 
-    #[private]
-    pub fn sign_callback(
-        &self,
-        #[callback_result] result: Result<SignResult, PromiseError>,
-    ) -> Vec<u8> {
-        if let Ok(sign_result) = result {
-            let big_r = &sign_result.big_r.affine_point;
-            let s = &sign_result.s.scalar;
-            let recovery_id = &sign_result.recovery_id;
-
-            let v: u64 = *recovery_id as u64;
-
-            let r = &big_r[2..];
-            let end = &big_r[..2];
-
-            let r_bytes = Vec::from_hex(r).expect("Invalid hex in r");
-            let s_bytes = Vec::from_hex(s).expect("Invalid hex in s");
-            let _end_bytes = Vec::from_hex(end).expect("Invalid hex in end");
-
-            let signature_omni: OmniSignature = OmniSignature {
-                v: v,
-                r: r_bytes.to_vec(),
-                s: s_bytes.to_vec(),
-            };
-
-            // Retrieve the stored last evm transaction
-            if let Some(last_evm_tx) = &self.last_evm_tx {
-                // Deserialize the JSON string back into EVMTransaction
-                let evm_tx: EVMTransaction = serde_json::from_slice(last_evm_tx)
-                    .expect("Failed to deserialize last EVM transaction");
-
-                // Now you can use `evm_tx` and build the transaction with signature
-                let near_tx_signed = evm_tx.build_with_signature(&signature_omni);
-
-                // Return the signed transaction here
-                return near_tx_signed;
-            } else {
-                panic!("No last evm transaction found");
-            }
-        } else {
-            panic!("Callback failed");
-        }
-    }
-
     // Helper function to encode the mint function call
-    fn encode_mint_function_call(to_address: H160, amount: U256, btc_txn_hash: String) -> Vec<u8> {
+    pub(crate) fn encode_mint_function_call(to_address: H160, amount: U256, btc_txn_hash: String) -> Vec<u8> {
         let mint_function_signature = "mintDeposit(address,uint256,string)"; // Updated Solidity function signature
 
         // Compute the Keccak-256 hash of the function signature and take the first 4 bytes
@@ -764,10 +962,47 @@ impl Atlas {
 
         let caller: AccountId = env::predecessor_account_id();
 
+        match self.apply_deposit_verification(&caller, mempool_deposit) {
+            Ok(()) => true, // success case returns true
+            Err(reason) => {
+                log!("{}", reason);
+                false
+            }
+        }
+    }
+
+    // Batch version of increment_deposit_verified_count
+    // Each mempool_deposit is verified independently, a rejected record does not abort the rest of the batch
+    // Returns one result per mempool_deposit, in the same order, with the reason for every rejected record
+    pub fn batch_increment_deposit_verified_count(
+        &mut self,
+        mempool_deposits: Vec<DepositRecord>,
+    ) -> Vec<VerificationResult> {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+
+        mempool_deposits
+            .into_iter()
+            .map(|mempool_deposit| {
+                let key = mempool_deposit.btc_txn_hash.clone();
+                VerificationResult::from_outcome(
+                    key,
+                    self.apply_deposit_verification(&caller, mempool_deposit),
+                )
+            })
+            .collect()
+    }
+
+    // Applies the caller's vote for increment_deposit_verified_count, returns the reason if the vote is rejected
+    pub(crate) fn apply_deposit_verification(
+        &mut self,
+        caller: &AccountId,
+        mempool_deposit: DepositRecord,
+    ) -> Result<(), String> {
         // Validate the mempool_deposit
         if mempool_deposit.btc_txn_hash.is_empty() {
-            log!("Invalid mempool_deposit: btc_txn_hash is empty");
-            return false;
+            return Err("Invalid mempool_deposit: btc_txn_hash is empty".to_string());
         }
 
         // Retrieve the deposit record using the btc_txn_hash
@@ -777,21 +1012,20 @@ impl Atlas {
             } else {
                 SIGNET.to_string()
             };
+            self.check_validator_voting_not_paused(&chain_id)?;
 
             // Use the is_validator function to check if the caller is authorized for the bitcoin deposit
-            if self.is_validator(&caller, &chain_id) {
+            if self.is_validator(caller, &chain_id) {
                 // Retrieve the list of validators for this btc_txn_hash using the getter method
                 let mut validators_list =
                     self.get_validators_by_txn_hash(deposit.btc_txn_hash.clone());
 
                 // Check if the caller has already verified this btc_txn_hash
-                if validators_list.contains(&caller) {
-                    log!(
+                if validators_list.contains(caller) {
+                    return Err(format!(
                         "Caller {} has already verified the transaction with btc_txn_hash: {}.",
-                        &caller,
-                        &deposit.btc_txn_hash
-                    );
-                    return false;
+                        caller, &deposit.btc_txn_hash
+                    ));
                 }
 
                 // Verify that all fields of deposit and mempool_deposit are equal
@@ -805,8 +1039,7 @@ impl Atlas {
                     || deposit.status != DEP_BTC_DEPOSITED_INTO_ATLAS
                     || deposit.remarks != mempool_deposit.remarks
                 {
-                    log!("Mismatch between near_deposit and mempool_deposit records. Verification failed.");
-                    return false;
+                    return Err("Mismatch between near_deposit and mempool_deposit records. Verification failed.".to_string());
                 }
 
                 // Increment the verified count
@@ -817,25 +1050,29 @@ impl Atlas {
                     .insert(mempool_deposit.btc_txn_hash.clone(), deposit);
 
                 // Add the caller to the list of validators for this btc_txn_hash
-                validators_list.push(caller);
+                validators_list.push(caller.clone());
                 self.verifications
-                    .insert(mempool_deposit.btc_txn_hash, validators_list);
+                    .insert(mempool_deposit.btc_txn_hash.clone(), validators_list);
+                self.record_validator_vote(
+                    caller,
+                    VOTE_DEPOSIT_VERIFIED,
+                    &mempool_deposit.btc_txn_hash,
+                    &mempool_deposit.btc_txn_hash,
+                    &chain_id,
+                );
 
-                true // success case returns true
+                Ok(())
             } else {
-                log!(
+                Err(format!(
                     "Caller {} is not an authorized validator for the chain ID: {}",
-                    &caller,
-                    &chain_id
-                );
-                return false;
+                    caller, &chain_id
+                ))
             }
         } else {
-            log!(
+            Err(format!(
                 "Deposit record not found for btc_txn_hash: {}.",
                 &mempool_deposit.btc_txn_hash
-            );
-            return false;
+            ))
         }
     }
 
@@ -851,18 +1088,62 @@ impl Atlas {
     ) -> bool {
         self.assert_not_paused();
 
-        // Validate input parameters
-        if btc_txn_hash.is_empty() || minted_txn_hash.is_empty() {
-            log!("Invalid input: btc_txn_hash or minted_txn_hash is empty");
-            return false;
+        let caller: AccountId = env::predecessor_account_id();
+
+        match self.apply_deposit_minted_txn_hash_verification(&caller, btc_txn_hash, minted_txn_hash) {
+            Ok(()) => true, // success case returns true
+            Err(reason) => {
+                log!("{}", reason);
+                false
+            }
         }
+    }
+
+    // Batch version of increment_deposit_minted_txn_hash_verified_count taking (btc_txn_hash, minted_txn_hash) pairs
+    // Each pair is verified independently, a rejected pair does not abort the rest of the batch
+    // Returns one result per pair, in the same order, with the reason for every rejected pair
+    pub fn batch_increment_deposit_minted_txn_hash_verified_count(
+        &mut self,
+        minted_txn_hashes: Vec<(String, String)>,
+    ) -> Vec<VerificationResult> {
+        self.assert_not_paused();
 
         let caller: AccountId = env::predecessor_account_id();
 
+        minted_txn_hashes
+            .into_iter()
+            .map(|(btc_txn_hash, minted_txn_hash)| {
+                let key = format!("{}{}{}", btc_txn_hash, COMMA, minted_txn_hash);
+                VerificationResult::from_outcome(
+                    key,
+                    self.apply_deposit_minted_txn_hash_verification(
+                        &caller,
+                        btc_txn_hash,
+                        minted_txn_hash,
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    // Applies the caller's vote for increment_deposit_minted_txn_hash_verified_count, returns the reason if the vote is rejected
+    pub(crate) fn apply_deposit_minted_txn_hash_verification(
+        &mut self,
+        caller: &AccountId,
+        btc_txn_hash: String,
+        minted_txn_hash: String,
+    ) -> Result<(), String> {
+        // Validate input parameters
+        if btc_txn_hash.is_empty() || minted_txn_hash.is_empty() {
+            return Err("Invalid input: btc_txn_hash or minted_txn_hash is empty".to_string());
+        }
+
         // Retrieve the deposit record using the btc_txn_hash
         if let Some(mut deposit) = self.deposits.get(&btc_txn_hash).cloned() {
+            self.check_validator_voting_not_paused(&deposit.receiving_chain_id)?;
+
             // Check if the caller is an authorized validator for the receiving_chain_id
-            if self.is_validator(&caller, &deposit.receiving_chain_id) {
+            if self.is_validator(caller, &deposit.receiving_chain_id) {
                 // Create a unique key for the verifications map using the COMMA constant
                 let verification_key = format!("{}{}{}", btc_txn_hash, COMMA, minted_txn_hash);
 
@@ -870,14 +1151,11 @@ impl Atlas {
                 let mut validators_list = self.get_validators_by_txn_hash(verification_key.clone());
 
                 // Check if the caller has already verified this <btc_txn_hash>,<minted_txn_hash>
-                if validators_list.contains(&caller) {
-                    log!(
+                if validators_list.contains(caller) {
+                    return Err(format!(
                         "Caller {} has already verified the transaction with btc_txn_hash: {} and minted_txn_hash: {}.",
-                        &caller,
-                        &btc_txn_hash,
-                        &minted_txn_hash
-                    );
-                    return false;
+                        caller, &btc_txn_hash, &minted_txn_hash
+                    ));
                 }
 
                 // Verify that the deposit record's btc_txn_hash and minted_txn_hash match the input parameters
@@ -886,33 +1164,37 @@ impl Atlas {
                 {
                     // Increment the minted_txn_hash_verified_count
                     deposit.minted_txn_hash_verified_count += 1;
+                    let receiving_chain_id = deposit.receiving_chain_id.clone();
 
                     // Update the deposit record in the map
                     self.deposits.insert(btc_txn_hash.clone(), deposit);
 
                     // Add the caller to the list of validators for this <btc_txn_hash>,<minted_txn_hash>
-                    validators_list.push(caller);
-                    self.verifications.insert(verification_key, validators_list);
+                    validators_list.push(caller.clone());
+                    self.verifications.insert(verification_key.clone(), validators_list);
+                    self.record_validator_vote(
+                        caller,
+                        VOTE_DEPOSIT_MINTED_TXN_HASH,
+                        &btc_txn_hash,
+                        &verification_key,
+                        &receiving_chain_id,
+                    );
 
-                    true // success case returns true
+                    Ok(())
                 } else {
-                    log!("Mismatch between deposit record and input parameters. Verification failed.");
-                    false
+                    Err("Mismatch between deposit record and input parameters. Verification failed.".to_string())
                 }
             } else {
-                log!(
+                Err(format!(
                     "Caller {} is not an authorized validator for the receiving_chain_id: {}",
-                    &caller,
-                    &deposit.receiving_chain_id
-                );
-                false
+                    caller, &deposit.receiving_chain_id
+                ))
             }
         } else {
-            log!(
+            Err(format!(
                 "Deposit record not found for btc_txn_hash: {}.",
                 &btc_txn_hash
-            );
-            false
+            ))
         }
     }
 
@@ -923,7 +1205,7 @@ impl Atlas {
         fee_rate: u64,
     ) -> WithDrawFailDepositResult {
         self.assert_not_paused();
-        self.assert_admin();
+        self.assert_role(ROLE_REFUND_OPERATOR);
 
         // Validate input parameters
         assert!(
@@ -936,6 +1218,8 @@ impl Atlas {
         let max_retry_count = global_params_json["max_retry_count"].as_u64().unwrap() as u8;
 
         if let Some(mut deposit) = self.deposits.get(&btc_txn_hash).cloned() {
+            self.assert_scope_not_paused(PAUSE_REFUNDS, &deposit.receiving_chain_id);
+
             if deposit.status == DEP_BTC_PENDING_MINTED_INTO_ABTC
                 && !deposit.remarks.is_empty()
                 && deposit.retry_count >= max_retry_count
@@ -1035,7 +1319,7 @@ impl Atlas {
 
     pub fn update_deposit_custody_txn_id(&mut self, btc_txn_hash: String, custody_txn_id: String) {
         self.assert_not_paused();
-        self.assert_admin();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
 
         // Validate input parameters
         assert!(!btc_txn_hash.is_empty(), "Transaction hash cannot be empty");
@@ -1059,7 +1343,7 @@ impl Atlas {
 
     pub fn update_withdraw_fail_deposit_status(&mut self, btc_txn_hash: String, timestamp: u64) {
         self.assert_not_paused();
-        self.assert_admin();
+        self.assert_role(ROLE_REFUND_OPERATOR);
 
         // Validate input parameters
         assert!(
@@ -1070,8 +1354,11 @@ impl Atlas {
 
         // Retrieve the redemption record based on txn_hash
         if let Some(mut deposit) = self.deposits.get(&btc_txn_hash.clone()).cloned() {
+            self.assert_scope_not_paused(PAUSE_REFUNDS, &deposit.receiving_chain_id);
+
             if deposit.status == DEP_BTC_REFUNDING && !deposit.custody_txn_id.is_empty() {
                 deposit.status = DEP_BTC_REFUNDED;
+                self.reserve_totals.refunded += deposit.btc_amount;
                 self.deposits.insert(btc_txn_hash.clone(), deposit);
             } else {
                 env::panic_str("Deposit is not in invalid conditions.");
@@ -0,0 +1,269 @@
+use crate::atlas::Atlas;
+use crate::constants::dispute::*;
+use crate::constants::status::*;
+use crate::constants::vote_type::*;
+use crate::modules::events::emit_event;
+use crate::modules::structs::{BridgingRecord, DepositRecord, DisputeRecord, DisputeWindowRecord, RedemptionRecord};
+use crate::AtlasExt;
+use near_sdk::{env, log, near_bindgen, AccountId};
+use serde_json::json;
+
+fn current_timestamp() -> u64 {
+    env::block_timestamp() / 1_000_000_000
+}
+
+#[near_bindgen]
+impl Atlas {
+    // Sets how long records verified on a chain stay disputable after reaching the threshold, 0 disables the window
+    // Deposits use the window of their receiving chain, redemptions the window of their redemption chain and bridgings
+    // the window of their origin chain
+    pub fn set_chain_dispute_window(&mut self, chain_id: String, window_seconds: u64) {
+        self.assert_not_paused();
+        self.assert_owner();
+
+        assert!(
+            self.chain_configs.get_chain_config(chain_id.clone()).is_some(),
+            "Chain ID not found"
+        );
+
+        if window_seconds == 0 {
+            self.dispute_windows.remove(&chain_id);
+        } else {
+            self.dispute_windows.insert(chain_id, window_seconds);
+        }
+    }
+
+    pub fn get_chain_dispute_window(&self, chain_id: String) -> u64 {
+        self.dispute_windows.get(&chain_id).copied().unwrap_or(0)
+    }
+
+    pub fn get_dispute_window(&self, record_key: String) -> Option<DisputeWindowRecord> {
+        self.dispute_window_starts.get(&record_key).cloned()
+    }
+
+    pub fn get_dispute(&self, record_key: String) -> Option<DisputeRecord> {
+        self.disputes.get(&record_key).cloned()
+    }
+
+    pub fn get_open_disputes(&self) -> Vec<DisputeRecord> {
+        self.disputes
+            .values()
+            .filter(|dispute| dispute.status == DISPUTE_OPEN)
+            .cloned()
+            .collect()
+    }
+
+    // Flags a deposit, redemption or bridging within its dispute window, freezing it until the owner resolves the dispute
+    // Any validator may flag, evidence is kept on the dispute record for the owner's review
+    pub fn flag_disputed_record(&mut self, record_type: String, record_key: String, evidence: String) {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+        let is_validator = self.validators.get(&caller).map_or(false, |chain_ids| {
+            chain_ids
+                .iter()
+                .any(|chain_id| self.is_validator(&caller, chain_id))
+        });
+        assert!(is_validator, "Only a validator can flag a record");
+        assert!(!evidence.is_empty(), "Evidence cannot be empty");
+
+        let is_pending = match record_type.as_str() {
+            DISPUTE_RECORD_DEPOSIT => self
+                .deposits
+                .get(&record_key)
+                .map_or(false, |deposit| deposit.status == DEP_BTC_DEPOSITED_INTO_ATLAS),
+            DISPUTE_RECORD_REDEMPTION => self
+                .redemptions
+                .get(&record_key)
+                .map_or(false, |redemption| redemption.status == RED_ABTC_BURNT),
+            DISPUTE_RECORD_BRIDGING => self
+                .bridgings
+                .get(&record_key)
+                .map_or(false, |bridging| bridging.status == BRG_ABTC_BURNT),
+            _ => env::panic_str("Invalid record type"),
+        };
+        assert!(is_pending, "Record is not pending");
+        assert!(
+            self.disputes.get(&record_key).is_none(),
+            "Record has already been disputed"
+        );
+
+        let window = self
+            .dispute_window_starts
+            .get(&record_key)
+            .cloned()
+            .expect("Record has not reached its verification threshold");
+        assert!(
+            current_timestamp() < window.started_at + self.get_chain_dispute_window(window.chain_id.clone()),
+            "Dispute window has passed"
+        );
+
+        self.disputes.insert(
+            record_key.clone(),
+            DisputeRecord {
+                record_type: record_type.clone(),
+                record_key: record_key.clone(),
+                chain_id: window.chain_id,
+                flagged_by: caller.clone(),
+                evidence: evidence.clone(),
+                status: DISPUTE_OPEN,
+                flagged_at: current_timestamp(),
+                resolved_at: 0,
+            },
+        );
+
+        log!("Validator {} flagged {} {}", caller, record_type, record_key);
+
+        emit_event(
+            "record_disputed",
+            json!({
+                "record_type": record_type,
+                "record_key": record_key,
+                "flagged_by": caller,
+                "evidence": evidence,
+            }),
+        );
+    }
+
+    // Owner review of an open dispute
+    // Dismissing unfreezes the record, upholding keeps it blocked and sets its remarks so no further step accepts it
+    pub fn resolve_dispute(&mut self, record_key: String, uphold: bool) {
+        self.assert_not_paused();
+        self.assert_owner();
+
+        let mut dispute = self
+            .disputes
+            .get(&record_key)
+            .cloned()
+            .expect("Dispute not found");
+        assert!(dispute.status == DISPUTE_OPEN, "Dispute is not open");
+
+        if uphold {
+            let remarks = format!("Dispute upheld, flagged by {}", dispute.flagged_by);
+            if dispute.record_type == DISPUTE_RECORD_DEPOSIT {
+                if let Some(mut deposit) = self.deposits.get(&record_key).cloned() {
+                    deposit.remarks = remarks;
+                    self.deposits.insert(record_key.clone(), deposit);
+                }
+            } else if dispute.record_type == DISPUTE_RECORD_BRIDGING {
+                if let Some(mut bridging) = self.bridgings.get(&record_key).cloned() {
+                    bridging.remarks = remarks;
+                    self.bridgings.insert(record_key.clone(), bridging);
+                }
+            } else if let Some(mut redemption) = self.redemptions.get(&record_key).cloned() {
+                redemption.remarks = remarks;
+                self.redemptions.insert(record_key.clone(), redemption);
+            }
+            dispute.status = DISPUTE_UPHELD;
+        } else {
+            dispute.status = DISPUTE_DISMISSED;
+        }
+        dispute.resolved_at = current_timestamp();
+        self.disputes.insert(record_key.clone(), dispute);
+
+        emit_event(
+            "dispute_resolved",
+            json!({
+                "record_key": record_key,
+                "upheld": uphold,
+            }),
+        );
+    }
+}
+
+impl Atlas {
+    // Starts the dispute window of a deposit, redemption or bridging the first time validator votes bring it to its threshold
+    pub fn start_dispute_window(&mut self, vote_type: &str, record_key: &str) {
+        if self.dispute_window_starts.contains_key(record_key) {
+            return;
+        }
+
+        let chain_id = match vote_type {
+            VOTE_DEPOSIT_VERIFIED => match self.deposits.get(record_key) {
+                Some(deposit) => self
+                    .chain_configs
+                    .get_chain_config(deposit.receiving_chain_id.clone())
+                    .filter(|chain_config| {
+                        self.is_deposit_btc_confirmed(deposit, chain_config.validators_threshold)
+                    })
+                    .map(|chain_config| chain_config.chain_id),
+                None => None,
+            },
+            VOTE_REDEMPTION_VERIFIED => match self.redemptions.get(record_key) {
+                Some(redemption) => self
+                    .chain_configs
+                    .get_chain_config(redemption.abtc_redemption_chain_id.clone())
+                    .filter(|chain_config| {
+                        self.is_redemption_burn_verified(redemption, chain_config.validators_threshold)
+                    })
+                    .map(|chain_config| chain_config.chain_id),
+                None => None,
+            },
+            VOTE_BRIDGING_VERIFIED => match self.bridgings.get(record_key) {
+                Some(bridging) => self
+                    .chain_configs
+                    .get_chain_config(bridging.origin_chain_id.clone())
+                    .filter(|chain_config| {
+                        self.is_bridging_burn_verified(bridging, chain_config.validators_threshold)
+                    })
+                    .map(|chain_config| chain_config.chain_id),
+                None => None,
+            },
+            _ => None,
+        };
+
+        if let Some(chain_id) = chain_id {
+            self.dispute_window_starts.insert(
+                record_key.to_string(),
+                DisputeWindowRecord {
+                    chain_id,
+                    started_at: current_timestamp(),
+                },
+            );
+        }
+    }
+
+    // A deposit can be minted once it is not frozen by a dispute and its dispute window has passed
+    // Deposits confirmed by an inclusion proof do not depend on validators and skip the window
+    pub fn is_deposit_dispute_window_passed(&self, deposit: &DepositRecord) -> bool {
+        self.is_dispute_window_passed(
+            &deposit.btc_txn_hash,
+            self.btc_deposit_proofs.contains_key(&deposit.btc_txn_hash),
+        )
+    }
+
+    // A redemption can be paid out in BTC once it is not frozen by a dispute and its dispute window has passed
+    // Redemptions confirmed by a receipt proof do not depend on validators and skip the window
+    pub fn is_redemption_dispute_window_passed(&self, redemption: &RedemptionRecord) -> bool {
+        self.is_dispute_window_passed(
+            &redemption.txn_hash,
+            self.evm_receipt_proofs.contains_key(&redemption.txn_hash),
+        )
+    }
+
+    // A bridging can be minted on its destination chain once it is not frozen by a dispute and its dispute window has passed
+    // Bridging burns are only confirmed by validator votes, so no bridging skips the window
+    pub fn is_bridging_dispute_window_passed(&self, bridging: &BridgingRecord) -> bool {
+        self.is_dispute_window_passed(&bridging.txn_hash, false)
+    }
+
+    // Records that reached their threshold before dispute windows were introduced have no window record and are not held back
+    fn is_dispute_window_passed(&self, record_key: &str, proven: bool) -> bool {
+        if let Some(dispute) = self.disputes.get(record_key) {
+            if dispute.status != DISPUTE_DISMISSED {
+                return false;
+            }
+        }
+
+        if proven {
+            return true;
+        }
+
+        match self.dispute_window_starts.get(record_key) {
+            Some(window) => {
+                current_timestamp() >= window.started_at + self.get_chain_dispute_window(window.chain_id.clone())
+            }
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::constants::events::*;
+use near_sdk::env;
+use serde_json::{json, Value};
+
+// Logs a NEP-297 event so indexers can follow Atlas state changes
+pub fn emit_event(event: &str, data: Value) {
+    let event_log = json!({
+        "standard": EVENT_STANDARD,
+        "version": EVENT_STANDARD_VERSION,
+        "event": event,
+        "data": [data]
+    })
+    .to_string();
+
+    env::log_str(&format!("EVENT_JSON:{}", event_log));
+}
@@ -0,0 +1,161 @@
+use crate::atlas::Atlas;
+use crate::constants::network_type::EVM;
+use crate::modules::events::emit_event;
+use crate::modules::structs::EvmAdminFunctionRecord;
+use crate::AtlasExt;
+use ethabi::param_type::{ParamType, Reader};
+use ethabi::token::{LenientTokenizer, Tokenizer};
+use near_sdk::env::keccak256;
+use near_sdk::{env, near_bindgen, PromiseOrValue};
+use serde_json::json;
+
+// Splits a Solidity function signature into its ABI parameter types
+// The signature has to be canonical, e.g. uint256 rather than uint, since its hash is the selector
+fn parse_function_signature(signature: &str) -> Vec<ParamType> {
+    let open = signature.find('(').expect("Invalid function signature");
+    assert!(open > 0 && signature.ends_with(')'), "Invalid function signature");
+
+    let params = &signature[open..];
+    let param_types = if params == "()" {
+        vec![]
+    } else {
+        match Reader::read(params) {
+            Ok(ParamType::Tuple(param_types)) => param_types,
+            _ => env::panic_str("Invalid function signature"),
+        }
+    };
+
+    let canonical = format!(
+        "{}({})",
+        &signature[..open],
+        param_types
+            .iter()
+            .map(|param_type| param_type.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    );
+    assert!(
+        canonical == signature,
+        "Function signature is not canonical, expected {}",
+        canonical
+    );
+
+    param_types
+}
+
+#[near_bindgen]
+impl Atlas {
+    // Allowlists a function of the EVM atBTC contracts for create_abtc_admin_call_tx, e.g. pause() or setMinter(address,bool)
+    pub fn add_evm_admin_function(&mut self, signature: String) {
+        self.assert_owner();
+
+        assert!(
+            !self.evm_admin_functions.contains_key(&signature),
+            "Function is already allowlisted"
+        );
+        let param_types = parse_function_signature(&signature);
+
+        let record = EvmAdminFunctionRecord {
+            signature: signature.clone(),
+            selector: format!("0x{}", hex::encode(&keccak256(signature.as_bytes())[0..4])),
+            param_types: param_types
+                .iter()
+                .map(|param_type| param_type.to_string())
+                .collect(),
+            added_by: env::predecessor_account_id(),
+            added_at: env::block_timestamp() / 1_000_000_000,
+        };
+
+        emit_event(
+            "evm_admin_function_added",
+            json!({
+                "signature": record.signature,
+                "selector": record.selector,
+            }),
+        );
+
+        self.evm_admin_functions.insert(signature, record);
+    }
+
+    pub fn remove_evm_admin_function(&mut self, signature: String) {
+        self.assert_owner();
+
+        assert!(
+            self.evm_admin_functions.remove(&signature).is_some(),
+            "Function is not allowlisted"
+        );
+
+        emit_event(
+            "evm_admin_function_removed",
+            json!({
+                "signature": signature,
+            }),
+        );
+    }
+
+    pub fn get_evm_admin_functions(&self) -> Vec<EvmAdminFunctionRecord> {
+        self.evm_admin_functions.values().cloned().collect()
+    }
+
+    // Signs a call of an allowlisted function to the atBTC contract of an EVM chain, args are parsed as its ABI types
+    // Not blocked by the global pause so the owner can still pause the EVM contracts during an incident
+    pub fn create_abtc_admin_call_tx(
+        &mut self,
+        chain_id: String,
+        signature: String,
+        args: Vec<String>,
+        gas: u128,
+        max_fee_per_gas: Option<u128>,
+        max_priority_fee_per_gas: Option<u128>,
+    ) -> PromiseOrValue<String> {
+        self.assert_owner();
+
+        let function = self
+            .evm_admin_functions
+            .get(&signature)
+            .cloned()
+            .expect("Function is not allowlisted");
+        let chain_config = self
+            .chain_configs
+            .get_chain_config(chain_id.clone())
+            .expect("Chain ID not found");
+        assert!(chain_config.network_type == EVM, "Chain ID is not an EVM chain");
+
+        let param_types = parse_function_signature(&function.signature);
+        assert!(
+            args.len() == param_types.len(),
+            "Expected {} arguments",
+            param_types.len()
+        );
+        let tokens: Vec<ethabi::Token> = param_types
+            .iter()
+            .zip(args.iter())
+            .map(|(param_type, arg)| {
+                LenientTokenizer::tokenize(param_type, arg).unwrap_or_else(|_| {
+                    env::panic_str(&format!("Invalid {} argument: {}", param_type, arg))
+                })
+            })
+            .collect();
+
+        let mut data = keccak256(function.signature.as_bytes())[0..4].to_vec();
+        data.extend_from_slice(&ethabi::encode(&tokens));
+
+        emit_event(
+            "evm_admin_call_requested",
+            json!({
+                "chain_id": chain_id,
+                "signature": function.signature,
+                "args": args,
+            }),
+        );
+
+        self.sign_abtc_call(
+            chain_id,
+            data,
+            &function.signature,
+            gas,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        )
+    }
+}
@@ -0,0 +1,204 @@
+use crate::atlas::Atlas;
+use crate::chain_configs::ChainConfigRecord;
+use crate::constants::evm_gas::*;
+use crate::constants::network_type::EVM;
+use crate::constants::role::*;
+use crate::modules::structs::{BaseFeeRecord, EvmFeeEstimate, EvmGasBoundsRecord};
+use crate::AtlasExt;
+use near_sdk::{env, log, near_bindgen, AccountId};
+
+#[near_bindgen]
+impl Atlas {
+    // Bounds on the gas values signed for an EVM chain, 0 leaves a bound unset and all 0 removes the bounds
+    pub fn set_evm_gas_bounds(
+        &mut self,
+        chain_id: String,
+        max_gas: u64,
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+        default_priority_fee_per_gas: u64,
+    ) {
+        self.assert_not_paused();
+        self.assert_role(ROLE_CONFIG_MANAGER);
+
+        let chain_config = self
+            .chain_configs
+            .get_chain_config(chain_id.clone())
+            .expect("Chain ID not found");
+        assert!(chain_config.network_type == EVM, "Chain ID is not an EVM chain");
+        assert!(
+            max_priority_fee_per_gas == 0 || default_priority_fee_per_gas <= max_priority_fee_per_gas,
+            "Default priority fee per gas cannot exceed max priority fee per gas"
+        );
+        assert!(
+            max_fee_per_gas == 0 || default_priority_fee_per_gas <= max_fee_per_gas,
+            "Default priority fee per gas cannot exceed max fee per gas"
+        );
+
+        let bounds = EvmGasBoundsRecord {
+            max_gas,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            default_priority_fee_per_gas,
+        };
+        if max_gas == 0
+            && max_fee_per_gas == 0
+            && max_priority_fee_per_gas == 0
+            && default_priority_fee_per_gas == 0
+        {
+            self.evm_gas_bounds.remove(&chain_id);
+        } else {
+            self.evm_gas_bounds.insert(chain_id, bounds);
+        }
+    }
+
+    pub fn get_evm_gas_bounds(&self, chain_id: String) -> EvmGasBoundsRecord {
+        self.evm_gas_bounds.get(&chain_id).cloned().unwrap_or_default()
+    }
+
+    // Posts the base fee of a recent block of an EVM chain, replacing the validator's previous post for the chain
+    // Returns true if the base fee is recorded and returns false if not
+    pub fn submit_evm_base_fee(&mut self, chain_id: String, block_number: u64, base_fee: u64) -> bool {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+        let chain_config = self
+            .chain_configs
+            .get_chain_config(chain_id.clone())
+            .expect("Chain ID not found");
+        assert!(chain_config.network_type == EVM, "Chain ID is not an EVM chain");
+
+        if let Err(reason) = self.check_validator_voting_not_paused(&chain_id) {
+            log!("{}", reason);
+            return false;
+        }
+        if !self.is_validator(&caller, &chain_id) {
+            log!(
+                "Caller {} is not an authorized validator for the chain ID: {}",
+                &caller,
+                &chain_id
+            );
+            return false;
+        }
+
+        let mut base_fees = self.get_evm_base_fees(chain_id.clone());
+        if let Some(previous) = base_fees.iter().find(|record| record.validator == caller) {
+            if block_number <= previous.block_number {
+                log!(
+                    "Caller {} has already posted the base fee of block {} on chain ID: {}",
+                    &caller,
+                    previous.block_number,
+                    &chain_id
+                );
+                return false;
+            }
+        }
+
+        base_fees.retain(|record| record.validator != caller);
+        base_fees.push(BaseFeeRecord {
+            validator: caller,
+            block_number,
+            base_fee,
+            submitted_at: env::block_timestamp() / 1_000_000_000,
+        });
+        self.evm_base_fees.insert(chain_id, base_fees);
+
+        true
+    }
+
+    pub fn get_evm_base_fees(&self, chain_id: String) -> Vec<BaseFeeRecord> {
+        self.evm_base_fees
+            .get(&chain_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    // Fee values signed when the caller leaves them out, taken from the median of the validators' recent base fees
+    // None until base fees of at least validators_threshold current validators are fresh
+    pub fn get_evm_fee_estimate(&self, chain_id: String) -> Option<EvmFeeEstimate> {
+        let chain_config = self.chain_configs.get_chain_config(chain_id.clone())?;
+        let bounds = self.get_evm_gas_bounds(chain_id.clone());
+        let current_timestamp = env::block_timestamp() / 1_000_000_000;
+
+        let mut base_fees: Vec<u64> = self
+            .get_evm_base_fees(chain_id.clone())
+            .iter()
+            .filter(|record| {
+                current_timestamp.saturating_sub(record.submitted_at) <= BASE_FEE_MAX_AGE
+                    && self.is_validator(&record.validator, &chain_id)
+            })
+            .map(|record| record.base_fee)
+            .collect();
+        if base_fees.is_empty() || base_fees.len() < chain_config.validators_threshold as usize {
+            return None;
+        }
+
+        base_fees.sort_unstable();
+        let middle = base_fees.len() / 2;
+        let median_base_fee = if base_fees.len() % 2 == 0 {
+            ((base_fees[middle - 1] as u128 + base_fees[middle] as u128) / 2) as u64
+        } else {
+            base_fees[middle]
+        };
+
+        let max_priority_fee_per_gas = bounds.default_priority_fee_per_gas;
+        let mut max_fee_per_gas = median_base_fee
+            .saturating_mul(BASE_FEE_MULTIPLIER)
+            .saturating_add(max_priority_fee_per_gas);
+        if bounds.max_fee_per_gas > 0 {
+            max_fee_per_gas = max_fee_per_gas.min(bounds.max_fee_per_gas);
+        }
+
+        Some(EvmFeeEstimate {
+            median_base_fee,
+            samples: base_fees.len() as u32,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        })
+    }
+}
+
+impl Atlas {
+    // Fills in the fee values the caller left out and refuses gas values outside the chain's bounds
+    // Returns (max_fee_per_gas, max_priority_fee_per_gas)
+    pub fn resolve_evm_fees(
+        &self,
+        chain_config: &ChainConfigRecord,
+        gas: u128,
+        max_fee_per_gas: Option<u128>,
+        max_priority_fee_per_gas: Option<u128>,
+    ) -> (u128, u128) {
+        let bounds = self.get_evm_gas_bounds(chain_config.chain_id.clone());
+        let max_priority_fee_per_gas = max_priority_fee_per_gas
+            .unwrap_or(bounds.default_priority_fee_per_gas as u128);
+        let max_fee_per_gas = max_fee_per_gas.unwrap_or_else(|| {
+            self.get_evm_fee_estimate(chain_config.chain_id.clone())
+                .expect("No base fee estimate for the chain, max_fee_per_gas is required")
+                .max_fee_per_gas as u128
+        });
+
+        assert!(
+            bounds.max_gas == 0 || gas <= bounds.max_gas as u128,
+            "Gas exceeds the chain's max_gas of {}",
+            bounds.max_gas
+        );
+        assert!(
+            bounds.max_fee_per_gas == 0
+                || max_fee_per_gas <= bounds.max_fee_per_gas as u128,
+            "Max fee per gas exceeds the chain's max_fee_per_gas of {}",
+            bounds.max_fee_per_gas
+        );
+        assert!(
+            bounds.max_priority_fee_per_gas == 0
+                || max_priority_fee_per_gas <= bounds.max_priority_fee_per_gas as u128,
+            "Max priority fee per gas exceeds the chain's max_priority_fee_per_gas of {}",
+            bounds.max_priority_fee_per_gas
+        );
+        assert!(
+            max_priority_fee_per_gas <= max_fee_per_gas,
+            "Max priority fee per gas cannot exceed max fee per gas"
+        );
+
+        (max_fee_per_gas, max_priority_fee_per_gas)
+    }
+}
@@ -0,0 +1,219 @@
+use crate::atlas::Atlas;
+use crate::constants::delimiter::COMMA;
+use crate::constants::network_type::EVM;
+use crate::constants::role::ROLE_DEPOSIT_OPERATOR;
+use crate::modules::events::emit_event;
+use crate::modules::structs::{EvmNonceRecord, NonceVoteRecord, PendingNonceRecord};
+use crate::AtlasExt;
+use near_sdk::{env, log, near_bindgen, AccountId};
+use serde_json::json;
+
+fn evm_nonce_key(chain_id: &str, path: &str) -> String {
+    format!("{}{}{}", chain_id, COMMA, path)
+}
+
+#[near_bindgen]
+impl Atlas {
+    pub fn get_evm_nonce(&self, chain_id: String) -> Option<EvmNonceRecord> {
+        self.evm_nonces
+            .get(&evm_nonce_key(&chain_id, &self.get_evm_signer_path(&chain_id)))
+            .cloned()
+    }
+
+    // Nonces handed out since the last resync, a nonce that stays pending while later ones are mined is a gap
+    pub fn get_pending_evm_nonces(&self, chain_id: String) -> Vec<PendingNonceRecord> {
+        self.get_evm_nonce(chain_id)
+            .map(|record| record.pending)
+            .unwrap_or_default()
+    }
+
+    // Votes for the transaction count of the chain's Atlas signer as read from the chain, replacing the caller's
+    // previous vote for the chain
+    // On-chain nonces only go up, so the verified nonce is the highest nonce that votes of at least the chain's
+    // validators_threshold are at or above, a validator voting too high cannot raise it on its own
+    // Returns true if the vote is counted and returns false if not
+    pub fn verify_evm_nonce(&mut self, chain_id: String, nonce: u64) -> bool {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+        let path = self.get_evm_signer_path(&chain_id);
+
+        if let Err(reason) = self.check_validator_voting_not_paused(&chain_id) {
+            log!("{}", reason);
+            return false;
+        }
+        if !self.is_validator(&caller, &chain_id) {
+            log!(
+                "Caller {} is not an authorized validator for the chain ID: {}",
+                &caller,
+                &chain_id
+            );
+            return false;
+        }
+
+        let key = evm_nonce_key(&chain_id, &path);
+        let mut record = self.get_or_init_evm_nonce(&chain_id, &path);
+        if record.verified_nonce.map_or(false, |verified_nonce| nonce <= verified_nonce) {
+            log!("Nonce {} is not above the verified nonce on chain ID: {}", nonce, &chain_id);
+            return false;
+        }
+        if let Some(previous) = record.votes.iter().find(|vote| vote.validator == caller) {
+            if nonce <= previous.nonce {
+                log!(
+                    "Caller {} has already voted nonce {} on chain ID: {}.",
+                    &caller,
+                    previous.nonce,
+                    &chain_id
+                );
+                return false;
+            }
+        }
+
+        record.votes.retain(|vote| vote.validator != caller);
+        record.votes.push(NonceVoteRecord {
+            validator: caller,
+            nonce,
+            submitted_at: env::block_timestamp() / 1_000_000_000,
+        });
+
+        if let Some(verified_nonce) = self.get_nonce_reached_by_threshold(&chain_id, &record.votes) {
+            if record.verified_nonce.map_or(true, |previous| verified_nonce > previous) {
+                // Nonces below the verified nonce are mined, nonces handed out from here on start at it
+                record.verified_nonce = Some(verified_nonce);
+                record.next_nonce = record.next_nonce.max(verified_nonce);
+                record.pending.retain(|pending| pending.nonce >= verified_nonce);
+
+                emit_event(
+                    "evm_nonce_verified",
+                    json!({
+                        "chain_id": chain_id,
+                        "path": path,
+                        "nonce": verified_nonce,
+                    }),
+                );
+            }
+        }
+        self.evm_nonces.insert(key, record);
+
+        true
+    }
+
+    // Resets the next nonce of the chain to the verified on-chain nonce when the relayer lost track of it
+    // Pending nonces below it were dropped once it was verified, the ones at or above it were never mined and are handed out again
+    pub fn resync_evm_nonce(&mut self, chain_id: String) {
+        self.assert_not_paused();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
+
+        let path = self.get_evm_signer_path(&chain_id);
+        let key = evm_nonce_key(&chain_id, &path);
+        let mut record = self.get_or_init_evm_nonce(&chain_id, &path);
+        let verified_nonce = record.verified_nonce.expect("No verified nonce for the chain");
+
+        let dropped: Vec<PendingNonceRecord> = record
+            .pending
+            .iter()
+            .filter(|pending| pending.nonce >= verified_nonce)
+            .cloned()
+            .collect();
+        let previous_nonce = record.next_nonce;
+        record.next_nonce = verified_nonce;
+        record.pending.clear();
+        self.evm_nonces.insert(key, record);
+
+        emit_event(
+            "evm_nonce_resynced",
+            json!({
+                "chain_id": chain_id,
+                "path": path,
+                "previous_nonce": previous_nonce,
+                "next_nonce": verified_nonce,
+                "dropped": dropped
+                    .iter()
+                    .map(|pending| {
+                        json!({
+                            "nonce": pending.nonce,
+                            "record_key": pending.record_key,
+                        })
+                    })
+                    .collect::<Vec<_>>(),
+            }),
+        );
+    }
+}
+
+impl Atlas {
+    // Hands out the next nonce of the chain's signer for a transaction signed for record_key
+    // Refused until the validators have verified the signer's nonce, next_nonce is seeded from it
+    pub fn next_evm_nonce(&mut self, chain_id: &str, path: &str, record_key: &str) -> u64 {
+        let mut record = self.get_or_init_evm_nonce(chain_id, path);
+        assert!(
+            record.verified_nonce.is_some(),
+            "No verified nonce for the chain, validators have to verify the signer's nonce first"
+        );
+        let nonce = record.next_nonce;
+
+        record.next_nonce += 1;
+        record.pending.push(PendingNonceRecord {
+            nonce,
+            record_key: record_key.to_string(),
+            signed_at: env::block_timestamp() / 1_000_000_000,
+        });
+        self.evm_nonces.insert(evm_nonce_key(chain_id, path), record);
+
+        nonce
+    }
+
+    // Transactions to a chain are signed with the chain's network type as derivation path
+    fn get_evm_signer_path(&self, chain_id: &str) -> String {
+        let chain_config = self
+            .chain_configs
+            .get_chain_config(chain_id.to_string())
+            .expect("Chain ID not found");
+        assert!(
+            chain_config.network_type == EVM,
+            "Chain ID is not an EVM chain"
+        );
+        chain_config.network_type
+    }
+
+    // Highest nonce that the votes of current validators reaching the chain's threshold are all at or above
+    // Weighted chains count validator weights, the others count validators
+    fn get_nonce_reached_by_threshold(&self, chain_id: &str, votes: &[NonceVoteRecord]) -> Option<u64> {
+        let chain_config = self.chain_configs.get_chain_config(chain_id.to_string())?;
+        let required_weight = self.get_required_verified_weight(chain_id.to_string());
+        let threshold = required_weight.unwrap_or(chain_config.validators_threshold as u64);
+
+        let mut votes: Vec<&NonceVoteRecord> = votes
+            .iter()
+            .filter(|vote| self.is_validator(&vote.validator, chain_id))
+            .collect();
+        votes.sort_unstable_by(|a, b| b.nonce.cmp(&a.nonce));
+
+        let mut accumulated = 0u64;
+        for vote in votes {
+            accumulated += if required_weight.is_some() {
+                self.get_validator_weight(vote.validator.clone(), chain_id.to_string())
+            } else {
+                1
+            };
+            if accumulated >= threshold {
+                return Some(vote.nonce);
+            }
+        }
+        None
+    }
+
+    fn get_or_init_evm_nonce(&self, chain_id: &str, path: &str) -> EvmNonceRecord {
+        self.evm_nonces
+            .get(&evm_nonce_key(chain_id, path))
+            .cloned()
+            .unwrap_or_else(|| EvmNonceRecord {
+                chain_id: chain_id.to_string(),
+                path: path.to_string(),
+                next_nonce: 0,
+                verified_nonce: None,
+                pending: vec![],
+                votes: vec![],
+            })
+    }
+}
@@ -0,0 +1,561 @@
+use crate::atlas::Atlas;
+use crate::constants::delimiter::COMMA;
+use crate::constants::network_type::*;
+use crate::constants::status::*;
+use crate::constants::vote_type::VOTE_EVM_BLOCK_HEADER;
+use crate::modules::structs::{
+    DepositRecord, EvmBlockHeaderRecord, EvmReceiptProofRecord, RedemptionRecord,
+};
+use crate::AtlasExt;
+use ethers_core::utils::rlp::{self, Rlp};
+use near_sdk::env::keccak256;
+use near_sdk::{env, log, near_bindgen, AccountId};
+
+// Positions of the fields used from an RLP-encoded EVM block header
+const HEADER_TRANSACTIONS_ROOT_INDEX: usize = 4;
+const HEADER_RECEIPTS_ROOT_INDEX: usize = 5;
+const HEADER_NUMBER_INDEX: usize = 8;
+
+fn to_nibbles(bytes: &[u8]) -> Vec<u8> {
+    bytes.iter().flat_map(|b| vec![b >> 4, b & 0x0f]).collect()
+}
+
+// Walks a Merkle-Patricia proof from the root and returns the value stored under key, if the proof is valid
+fn verify_mpt_proof(root: &[u8], key: &[u8], proof: &[Vec<u8>]) -> Option<Vec<u8>> {
+    let key_nibbles = to_nibbles(key);
+    let mut position = 0;
+    let mut expected_hash = root.to_vec();
+
+    for node_bytes in proof.iter() {
+        if keccak256(node_bytes) != expected_hash {
+            return None;
+        }
+
+        let mut node = Rlp::new(node_bytes);
+        // Children shorter than 32 bytes are embedded in their parent instead of referenced by hash
+        loop {
+            let child = match node.item_count().ok()? {
+                17 => {
+                    if position == key_nibbles.len() {
+                        return node.at(16).ok()?.data().ok().map(|value| value.to_vec());
+                    }
+                    let child = node.at(key_nibbles[position] as usize).ok()?;
+                    position += 1;
+                    child
+                }
+                2 => {
+                    let encoded_path = to_nibbles(node.at(0).ok()?.data().ok()?);
+                    let flag = *encoded_path.first()?;
+                    let path = if flag & 1 == 1 {
+                        &encoded_path[1..]
+                    } else {
+                        &encoded_path[2.min(encoded_path.len())..]
+                    };
+
+                    if !key_nibbles[position..].starts_with(path) {
+                        return None;
+                    }
+                    position += path.len();
+
+                    if flag >= 2 {
+                        // Leaf node, the whole key has to be consumed
+                        if position != key_nibbles.len() {
+                            return None;
+                        }
+                        return node.at(1).ok()?.data().ok().map(|value| value.to_vec());
+                    }
+                    node.at(1).ok()?
+                }
+                _ => return None,
+            };
+
+            if child.is_empty() {
+                return None;
+            }
+            if child.is_list() {
+                node = child;
+                continue;
+            }
+
+            expected_hash = child.data().ok()?.to_vec();
+            break;
+        }
+    }
+
+    None
+}
+
+// Typed receipts (EIP-2718) are prefixed with their type byte before the RLP payload
+fn strip_typed_envelope(encoded: &[u8]) -> &[u8] {
+    match encoded.first() {
+        Some(first) if *first < 0x80 => &encoded[1..],
+        _ => encoded,
+    }
+}
+
+fn normalize_evm_hex(value: &str) -> String {
+    value.trim_start_matches("0x").to_lowercase()
+}
+
+#[near_bindgen]
+impl Atlas {
+    // Co-signs an RLP-encoded EVM block header for the given chain
+    // Caller of this function has to be an authorised validator for the chain_id and a new validator of this header
+    // The header becomes usable for receipt proofs once its verified_count reaches the chain's validators_threshold
+    // Returns true if verified_count incremented successfully and returns false if not incremented
+    pub fn submit_evm_block_header(&mut self, chain_id: String, header_rlp_hex: String) -> bool {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+
+        let chain_config = self
+            .chain_configs
+            .get_chain_config(chain_id.clone())
+            .unwrap_or_else(|| env::panic_str("Chain ID not found"));
+        assert!(
+            chain_config.network_type == EVM.to_string(),
+            "Chain ID is not an EVM chain"
+        );
+
+        if !self.is_validator(&caller, &chain_id) {
+            log!(
+                "Caller {} is not an authorized validator for the chain ID: {}",
+                &caller,
+                &chain_id
+            );
+            return false;
+        }
+
+        let header_bytes =
+            hex::decode(header_rlp_hex.trim_start_matches("0x")).expect("Invalid hex in header");
+        let header = Rlp::new(&header_bytes);
+        let transactions_root: Vec<u8> = header
+            .val_at(HEADER_TRANSACTIONS_ROOT_INDEX)
+            .expect("Invalid transactions root in header");
+        let receipts_root: Vec<u8> = header
+            .val_at(HEADER_RECEIPTS_ROOT_INDEX)
+            .expect("Invalid receipts root in header");
+        let block_number: u64 = header
+            .val_at(HEADER_NUMBER_INDEX)
+            .expect("Invalid block number in header");
+
+        let block_hash = format!("0x{}", hex::encode(keccak256(&header_bytes)));
+        let header_key = format!("{}{}{}", chain_id, COMMA, block_hash);
+
+        // Retrieve the list of validators for this <chain_id>,<block_hash>
+        let mut validators_list = self.get_validators_by_txn_hash(header_key.clone());
+
+        if validators_list.contains(&caller) {
+            log!(
+                "Caller {} has already verified the header with block_hash: {} on chain ID: {}.",
+                &caller,
+                &block_hash,
+                &chain_id
+            );
+            return false;
+        }
+
+        let mut record = self
+            .evm_headers
+            .get(&header_key)
+            .cloned()
+            .unwrap_or_else(|| EvmBlockHeaderRecord {
+                chain_id: chain_id.clone(),
+                block_hash: block_hash.clone(),
+                block_number,
+                transactions_root: format!("0x{}", hex::encode(&transactions_root)),
+                receipts_root: format!("0x{}", hex::encode(&receipts_root)),
+                verified_count: 0,
+            });
+
+        record.verified_count += 1;
+        let verified_count = record.verified_count;
+        self.evm_headers.insert(header_key.clone(), record);
+
+        validators_list.push(caller.clone());
+        self.verifications.insert(header_key.clone(), validators_list);
+        self.record_validator_vote(&caller, VOTE_EVM_BLOCK_HEADER, &header_key, &header_key, &chain_id);
+
+        // The first header to reach the threshold at a height ends the votes on it and on competing headers of that height
+        let height_key = format!("{}{}{}", chain_id, COMMA, block_number);
+        if !self.evm_cosigned_headers.contains_key(&height_key)
+            && self.is_verification_threshold_met(
+                &header_key,
+                &chain_id,
+                verified_count,
+                chain_config.validators_threshold,
+            )
+        {
+            self.evm_cosigned_headers.insert(height_key, block_hash);
+        }
+
+        true
+    }
+
+    pub fn get_evm_block_header(
+        &self,
+        chain_id: String,
+        block_hash: String,
+    ) -> Option<EvmBlockHeaderRecord> {
+        self.evm_headers
+            .get(&format!("{}{}{}", chain_id, COMMA, block_hash))
+            .cloned()
+    }
+
+    pub fn get_evm_receipt_proof(&self, record_key: String) -> Option<EvmReceiptProofRecord> {
+        self.evm_receipt_proofs.get(&record_key).cloned()
+    }
+
+    // Proves the mint of a deposit with transaction and receipt proofs against a co-signed header of the receiving chain
+    // The transaction at tx_index must hash to the deposit's minted_txn_hash and its receipt must succeed and
+    // contain the MintDeposit event of the atBTC contract for the deposit's receiving address, btc_txn_hash and amount
+    // Returns true if the proof is accepted and returns false if not
+    pub fn verify_deposit_minted_by_receipt_proof(
+        &mut self,
+        btc_txn_hash: String,
+        block_hash: String,
+        tx_index: u64,
+        tx_proof: Vec<String>,
+        receipt_proof: Vec<String>,
+    ) -> bool {
+        self.assert_not_paused();
+
+        let deposit = match self.deposits.get(&btc_txn_hash) {
+            Some(deposit) => deposit.clone(),
+            None => {
+                log!("Deposit record not found for btc_txn_hash: {}.", &btc_txn_hash);
+                return false;
+            }
+        };
+
+        if deposit.status != DEP_BTC_PENDING_MINTED_INTO_ABTC
+            || deposit.minted_txn_hash.is_empty()
+            || !deposit.remarks.is_empty()
+        {
+            log!(
+                "Deposit is not pending mint for btc_txn_hash: {}. Status: {}, Minted txn hash: {}",
+                &btc_txn_hash,
+                deposit.status,
+                deposit.minted_txn_hash
+            );
+            return false;
+        }
+
+        let receipt = match self.verify_evm_transaction_receipt(
+            &deposit.receiving_chain_id,
+            &block_hash,
+            tx_index,
+            &tx_proof,
+            &receipt_proof,
+            &deposit.minted_txn_hash,
+        ) {
+            Some(receipt) => receipt,
+            None => return false,
+        };
+
+        let abtc_address = self
+            .chain_configs
+            .get_chain_config(deposit.receiving_chain_id.clone())
+            .unwrap()
+            .abtc_address;
+
+        if !Self::receipt_contains_abtc_event(
+            &receipt,
+            &abtc_address,
+            "MintDeposit(address,string,uint256)",
+            &deposit.receiving_address,
+            &deposit.btc_txn_hash,
+            deposit.btc_amount,
+        ) {
+            log!("MintDeposit event not found in receipt. Verification failed.");
+            return false;
+        }
+
+        self.record_evm_receipt_proof(btc_txn_hash, deposit.receiving_chain_id, block_hash, tx_index);
+        true
+    }
+
+    // Proves the burn of a redemption with transaction and receipt proofs against a co-signed header of the redemption chain
+    // The transaction at tx_index must hash to the EVM transaction hash in the redemption's txn_hash and its receipt must succeed and
+    // contain the BurnRedeem event of the atBTC contract for the redemption address, BTC receiving address and amount
+    // Returns true if the proof is accepted and returns false if not
+    pub fn verify_redemption_burnt_by_receipt_proof(
+        &mut self,
+        txn_hash: String,
+        block_hash: String,
+        tx_index: u64,
+        tx_proof: Vec<String>,
+        receipt_proof: Vec<String>,
+    ) -> bool {
+        self.assert_not_paused();
+
+        let redemption = match self.redemptions.get(&txn_hash) {
+            Some(redemption) => redemption.clone(),
+            None => {
+                log!("Redemption record not found for txn_hash: {}.", &txn_hash);
+                return false;
+            }
+        };
+
+        if redemption.status != RED_ABTC_BURNT || !redemption.remarks.is_empty() {
+            log!(
+                "Redemption is not in burnt status for txn_hash: {}. Status: {}",
+                &txn_hash,
+                redemption.status
+            );
+            return false;
+        }
+
+        // txn_hash is stored as <chain_id>,<evm txn hash>
+        let evm_txn_hash = match txn_hash.split_once(COMMA) {
+            Some((_, evm_txn_hash)) => evm_txn_hash.to_string(),
+            None => {
+                log!("Invalid redemption txn_hash format: {}.", &txn_hash);
+                return false;
+            }
+        };
+
+        let receipt = match self.verify_evm_transaction_receipt(
+            &redemption.abtc_redemption_chain_id,
+            &block_hash,
+            tx_index,
+            &tx_proof,
+            &receipt_proof,
+            &evm_txn_hash,
+        ) {
+            Some(receipt) => receipt,
+            None => return false,
+        };
+
+        let abtc_address = self
+            .chain_configs
+            .get_chain_config(redemption.abtc_redemption_chain_id.clone())
+            .unwrap()
+            .abtc_address;
+
+        if !Self::receipt_contains_abtc_event(
+            &receipt,
+            &abtc_address,
+            "BurnRedeem(address,string,uint256)",
+            &redemption.abtc_redemption_address,
+            &redemption.btc_receiving_address,
+            redemption.abtc_amount,
+        ) {
+            log!("BurnRedeem event not found in receipt. Verification failed.");
+            return false;
+        }
+
+        self.record_evm_receipt_proof(
+            txn_hash,
+            redemption.abtc_redemption_chain_id,
+            block_hash,
+            tx_index,
+        );
+        true
+    }
+}
+
+impl Atlas {
+    // A header is pending until it or a competing header of the same chain and block number is co-signed
+    pub(crate) fn is_evm_header_pending(&self, header_key: &str) -> bool {
+        self.evm_headers.get(header_key).map_or(false, |header| {
+            !self.evm_cosigned_headers.contains_key(&format!(
+                "{}{}{}",
+                header.chain_id, COMMA, header.block_number
+            ))
+        })
+    }
+
+    // A deposit's mint counts as verified once validators reach the threshold or a receipt proof is recorded
+    pub fn is_deposit_mint_verified(&self, deposit: &DepositRecord, validators_threshold: u8) -> bool {
+        self.is_verification_threshold_met(
+            &format!("{}{}{}", deposit.btc_txn_hash, COMMA, deposit.minted_txn_hash),
+            &deposit.receiving_chain_id,
+            deposit.minted_txn_hash_verified_count,
+            validators_threshold,
+        )
+            || self.evm_receipt_proofs.contains_key(&deposit.btc_txn_hash)
+    }
+
+    // A redemption's burn counts as verified once validators reach the threshold or a receipt proof is recorded
+    pub fn is_redemption_burn_verified(
+        &self,
+        redemption: &RedemptionRecord,
+        validators_threshold: u8,
+    ) -> bool {
+        self.is_verification_threshold_met(
+            &redemption.txn_hash,
+            &redemption.abtc_redemption_chain_id,
+            redemption.verified_count,
+            validators_threshold,
+        ) || self.evm_receipt_proofs.contains_key(&redemption.txn_hash)
+    }
+
+    // Checks both proofs against a co-signed header and returns the decoded receipt RLP if
+    // the transaction hashes to expected_txn_hash and its receipt reports success
+    fn verify_evm_transaction_receipt(
+        &self,
+        chain_id: &str,
+        block_hash: &str,
+        tx_index: u64,
+        tx_proof: &[String],
+        receipt_proof: &[String],
+        expected_txn_hash: &str,
+    ) -> Option<Vec<u8>> {
+        let chain_config = self.chain_configs.get_chain_config(chain_id.to_string())?;
+        if chain_config.network_type != EVM.to_string() {
+            log!("Chain ID {} is not an EVM chain.", chain_id);
+            return None;
+        }
+
+        let header_key = format!("{}{}{}", chain_id, COMMA, block_hash);
+        let header = match self.evm_headers.get(&header_key) {
+            Some(header) => header.clone(),
+            None => {
+                log!("Block header {} not found for chain ID: {}.", block_hash, chain_id);
+                return None;
+            }
+        };
+
+        if !self.is_verification_threshold_met(
+            &header_key,
+            chain_id,
+            header.verified_count,
+            chain_config.validators_threshold,
+        ) {
+            log!(
+                "Block header's verified_count ({}) is less than validators_threshold ({})",
+                header.verified_count,
+                chain_config.validators_threshold
+            );
+            return None;
+        }
+
+        let decode_proof = |proof: &[String]| -> Vec<Vec<u8>> {
+            proof
+                .iter()
+                .map(|node| hex::decode(node.trim_start_matches("0x")).expect("Invalid hex in proof node"))
+                .collect()
+        };
+        let key = rlp::encode(&tx_index).to_vec();
+
+        let transactions_root = hex::decode(normalize_evm_hex(&header.transactions_root)).unwrap();
+        let raw_tx = match verify_mpt_proof(&transactions_root, &key, &decode_proof(tx_proof)) {
+            Some(raw_tx) => raw_tx,
+            None => {
+                log!("Invalid transaction proof. Verification failed.");
+                return None;
+            }
+        };
+
+        if hex::encode(keccak256(&raw_tx)) != normalize_evm_hex(expected_txn_hash) {
+            log!("Proven transaction does not match txn hash {}.", expected_txn_hash);
+            return None;
+        }
+
+        let receipts_root = hex::decode(normalize_evm_hex(&header.receipts_root)).unwrap();
+        let encoded_receipt =
+            match verify_mpt_proof(&receipts_root, &key, &decode_proof(receipt_proof)) {
+                Some(encoded_receipt) => encoded_receipt,
+                None => {
+                    log!("Invalid receipt proof. Verification failed.");
+                    return None;
+                }
+            };
+
+        let receipt = strip_typed_envelope(&encoded_receipt).to_vec();
+        let status: Vec<u8> = Rlp::new(&receipt).val_at(0).unwrap_or_default();
+        if status != vec![1u8] {
+            log!("Proven transaction {} did not succeed.", expected_txn_hash);
+            return None;
+        }
+
+        Some(receipt)
+    }
+
+    // Looks for an atBTC event of the form Event(address indexed wallet, string text, uint256 amount)
+    fn receipt_contains_abtc_event(
+        receipt: &[u8],
+        abtc_address: &str,
+        event_signature: &str,
+        wallet: &str,
+        text: &str,
+        amount: u64,
+    ) -> bool {
+        let abtc_address = match hex::decode(normalize_evm_hex(abtc_address)) {
+            Ok(address) => address,
+            Err(_) => return false,
+        };
+        let wallet = match hex::decode(normalize_evm_hex(wallet)) {
+            Ok(wallet) if wallet.len() == 20 => wallet,
+            _ => return false,
+        };
+        let mut wallet_topic = vec![0u8; 12];
+        wallet_topic.extend_from_slice(&wallet);
+        let event_topic = keccak256(event_signature.as_bytes());
+
+        let receipt = Rlp::new(receipt);
+        let logs = match receipt.at(3) {
+            Ok(logs) => logs,
+            Err(_) => return false,
+        };
+
+        logs.iter().any(|log_entry| {
+            let address: Vec<u8> = log_entry.val_at(0).unwrap_or_default();
+            let topics: Vec<Vec<u8>> = log_entry.list_at(1).unwrap_or_default();
+            let data: Vec<u8> = log_entry.val_at(2).unwrap_or_default();
+
+            if address != abtc_address
+                || topics.len() < 2
+                || topics[0] != event_topic
+                || topics[1] != wallet_topic
+            {
+                return false;
+            }
+
+            match ethabi::decode(
+                &[ethabi::ParamType::String, ethabi::ParamType::Uint(256)],
+                &data,
+            ) {
+                Ok(tokens) => {
+                    tokens[0] == ethabi::Token::String(text.to_string())
+                        && tokens[1] == ethabi::Token::Uint(ethabi::Uint::from(amount))
+                }
+                Err(_) => false,
+            }
+        })
+    }
+
+    fn record_evm_receipt_proof(
+        &mut self,
+        record_key: String,
+        chain_id: String,
+        block_hash: String,
+        tx_index: u64,
+    ) {
+        let block_number = self
+            .evm_headers
+            .get(&format!("{}{}{}", chain_id, COMMA, block_hash))
+            .map(|header| header.block_number)
+            .unwrap_or(0);
+
+        log!(
+            "Receipt proof recorded for {} in block {} on chain ID: {}",
+            record_key,
+            block_hash,
+            chain_id
+        );
+
+        self.evm_receipt_proofs.insert(
+            record_key.clone(),
+            EvmReceiptProofRecord {
+                record_key,
+                chain_id,
+                block_hash,
+                block_number,
+                tx_index,
+                timestamp: env::block_timestamp() / 1_000_000_000,
+            },
+        );
+    }
+}
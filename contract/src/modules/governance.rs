@@ -0,0 +1,325 @@
+use crate::atlas::Atlas;
+use crate::constants::governance::*;
+use crate::constants::status::*;
+use crate::constants::validator_bond::BPS_DENOMINATOR;
+use crate::global_params::GlobalParams;
+use crate::modules::events::emit_event;
+use crate::modules::structs::{GovernanceParams, ProposalRecord, ProposalVote};
+use crate::AtlasExt;
+use near_sdk::{env, log, near_bindgen, AccountId};
+use serde_json::json;
+
+fn current_timestamp() -> u64 {
+    env::block_timestamp() / 1_000_000_000
+}
+
+fn parse_value<T: std::str::FromStr>(value: &str) -> T {
+    value
+        .parse::<T>()
+        .unwrap_or_else(|_| env::panic_str("Invalid proposal value"))
+}
+
+#[near_bindgen]
+impl Atlas {
+    // Enables governance with its first council, afterwards the council only changes through a set_governance_params proposal
+    pub fn set_governance_params(&mut self, governance_params: GovernanceParams) {
+        self.assert_not_paused();
+        self.assert_owner();
+
+        assert!(
+            !self.is_governance_enabled(),
+            "Governance params can only be changed through a proposal"
+        );
+        Self::assert_valid_governance_params(&governance_params);
+
+        self.governance_params = governance_params;
+    }
+
+    pub fn get_governance_params(&self) -> GovernanceParams {
+        self.governance_params.clone()
+    }
+
+    pub fn is_governance_enabled(&self) -> bool {
+        !self.governance_params.council_type.is_empty()
+    }
+
+    pub fn is_council_member(&self, account_id: AccountId) -> bool {
+        match self.governance_params.council_type.as_str() {
+            COUNCIL_VALIDATORS => self.validators.get(&account_id).map_or(false, |chain_ids| {
+                chain_ids
+                    .iter()
+                    .any(|chain_id| self.is_validator(&account_id, chain_id))
+            }),
+            COUNCIL_MEMBERS => self.governance_params.council_members.contains(&account_id),
+            _ => false,
+        }
+    }
+
+    pub fn get_council_size(&self) -> u64 {
+        match self.governance_params.council_type.as_str() {
+            COUNCIL_VALIDATORS => self
+                .validators
+                .keys()
+                .filter(|account_id| self.is_council_member(account_id.clone()))
+                .count() as u64,
+            COUNCIL_MEMBERS => self.governance_params.council_members.len() as u64,
+            _ => 0,
+        }
+    }
+
+    pub fn get_proposal(&self, proposal_id: u64) -> Option<ProposalRecord> {
+        self.proposals.get(&proposal_id).cloned()
+    }
+
+    pub fn get_proposals(&self) -> Vec<ProposalRecord> {
+        self.proposals.values().cloned().collect()
+    }
+
+    // Submits a typed parameter change, the value is checked against the current params before it is accepted
+    // The proposer's submission counts as its approving vote
+    pub fn submit_proposal(&mut self, proposal_type: String, value: String) -> u64 {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+        assert!(self.is_governance_enabled(), "Governance is not enabled");
+        assert!(
+            self.is_council_member(caller.clone()),
+            "Only a council member can submit a proposal"
+        );
+
+        self.validate_proposal(&proposal_type, &value);
+
+        self.proposal_nonce += 1;
+        let proposal_id = self.proposal_nonce;
+        self.proposals.insert(
+            proposal_id,
+            ProposalRecord {
+                proposal_id,
+                proposal_type: proposal_type.clone(),
+                value,
+                proposer: caller.clone(),
+                status: PROPOSAL_PENDING,
+                votes: Vec::new(),
+                created_at: current_timestamp(),
+                approved_at: 0,
+                executed_at: 0,
+            },
+        );
+
+        emit_event(
+            "proposal_submitted",
+            json!({
+                "proposal_id": proposal_id,
+                "proposal_type": proposal_type,
+                "proposer": caller,
+            }),
+        );
+
+        self.vote_on_proposal(proposal_id, true);
+
+        proposal_id
+    }
+
+    // Records a council member's vote, a proposal is approved or rejected as soon as either side reaches quorum
+    pub fn vote_on_proposal(&mut self, proposal_id: u64, approve: bool) {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+        assert!(
+            self.is_council_member(caller.clone()),
+            "Only a council member can vote on a proposal"
+        );
+
+        let mut proposal = self
+            .proposals
+            .get(&proposal_id)
+            .cloned()
+            .expect("Proposal not found");
+        assert!(proposal.status == PROPOSAL_PENDING, "Proposal is not pending");
+        assert!(
+            current_timestamp() < proposal.created_at + self.governance_params.voting_period,
+            "Voting period has ended"
+        );
+        assert!(
+            !proposal.votes.iter().any(|vote| vote.account_id == caller),
+            "Caller has already voted on this proposal"
+        );
+
+        proposal.votes.push(ProposalVote {
+            account_id: caller.clone(),
+            approve,
+            timestamp: current_timestamp(),
+        });
+
+        if self.is_proposal_quorum_met(&proposal, true) {
+            proposal.status = PROPOSAL_APPROVED;
+            proposal.approved_at = current_timestamp();
+            log!("Proposal {} approved", proposal_id);
+        } else if self.is_proposal_quorum_met(&proposal, false) {
+            proposal.status = PROPOSAL_REJECTED;
+            log!("Proposal {} rejected", proposal_id);
+        }
+
+        self.proposals.insert(proposal_id, proposal);
+
+        emit_event(
+            "proposal_voted",
+            json!({
+                "proposal_id": proposal_id,
+                "account_id": caller,
+                "approve": approve,
+            }),
+        );
+    }
+
+    // Applies an approved proposal once its timelock has passed, callable by anyone
+    // Quorum is counted again against the current council, so votes of members who left no longer count
+    pub fn execute_proposal(&mut self, proposal_id: u64) {
+        self.assert_not_paused();
+
+        let mut proposal = self
+            .proposals
+            .get(&proposal_id)
+            .cloned()
+            .expect("Proposal not found");
+        assert!(proposal.status == PROPOSAL_APPROVED, "Proposal is not approved");
+        assert!(
+            current_timestamp() >= proposal.approved_at + self.governance_params.timelock,
+            "Proposal timelock has not passed"
+        );
+        assert!(
+            self.is_proposal_quorum_met(&proposal, true),
+            "Proposal no longer has quorum"
+        );
+
+        self.apply_proposal(&proposal.proposal_type, &proposal.value);
+
+        proposal.status = PROPOSAL_EXECUTED;
+        proposal.executed_at = current_timestamp();
+        self.proposals.insert(proposal_id, proposal.clone());
+
+        emit_event(
+            "proposal_executed",
+            json!({
+                "proposal_id": proposal_id,
+                "proposal_type": proposal.proposal_type,
+                "value": proposal.value,
+            }),
+        );
+    }
+}
+
+impl Atlas {
+    // Direct owner changes of governed params are disabled once governance is enabled
+    pub fn assert_not_governed(&self) {
+        assert!(
+            !self.is_governance_enabled(),
+            "This change requires a governance proposal"
+        );
+    }
+
+    fn assert_valid_governance_params(governance_params: &GovernanceParams) {
+        match governance_params.council_type.as_str() {
+            COUNCIL_VALIDATORS => {}
+            COUNCIL_MEMBERS => assert!(
+                !governance_params.council_members.is_empty(),
+                "Council members cannot be empty"
+            ),
+            _ => env::panic_str("Invalid council type"),
+        }
+        assert!(
+            governance_params.quorum_bps > 0 && governance_params.quorum_bps as u128 <= BPS_DENOMINATOR,
+            "Quorum must be between 1 and 10000 basis points"
+        );
+        assert!(
+            governance_params.voting_period > 0,
+            "Voting period must be greater than 0"
+        );
+    }
+
+    // Votes of current council members on one side against quorum_bps of the current council size
+    fn is_proposal_quorum_met(&self, proposal: &ProposalRecord, approve: bool) -> bool {
+        let council_size = self.get_council_size() as u128;
+        if council_size == 0 {
+            return false;
+        }
+
+        let votes = proposal
+            .votes
+            .iter()
+            .filter(|vote| vote.approve == approve && self.is_council_member(vote.account_id.clone()))
+            .count() as u128;
+
+        votes * BPS_DENOMINATOR >= council_size * self.governance_params.quorum_bps as u128
+    }
+
+    // Rejects malformed values at submission, global params changes are applied to a copy of the current params
+    pub fn validate_proposal(&self, proposal_type: &str, value: &str) {
+        match proposal_type {
+            PROPOSAL_SET_CHAIN_CONFIGS => {
+                let config: serde_json::Value =
+                    serde_json::from_str(value).expect("Invalid JSON data for chain configs");
+                assert!(
+                    config.get("chains").and_then(|chains| chains.as_array()).is_some(),
+                    "Chain configs JSON must contain a chains array"
+                );
+            }
+            PROPOSAL_SET_GOVERNANCE_PARAMS => {
+                let governance_params: GovernanceParams =
+                    serde_json::from_str(value).expect("Invalid JSON data for governance params");
+                Self::assert_valid_governance_params(&governance_params);
+            }
+            _ => {
+                let mut global_params = self.global_params.clone();
+                Self::apply_global_params_proposal(&mut global_params, proposal_type, value);
+            }
+        }
+    }
+
+    pub fn apply_proposal(&mut self, proposal_type: &str, value: &str) {
+        match proposal_type {
+            PROPOSAL_SET_CHAIN_CONFIGS => {
+                self.chain_configs
+                    .apply_chain_configs_from_json(value.to_string());
+            }
+            PROPOSAL_SET_GOVERNANCE_PARAMS => {
+                let governance_params: GovernanceParams =
+                    serde_json::from_str(value).expect("Invalid JSON data for governance params");
+                Self::assert_valid_governance_params(&governance_params);
+                self.governance_params = governance_params;
+            }
+            _ => Self::apply_global_params_proposal(&mut self.global_params, proposal_type, value),
+        }
+    }
+
+    fn apply_global_params_proposal(global_params: &mut GlobalParams, proposal_type: &str, value: &str) {
+        match proposal_type {
+            PROPOSAL_SET_MPC_CONTRACT => global_params.apply_mpc_contract(parse_value(value)),
+            PROPOSAL_UPDATE_FEE_DEPOSIT_BPS => global_params.apply_fee_deposit_bps(parse_value(value)),
+            PROPOSAL_UPDATE_FEE_REDEMPTION_BPS => {
+                global_params.apply_fee_redemption_bps(parse_value(value))
+            }
+            PROPOSAL_UPDATE_FEE_BRIDGING_BPS => global_params.apply_fee_bridging_bps(parse_value(value)),
+            PROPOSAL_UPDATE_FEE_BABYLON_REWARDS_BPS => {
+                global_params.apply_fee_babylon_rewards_bps(parse_value(value))
+            }
+            PROPOSAL_UPDATE_FEE_VALIDATOR_REWARDS_BPS => {
+                global_params.apply_fee_validator_rewards_bps(parse_value(value))
+            }
+            PROPOSAL_UPDATE_BTC_STAKING_CAP => global_params.apply_btc_staking_cap(parse_value(value)),
+            PROPOSAL_UPDATE_BTC_MAX_STAKING_AMOUNT => {
+                global_params.apply_btc_max_staking_amount(parse_value(value))
+            }
+            PROPOSAL_UPDATE_BTC_MIN_STAKING_AMOUNT => {
+                global_params.apply_btc_min_staking_amount(parse_value(value))
+            }
+            PROPOSAL_UPDATE_TREASURY_ADDRESS => {
+                global_params.apply_treasury_address(value.to_string())
+            }
+            PROPOSAL_UPDATE_BTC_ATLAS_DEPOSIT_ADDRESS => {
+                global_params.apply_btc_atlas_deposit_address(value.to_string())
+            }
+            _ => env::panic_str("Invalid proposal type"),
+        }
+    }
+}
@@ -0,0 +1,424 @@
+use crate::atlas::Atlas;
+use crate::chain_configs::ChainConfigRecord;
+use crate::constants::mint_attempt::*;
+use crate::constants::near_gas::SIGN_CALLBACK_GAS;
+use crate::constants::network_type::EVM;
+use crate::constants::pause_scope::{PAUSE_BRIDGING, PAUSE_MINTING};
+use crate::constants::role::ROLE_DEPOSIT_OPERATOR;
+use crate::constants::status::{BRG_ABTC_PENDING_BRIDGE_FROM_ORIGIN_TO_DEST, DEP_BTC_PENDING_MINTED_INTO_ABTC};
+use crate::modules::events::emit_event;
+use crate::modules::signer::*;
+use crate::modules::structs::MintAttemptRecord;
+use crate::AtlasExt;
+use ethers_core::types::{H160, U256};
+use hex::FromHex;
+use near_sdk::env::keccak256;
+use near_sdk::json_types::U128;
+use near_sdk::{env, log, near_bindgen, NearToken, Promise, PromiseError};
+use omni_transaction::evm::evm_transaction::EVMTransaction;
+use omni_transaction::evm::types::Signature as OmniSignature;
+use omni_transaction::evm::utils::parse_eth_address;
+use omni_transaction::transaction_builder::{
+    TransactionBuilder as OmniTransactionBuilder, TxBuilder,
+};
+use omni_transaction::types::EVM as OmniEVM;
+use serde_json::json;
+
+// Lowest fee a replacement can be signed with, REPLACEMENT_FEE_BUMP_BPS above the fee of the previous attempt
+fn bumped_fee(fee: u128) -> u128 {
+    fee + (fee * REPLACEMENT_FEE_BUMP_BPS / 10_000).max(1)
+}
+
+#[near_bindgen]
+impl Atlas {
+    pub fn get_mint_attempts(&self, btc_txn_hash: String) -> Vec<MintAttemptRecord> {
+        self.mint_attempts
+            .get(&btc_txn_hash)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn get_bridging_mint_attempts(&self, txn_hash: String) -> Vec<MintAttemptRecord> {
+        self.mint_attempts.get(&txn_hash).cloned().unwrap_or_default()
+    }
+
+    // Re-signs the stuck mint of a deposit at the same nonce with higher fees
+    // Omitted fees default to the minimum bump over the previous attempt, or to the fee estimate if that is higher
+    pub fn speed_up_mint_abtc_tx(
+        &mut self,
+        btc_txn_hash: String,
+        gas: u128,
+        max_fee_per_gas: Option<u128>,
+        max_priority_fee_per_gas: Option<u128>,
+    ) -> Promise {
+        self.assert_not_paused();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
+
+        let (chain_config, mint_data) = self.get_replaceable_deposit_mint(&btc_txn_hash);
+        self.sign_mint_replacement(
+            btc_txn_hash,
+            MINT_ATTEMPT_SPEED_UP,
+            chain_config,
+            mint_data,
+            gas,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        )
+    }
+
+    // Signs a zero-value transfer from the signer to itself at the nonce of the stuck mint, so the mint can no longer land
+    // The deposit stays pending, the operator rolls it back once the cancellation is mined
+    pub fn cancel_mint_abtc_tx(
+        &mut self,
+        btc_txn_hash: String,
+        gas: u128,
+        max_fee_per_gas: Option<u128>,
+        max_priority_fee_per_gas: Option<u128>,
+    ) -> Promise {
+        self.assert_not_paused();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
+
+        let (chain_config, mint_data) = self.get_replaceable_deposit_mint(&btc_txn_hash);
+        self.sign_mint_replacement(
+            btc_txn_hash,
+            MINT_ATTEMPT_CANCEL,
+            chain_config,
+            mint_data,
+            gas,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        )
+    }
+
+    // Re-signs the stuck mintBridge of a bridging at the same nonce with higher fees, see speed_up_mint_abtc_tx
+    pub fn speed_up_bridging_abtc_tx(
+        &mut self,
+        txn_hash: String,
+        gas: u128,
+        max_fee_per_gas: Option<u128>,
+        max_priority_fee_per_gas: Option<u128>,
+    ) -> Promise {
+        self.assert_not_paused();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
+
+        let (chain_config, mint_data) = self.get_replaceable_bridging_mint(&txn_hash);
+        self.assert_scope_not_paused(PAUSE_BRIDGING, &chain_config.chain_id);
+        self.sign_mint_replacement(
+            txn_hash,
+            MINT_ATTEMPT_SPEED_UP,
+            chain_config,
+            mint_data,
+            gas,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        )
+    }
+
+    // Cancels the stuck mintBridge of a bridging with a zero-value self-transfer at its nonce, see cancel_mint_abtc_tx
+    pub fn cancel_bridging_abtc_tx(
+        &mut self,
+        txn_hash: String,
+        gas: u128,
+        max_fee_per_gas: Option<u128>,
+        max_priority_fee_per_gas: Option<u128>,
+    ) -> Promise {
+        self.assert_not_paused();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
+
+        let (chain_config, mint_data) = self.get_replaceable_bridging_mint(&txn_hash);
+        self.sign_mint_replacement(
+            txn_hash,
+            MINT_ATTEMPT_CANCEL,
+            chain_config,
+            mint_data,
+            gas,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        )
+    }
+
+    // Returns the signed transaction and records its hash and signer against the attempt of the deposit or bridging
+    #[private]
+    pub fn sign_mint_attempt_callback(
+        &mut self,
+        record_key: String,
+        attempt_index: u32,
+        evm_tx: EVMTransaction,
+        #[callback_result] result: Result<SignResult, PromiseError>,
+    ) -> Vec<u8> {
+        if let Ok(sign_result) = result {
+            let r_bytes =
+                Vec::from_hex(&sign_result.big_r.affine_point[2..]).expect("Invalid hex in r");
+            let s_bytes = Vec::from_hex(&sign_result.s.scalar).expect("Invalid hex in s");
+
+            let mut signature = r_bytes.clone();
+            signature.extend_from_slice(&s_bytes);
+            let from_address = env::ecrecover(
+                &keccak256(&evm_tx.build_for_signing()),
+                &signature,
+                sign_result.recovery_id,
+                false,
+            )
+            .map(|public_key| format!("0x{}", hex::encode(&keccak256(&public_key)[12..])))
+            .unwrap_or_default();
+
+            let signed_tx = evm_tx.build_with_signature(&OmniSignature {
+                v: sign_result.recovery_id as u64,
+                r: r_bytes,
+                s: s_bytes,
+            });
+
+            let mut attempts = self.get_mint_attempts(record_key.clone());
+            if let Some(attempt) = attempts.get_mut(attempt_index as usize) {
+                attempt.tx_hash = format!("0x{}", hex::encode(keccak256(&signed_tx)));
+                attempt.from_address = from_address;
+                log!(
+                    "Signed {} attempt {} for {}",
+                    attempt.attempt_type,
+                    attempt.tx_hash,
+                    record_key
+                );
+                self.mint_attempts.insert(record_key, attempts);
+            }
+
+            signed_tx
+        } else {
+            panic!("Callback failed");
+        }
+    }
+}
+
+impl Atlas {
+    // A mint can only be rolled back to be signed again at a new nonce when nothing was signed for it or its last signed
+    // attempt is a cancellation, otherwise the transaction signed before can still land next to the new one
+    pub(crate) fn is_mint_rollback_allowed(&self, record_key: &str) -> bool {
+        self.get_mint_attempts(record_key.to_string())
+            .iter()
+            .filter(|attempt| !attempt.tx_hash.is_empty())
+            .last()
+            .map_or(true, |attempt| attempt.attempt_type == MINT_ATTEMPT_CANCEL)
+    }
+
+    // Records the attempt against the deposit btc_txn_hash or bridging txn_hash and requests the MPC signature of its transaction
+    pub(crate) fn sign_mint_attempt(
+        &mut self,
+        record_key: String,
+        mut attempt: MintAttemptRecord,
+        evm_tx: EVMTransaction,
+        path: String,
+    ) -> Promise {
+        let evm_tx_hash = keccak256(&evm_tx.build_for_signing());
+        log!("Payload: [{}] {:?}", evm_tx_hash.len(), evm_tx_hash);
+
+        attempt.evm_tx = serde_json::to_string(&evm_tx).expect("Failed to serialize transaction");
+
+        let mut attempts = self.get_mint_attempts(record_key.clone());
+        let attempt_index = attempts.len() as u32;
+        attempts.push(attempt);
+        self.mint_attempts.insert(record_key.clone(), attempts);
+
+        ext_signer::ext(self.global_params.get_mpc_contract())
+            .with_attached_deposit(NearToken::from_millinear(500))
+            .sign(SignRequest::new(
+                evm_tx_hash
+                    .try_into()
+                    .unwrap_or_else(|e| panic!("Failed to convert payload {:?}", e)),
+                path,
+                0,
+            ))
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(SIGN_CALLBACK_GAS)
+                    .with_unused_gas_weight(0)
+                    .sign_mint_attempt_callback(record_key, attempt_index, evm_tx),
+            )
+    }
+
+    // The reported minted txn hash of an EVM mint has to be the hash of one of the record's signed mint or speed-up attempts
+    // Only records without any attempt are taken as is, NEAR mints are function calls that never record one
+    pub fn is_mint_attempt_txn_hash(&self, record_key: &str, minted_txn_hash: &str) -> bool {
+        let attempts = self.get_mint_attempts(record_key.to_string());
+
+        attempts.is_empty()
+            || attempts.iter().any(|attempt| {
+                attempt.attempt_type != MINT_ATTEMPT_CANCEL
+                    && !attempt.tx_hash.is_empty()
+                    && attempt.tx_hash.eq_ignore_ascii_case(minted_txn_hash)
+            })
+    }
+
+    // A mint can be replaced while it is signed but not yet reported as mined
+    // Returns the receiving chain and the mintDeposit call a speed-up re-signs
+    fn get_replaceable_deposit_mint(&self, btc_txn_hash: &str) -> (ChainConfigRecord, Vec<u8>) {
+        let deposit = self
+            .deposits
+            .get(btc_txn_hash)
+            .cloned()
+            .expect("Deposit record not found");
+        assert!(
+            deposit.status == DEP_BTC_PENDING_MINTED_INTO_ABTC,
+            "Deposit is not pending its mint"
+        );
+        assert!(
+            deposit.minted_txn_hash.is_empty(),
+            "Minted txn hash is already reported"
+        );
+
+        let chain_config = self
+            .chain_configs
+            .get_chain_config(deposit.receiving_chain_id.clone())
+            .expect("Chain ID not found");
+        assert!(chain_config.network_type == EVM, "Deposit is not minted on an EVM chain");
+
+        let destination = H160::from_slice(
+            &hex::decode(deposit.receiving_address.trim_start_matches("0x"))
+                .expect("Invalid hex address"),
+        );
+        let mint_data = Self::encode_mint_function_call(
+            destination,
+            U256::from(deposit.btc_amount),
+            btc_txn_hash.to_string(),
+        );
+
+        (chain_config, mint_data)
+    }
+
+    // A bridge mint can be replaced while it is signed but its destination txn hash is not yet reported
+    // Returns the destination chain and the mintBridge call a speed-up re-signs
+    fn get_replaceable_bridging_mint(&self, txn_hash: &str) -> (ChainConfigRecord, Vec<u8>) {
+        let bridging = self
+            .bridgings
+            .get(txn_hash)
+            .cloned()
+            .expect("Bridging record not found");
+        assert!(
+            bridging.status == BRG_ABTC_PENDING_BRIDGE_FROM_ORIGIN_TO_DEST,
+            "Bridging is not pending its mint"
+        );
+        assert!(
+            bridging.dest_txn_hash.is_empty(),
+            "Destination txn hash is already reported"
+        );
+
+        let chain_config = self
+            .chain_configs
+            .get_chain_config(bridging.dest_chain_id.clone())
+            .expect("Chain ID not found");
+        assert!(chain_config.network_type == EVM, "Bridging is not minted on an EVM chain");
+
+        (chain_config, Self::encode_bridging_mint_function_call(&bridging))
+    }
+
+    // Signs a speed-up or cancellation at the nonce of the latest attempt of the deposit or bridging
+    fn sign_mint_replacement(
+        &mut self,
+        record_key: String,
+        attempt_type: &str,
+        chain_config: ChainConfigRecord,
+        mint_data: Vec<u8>,
+        gas: u128,
+        max_fee_per_gas: Option<u128>,
+        max_priority_fee_per_gas: Option<u128>,
+    ) -> Promise {
+        assert!(gas != 0, "Gas cannot be zero");
+
+        let previous = self
+            .get_mint_attempts(record_key.clone())
+            .pop()
+            .expect("No signed mint transaction for the record");
+        let (to_address, data) = if attempt_type == MINT_ATTEMPT_SPEED_UP {
+            assert!(
+                previous.attempt_type != MINT_ATTEMPT_CANCEL,
+                "Mint transaction is already cancelled"
+            );
+            self.assert_scope_not_paused(PAUSE_MINTING, &chain_config.chain_id);
+
+            (chain_config.abtc_address.clone(), mint_data)
+        } else {
+            let from_address = self
+                .get_mint_attempts(record_key.clone())
+                .into_iter()
+                .map(|attempt| attempt.from_address)
+                .find(|from_address| !from_address.is_empty())
+                .expect("Signer address of the mint is not known yet");
+            (from_address, vec![])
+        };
+
+        let min_max_fee_per_gas = bumped_fee(previous.max_fee_per_gas.0);
+        let min_max_priority_fee_per_gas = bumped_fee(previous.max_priority_fee_per_gas.0);
+
+        let max_fee_per_gas = max_fee_per_gas.unwrap_or_else(|| {
+            self.get_evm_fee_estimate(chain_config.chain_id.clone())
+                .map_or(min_max_fee_per_gas, |estimate| {
+                    (estimate.max_fee_per_gas as u128).max(min_max_fee_per_gas)
+                })
+        });
+        let max_priority_fee_per_gas = max_priority_fee_per_gas.unwrap_or(
+            min_max_priority_fee_per_gas.max(
+                self.get_evm_gas_bounds(chain_config.chain_id.clone())
+                    .default_priority_fee_per_gas as u128,
+            ),
+        );
+        let (max_fee_per_gas, max_priority_fee_per_gas) = self.resolve_evm_fees(
+            &chain_config,
+            gas,
+            Some(max_fee_per_gas),
+            Some(max_priority_fee_per_gas),
+        );
+        assert!(
+            max_fee_per_gas >= min_max_fee_per_gas
+                && max_priority_fee_per_gas >= min_max_priority_fee_per_gas,
+            "Replacement fees have to be at least {} max fee and {} priority fee per gas",
+            min_max_fee_per_gas,
+            min_max_priority_fee_per_gas
+        );
+
+        let evm_tx = OmniTransactionBuilder::new::<OmniEVM>()
+            .nonce(previous.nonce)
+            .to(parse_eth_address(to_address.trim_start_matches("0x")))
+            .value(0)
+            .input(data)
+            .max_priority_fee_per_gas(max_priority_fee_per_gas)
+            .max_fee_per_gas(max_fee_per_gas)
+            .gas_limit(gas)
+            .chain_id(
+                chain_config
+                    .chain_id
+                    .parse::<u64>()
+                    .unwrap_or_else(|_| env::panic_str("Invalid chain ID format.")),
+            )
+            .build();
+
+        emit_event(
+            "mint_tx_replacement_requested",
+            json!({
+                "record_key": record_key,
+                "attempt_type": attempt_type,
+                "chain_id": chain_config.chain_id,
+                "nonce": previous.nonce,
+                "max_fee_per_gas": max_fee_per_gas.to_string(),
+                "max_priority_fee_per_gas": max_priority_fee_per_gas.to_string(),
+            }),
+        );
+
+        let attempt = MintAttemptRecord {
+            attempt_type: attempt_type.to_string(),
+            chain_id: chain_config.chain_id.clone(),
+            nonce: previous.nonce,
+            gas: U128(gas),
+            max_fee_per_gas: U128(max_fee_per_gas),
+            max_priority_fee_per_gas: U128(max_priority_fee_per_gas),
+            tx_hash: String::new(),
+            from_address: String::new(),
+            evm_tx: String::new(),
+            signed_at: env::block_timestamp() / 1_000_000_000,
+        };
+
+        self.sign_mint_attempt(
+            record_key,
+            attempt,
+            evm_tx,
+            chain_config.network_type.clone(),
+        )
+    }
+}
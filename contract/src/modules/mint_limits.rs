@@ -0,0 +1,258 @@
+use crate::atlas::Atlas;
+use crate::constants::mint_limit::*;
+use crate::constants::pause_scope::PAUSE_MINTING;
+use crate::constants::role::*;
+use crate::modules::events::emit_event;
+use crate::modules::structs::{MintAllowanceRecord, MintCapacity, MintLimitRecord, MintVolumeRecord};
+use crate::AtlasExt;
+use near_sdk::{env, near_bindgen};
+use serde_json::json;
+
+fn current_timestamp() -> u64 {
+    env::block_timestamp() / 1_000_000_000
+}
+
+// Remaining capacity under a limit, None for no limit
+fn remaining_capacity(limit: u64, used: u64) -> Option<u64> {
+    if limit == 0 {
+        None
+    } else {
+        Some(limit.saturating_sub(used))
+    }
+}
+
+// A limit raised by a reviewed allowance, no limit stays no limit
+fn allowed_limit(limit: u64, allowance: u64) -> u64 {
+    if limit == 0 {
+        0
+    } else {
+        limit + allowance
+    }
+}
+
+// The tighter of two remaining capacities
+fn min_capacity(a: Option<u64>, b: Option<u64>) -> Option<u64> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+#[near_bindgen]
+impl Atlas {
+    // Limits in sats minted per rolling hour and day on one chain, 0 removes a limit
+    pub fn set_chain_mint_limits(&mut self, chain_id: String, hourly_limit: u64, daily_limit: u64) {
+        self.assert_not_paused();
+        self.assert_role(ROLE_CONFIG_MANAGER);
+
+        assert!(
+            self.chain_configs.get_chain_config(chain_id.clone()).is_some(),
+            "Chain ID not found"
+        );
+
+        if hourly_limit == 0 && daily_limit == 0 {
+            self.mint_limits.remove(&chain_id);
+        } else {
+            self.mint_limits.insert(
+                chain_id,
+                MintLimitRecord {
+                    hourly_limit,
+                    daily_limit,
+                },
+            );
+        }
+    }
+
+    // Limits in sats minted per rolling hour and day across all chains, 0 removes a limit
+    pub fn set_global_mint_limits(&mut self, hourly_limit: u64, daily_limit: u64) {
+        self.assert_not_paused();
+        self.assert_role(ROLE_CONFIG_MANAGER);
+
+        self.global_mint_limits = MintLimitRecord {
+            hourly_limit,
+            daily_limit,
+        };
+    }
+
+    pub fn get_chain_mint_limits(&self, chain_id: String) -> MintLimitRecord {
+        self.mint_limits.get(&chain_id).cloned().unwrap_or_default()
+    }
+
+    // Allowance granted when the breaker of chain_id, or the global breaker for None, was last resumed
+    pub fn get_mint_allowance(&self, chain_id: Option<String>) -> Option<MintAllowanceRecord> {
+        self.mint_allowances.get(&chain_id.unwrap_or_default()).cloned()
+    }
+
+    pub fn get_global_mint_limits(&self) -> MintLimitRecord {
+        self.global_mint_limits.clone()
+    }
+
+    // Sats that can still be minted on chain_id, bounded by both the chain and the global limits
+    pub fn get_remaining_mint_capacity(&self, chain_id: String) -> MintCapacity {
+        let chain_limits = self.get_chain_mint_limits(chain_id.clone());
+        let global_limits = &self.global_mint_limits;
+        let chain_allowance = self.get_active_mint_allowance(&chain_id);
+        let global_allowance = self.get_active_mint_allowance("");
+
+        MintCapacity {
+            hourly_remaining: min_capacity(
+                remaining_capacity(
+                    allowed_limit(chain_limits.hourly_limit, chain_allowance),
+                    self.get_mint_volume(Some(&chain_id), MINT_HOURLY_WINDOW),
+                ),
+                remaining_capacity(
+                    allowed_limit(global_limits.hourly_limit, global_allowance),
+                    self.get_mint_volume(None, MINT_HOURLY_WINDOW),
+                ),
+            ),
+            daily_remaining: min_capacity(
+                remaining_capacity(
+                    allowed_limit(chain_limits.daily_limit, chain_allowance),
+                    self.get_mint_volume(Some(&chain_id), MINT_DAILY_WINDOW),
+                ),
+                remaining_capacity(
+                    allowed_limit(global_limits.daily_limit, global_allowance),
+                    self.get_mint_volume(None, MINT_DAILY_WINDOW),
+                ),
+            ),
+            chain_id,
+        }
+    }
+
+    // Lifts a minting pause set by the circuit breaker after review, None resumes a breaker pause on every chain
+    // Mint volumes are kept, allowance is the reviewed headroom in sats added to the resumed limits until the
+    // mints in the daily window have rolled out of it
+    pub fn resume_mint_circuit_breaker(&mut self, chain_id: Option<String>, allowance: u64) {
+        self.assert_role(ROLE_PAUSE_GUARDIAN);
+
+        let chain_id = chain_id.unwrap_or_default();
+        let paused_scope = self
+            .get_paused_scope(PAUSE_MINTING, &chain_id)
+            .expect("Minting is not paused");
+        assert!(
+            paused_scope.paused_by == env::current_account_id(),
+            "Minting was not paused by the circuit breaker"
+        );
+
+        self.remove_paused_scope(PAUSE_MINTING, &chain_id);
+        if allowance == 0 {
+            self.mint_allowances.remove(&chain_id);
+        } else {
+            self.mint_allowances.insert(
+                chain_id.clone(),
+                MintAllowanceRecord {
+                    amount: allowance,
+                    expires_at: current_timestamp() + MINT_DAILY_WINDOW,
+                    granted_by: env::predecessor_account_id(),
+                },
+            );
+        }
+
+        emit_event(
+            "mint_circuit_breaker_resumed",
+            json!({
+                "chain_id": chain_id,
+                "allowance": allowance,
+                "resumed_by": env::predecessor_account_id(),
+            }),
+        );
+    }
+}
+
+impl Atlas {
+    // Counts the mint of record_key toward the rolling windows of its chain, returns false without counting it if a limit
+    // would be exceeded
+    // Exceeding a chain limit pauses minting on that chain, exceeding a global limit pauses minting on every chain
+    // A record is counted once, signing it again after a failed signature or a rollback does not count its sats twice
+    pub fn record_mint_volume(&mut self, chain_id: &str, record_key: &str, amount: u64) -> bool {
+        let now = current_timestamp();
+        let mut volumes = self.mint_volumes.get(chain_id).cloned().unwrap_or_default();
+        if volumes
+            .iter()
+            .any(|volume| volume.record_key == record_key && volume.timestamp + MINT_DAILY_WINDOW > now)
+        {
+            return true;
+        }
+
+        let chain_limits = self.get_chain_mint_limits(chain_id.to_string());
+        let global_limits = self.global_mint_limits.clone();
+        let chain_allowance = self.get_active_mint_allowance(chain_id);
+        let global_allowance = self.get_active_mint_allowance("");
+
+        let windows = [
+            (
+                MINT_HOURLY_WINDOW,
+                allowed_limit(chain_limits.hourly_limit, chain_allowance),
+                allowed_limit(global_limits.hourly_limit, global_allowance),
+            ),
+            (
+                MINT_DAILY_WINDOW,
+                allowed_limit(chain_limits.daily_limit, chain_allowance),
+                allowed_limit(global_limits.daily_limit, global_allowance),
+            ),
+        ];
+        for (window, chain_limit, global_limit) in windows {
+            let chain_volume = self.get_mint_volume(Some(chain_id), window);
+            if chain_limit > 0 && chain_volume + amount > chain_limit {
+                self.trip_mint_circuit_breaker(chain_id, window, chain_limit, chain_volume, amount);
+                return false;
+            }
+
+            let global_volume = self.get_mint_volume(None, window);
+            if global_limit > 0 && global_volume + amount > global_limit {
+                self.trip_mint_circuit_breaker("", window, global_limit, global_volume, amount);
+                return false;
+            }
+        }
+
+        // Only the daily window is kept, older mints no longer count toward any limit
+        volumes.retain(|volume| volume.timestamp + MINT_DAILY_WINDOW > now);
+        volumes.push(MintVolumeRecord {
+            record_key: record_key.to_string(),
+            amount,
+            timestamp: now,
+        });
+        self.mint_volumes.insert(chain_id.to_string(), volumes);
+
+        true
+    }
+
+    // Sats of the allowance of chain_id, empty for global, that has not expired yet
+    fn get_active_mint_allowance(&self, chain_id: &str) -> u64 {
+        self.mint_allowances
+            .get(chain_id)
+            .filter(|allowance| allowance.expires_at > current_timestamp())
+            .map_or(0, |allowance| allowance.amount)
+    }
+
+    // Sats minted within the last window seconds on chain_id, or on all chains for None
+    fn get_mint_volume(&self, chain_id: Option<&str>, window: u64) -> u64 {
+        let now = current_timestamp();
+        self.mint_volumes
+            .iter()
+            .filter(|(volume_chain_id, _)| {
+                chain_id.map_or(true, |chain_id| chain_id == volume_chain_id.as_str())
+            })
+            .flat_map(|(_, volumes)| volumes.iter())
+            .filter(|volume| volume.timestamp + window > now)
+            .map(|volume| volume.amount)
+            .sum()
+    }
+
+    // Pauses minting as the contract itself, so resume_mint_circuit_breaker can tell breaker pauses from guardian pauses
+    fn trip_mint_circuit_breaker(&mut self, chain_id: &str, window: u64, limit: u64, volume: u64, amount: u64) {
+        self.insert_paused_scope(PAUSE_MINTING, chain_id, env::current_account_id());
+
+        emit_event(
+            "mint_circuit_breaker_tripped",
+            json!({
+                "chain_id": chain_id,
+                "window": window,
+                "limit": limit,
+                "volume": volume,
+                "amount": amount,
+            }),
+        );
+    }
+}
@@ -4,4 +4,25 @@ pub mod validation;
 pub mod utils;
 pub mod deposits;
 pub mod redemptions;
-pub mod admin;
\ No newline at end of file
+pub mod admin;
+pub mod btc_light_client;
+pub mod evm_receipt_proofs;
+pub mod attestations;
+pub mod validator_bonds;
+pub mod validator_weights;
+pub mod events;
+pub mod validator_votes;
+pub mod validator_stats;
+pub mod validator_rewards;
+pub mod disputes;
+pub mod governance;
+pub mod timelock;
+pub mod roles;
+pub mod pause_scopes;
+pub mod mint_limits;
+pub mod reserves;
+pub mod evm_admin_calls;
+pub mod bridgings;
+pub mod evm_nonces;
+pub mod evm_gas;
+pub mod mint_attempts;
@@ -0,0 +1,131 @@
+use crate::atlas::Atlas;
+use crate::constants::delimiter::COMMA;
+use crate::constants::pause_scope::*;
+use crate::constants::role::ROLE_PAUSE_GUARDIAN;
+use crate::modules::events::emit_event;
+use crate::modules::structs::PausedScopeRecord;
+use crate::AtlasExt;
+use near_sdk::{env, near_bindgen, AccountId};
+use serde_json::json;
+
+// A scope paused on every chain is keyed by the scope alone, a scope paused on one chain by <scope>,<chain_id>
+fn paused_scope_key(scope: &str, chain_id: &str) -> String {
+    if chain_id.is_empty() {
+        scope.to_string()
+    } else {
+        format!("{}{}{}", scope, COMMA, chain_id)
+    }
+}
+
+#[near_bindgen]
+impl Atlas {
+    // Pauses one flow, on every chain when chain_id is None, while the rest of the contract keeps running
+    // Not blocked by the global pause so guardians can narrow a global pause down before it is lifted
+    pub fn pause_scope(&mut self, scope: String, chain_id: Option<String>) {
+        self.assert_role(ROLE_PAUSE_GUARDIAN);
+
+        assert!(PAUSE_SCOPES.contains(&scope.as_str()), "Invalid pause scope");
+        let chain_id = chain_id.unwrap_or_default();
+        if !chain_id.is_empty() {
+            assert!(
+                self.chain_configs.get_chain_config(chain_id.clone()).is_some(),
+                "Chain ID not found"
+            );
+        }
+
+        assert!(
+            self.insert_paused_scope(&scope, &chain_id, env::predecessor_account_id()),
+            "Scope is already paused"
+        );
+    }
+
+    // Lifts the pause of the same scope and chain_id, owner only
+    pub fn unpause_scope(&mut self, scope: String, chain_id: Option<String>) {
+        self.assert_owner();
+
+        let chain_id = chain_id.unwrap_or_default();
+        assert!(
+            self.remove_paused_scope(&scope, &chain_id),
+            "Scope is not paused"
+        );
+    }
+
+    pub fn get_paused_scopes(&self) -> Vec<PausedScopeRecord> {
+        self.paused_scopes.values().cloned().collect()
+    }
+
+    // True if the scope is paused on every chain or on chain_id
+    pub fn is_scope_paused(&self, scope: String, chain_id: String) -> bool {
+        self.paused_scopes.contains_key(&scope)
+            || (!chain_id.is_empty()
+                && self
+                    .paused_scopes
+                    .contains_key(&paused_scope_key(&scope, &chain_id)))
+    }
+}
+
+impl Atlas {
+    // Records the pause and emits scope_paused, returns false if the scope was already paused on chain_id
+    pub fn insert_paused_scope(&mut self, scope: &str, chain_id: &str, paused_by: AccountId) -> bool {
+        let key = paused_scope_key(scope, chain_id);
+        if self.paused_scopes.contains_key(&key) {
+            return false;
+        }
+
+        self.paused_scopes.insert(
+            key,
+            PausedScopeRecord {
+                scope: scope.to_string(),
+                chain_id: chain_id.to_string(),
+                paused_by: paused_by.clone(),
+                paused_at: env::block_timestamp() / 1_000_000_000,
+            },
+        );
+
+        emit_event(
+            "scope_paused",
+            json!({
+                "scope": scope,
+                "chain_id": chain_id,
+                "paused_by": paused_by,
+            }),
+        );
+
+        true
+    }
+
+    pub fn get_paused_scope(&self, scope: &str, chain_id: &str) -> Option<PausedScopeRecord> {
+        self.paused_scopes.get(&paused_scope_key(scope, chain_id)).cloned()
+    }
+
+    pub fn remove_paused_scope(&mut self, scope: &str, chain_id: &str) -> bool {
+        let removed = self.paused_scopes.remove(&paused_scope_key(scope, chain_id)).is_some();
+        if removed {
+            emit_event(
+                "scope_unpaused",
+                json!({
+                    "scope": scope,
+                    "chain_id": chain_id,
+                }),
+            );
+        }
+        removed
+    }
+
+    pub fn assert_scope_not_paused(&self, scope: &str, chain_id: &str) {
+        assert!(
+            !self.is_scope_paused(scope.to_string(), chain_id.to_string()),
+            "The {} flow is paused for chain ID: {}",
+            scope,
+            chain_id
+        );
+    }
+
+    // Vote paths return rejection reasons instead of panicking so batch votes keep going
+    pub fn check_validator_voting_not_paused(&self, chain_id: &str) -> Result<(), String> {
+        if self.is_scope_paused(PAUSE_VALIDATOR_VOTING.to_string(), chain_id.to_string()) {
+            return Err(format!("Validator voting is paused for chain ID: {}", chain_id));
+        }
+        Ok(())
+    }
+}
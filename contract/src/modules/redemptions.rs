@@ -1,9 +1,13 @@
 use crate::atlas::Atlas;
 use crate::constants::status::*;
+use crate::constants::vote_type::*;
 use crate::constants::network_type::*;
+use crate::constants::pause_scope::*;
+use crate::constants::role::*;
 use crate::constants::delimiter::COMMA;
 use crate::modules::structs::RedemptionRecord;
 use crate::AtlasExt;
+use crate::VerificationResult;
 use near_sdk::{env, log, near_bindgen, AccountId};
 
 #[near_bindgen]
@@ -19,7 +23,8 @@ impl Atlas {
         date_created: u64,
     ) {
         self.assert_not_paused();
-        self.assert_admin();
+        self.assert_role(ROLE_REDEMPTION_OPERATOR);
+        self.assert_scope_not_paused(PAUSE_REDEMPTIONS, &abtc_redemption_chain_id);
 
         // Input validation
         assert!(!txn_hash.is_empty(), "Transaction hash cannot be empty");
@@ -61,6 +66,9 @@ impl Atlas {
             custody_txn_id: "".to_string(),
         };
 
+        self.update_chain_reserve(&record.abtc_redemption_chain_id, |reserve| {
+            reserve.burned += record.abtc_amount
+        });
         self.redemptions.insert(txn_hash, record);
     }
 
@@ -130,13 +138,15 @@ impl Atlas {
 
     pub fn update_redemption_start(&mut self, txn_hash: String) {
         self.assert_not_paused();
-        self.assert_admin();
+        self.assert_role(ROLE_REDEMPTION_OPERATOR);
 
         // Validate input
         assert!(!txn_hash.is_empty(), "Transaction hash cannot be empty");
 
         // Retrieve the redemption record based on txn_hash
         if let Some(mut redemption) = self.redemptions.get(&txn_hash).cloned() {
+            self.assert_scope_not_paused(PAUSE_REDEMPTIONS, &redemption.abtc_redemption_chain_id);
+
             // Fetch chain configuration for the redemption's chain_id
             if let Some(chain_config) = self
                 .chain_configs
@@ -144,7 +154,8 @@ impl Atlas {
             {
                 // Check all specified conditions
                 if redemption.status == RED_ABTC_BURNT
-                    && redemption.verified_count >= chain_config.validators_threshold
+                    && self.is_redemption_burn_verified(&redemption, chain_config.validators_threshold)
+                    && self.is_redemption_dispute_window_passed(&redemption)
                     && redemption.remarks.is_empty()
                     && redemption.btc_txn_hash.is_empty()
                 {
@@ -178,7 +189,7 @@ impl Atlas {
         btc_txn_hash: String,
     ) {
         self.assert_not_paused();
-        self.assert_admin();
+        self.assert_role(ROLE_REDEMPTION_OPERATOR);
 
         // Validate input parameters
         assert!(!txn_hash.is_empty(), "Transaction hash cannot be empty");
@@ -186,6 +197,8 @@ impl Atlas {
 
         // Retrieve the redemption record based on txn_hash
         if let Some(mut redemption) = self.redemptions.get(&txn_hash).cloned() {
+            self.assert_scope_not_paused(PAUSE_REDEMPTIONS, &redemption.abtc_redemption_chain_id);
+
             // Fetch chain configuration for the redemption's chain_id
             if let Some(chain_config) = self
                 .chain_configs
@@ -193,7 +206,7 @@ impl Atlas {
             {
                 // Check all specified conditions
                 if redemption.status == RED_BTC_PENDING_REDEMPTION_FROM_ATLAS_TO_USER
-                    && redemption.verified_count >= chain_config.validators_threshold
+                    && self.is_redemption_burn_verified(&redemption, chain_config.validators_threshold)
                     && redemption.remarks.is_empty()
                     && redemption.btc_txn_hash.is_empty()
                 {
@@ -229,7 +242,7 @@ impl Atlas {
         timestamp: u64,
     ) {
         self.assert_not_paused();
-        self.assert_admin();
+        self.assert_role(ROLE_REDEMPTION_OPERATOR);
 
         // Validate input parameters
         assert!(!txn_hash.is_empty(), "Transaction hash cannot be empty");
@@ -256,15 +269,33 @@ impl Atlas {
                 {
                     // Check all specified conditions
                     if (redemption.status == RED_BTC_PENDING_MEMPOOL_CONFIRMATION)
-                        && redemption.verified_count >= chain_config.validators_threshold
-                        && redemption.btc_txn_hash_verified_count >= btc_chain_config.validators_threshold
+                        && self.is_redemption_burn_verified(&redemption, chain_config.validators_threshold)
+                        && self.is_verification_threshold_met(
+                            &format!("{}{}{}", txn_hash, COMMA, btc_txn_hash),
+                            &btc_chain_id,
+                            redemption.btc_txn_hash_verified_count,
+                            btc_chain_config.validators_threshold,
+                        )
                         && redemption.remarks.is_empty()
                         && redemption.btc_txn_hash == btc_txn_hash
                     {
                         // All conditions are met, proceed to update the redemption status
                         redemption.status = RED_BTC_REDEEMED_BACK_TO_USER;                    
                         redemption.timestamp = timestamp;
+                        self.reserve_totals.redeemed += redemption.abtc_amount;
+                        let fee_amount = redemption.abtc_amount
+                            * self.global_params.get_fee_redemption_bps() as u64
+                            / 10000;
+                        let redemption_chain_id = redemption.abtc_redemption_chain_id.clone();
                         self.redemptions.insert(txn_hash.clone(), redemption);
+                        self.credit_validator_rewards(
+                            &txn_hash,
+                            fee_amount,
+                            &[
+                                (txn_hash.clone(), redemption_chain_id),
+                                (format!("{}{}{}", txn_hash, COMMA, btc_txn_hash), btc_chain_id.clone()),
+                            ],
+                        );
                         log!("Redemption status updated to RED_BTC_REDEEMED_BACK_TO_USER for txn_hash: {}", txn_hash);
                     } else {
                         // Panic with the expected message if conditions are not met
@@ -283,7 +314,7 @@ impl Atlas {
 
     pub fn update_redemption_remarks(&mut self, txn_hash: String, remarks: String) {
         self.assert_not_paused();
-        self.assert_admin();
+        self.assert_role(ROLE_REDEMPTION_OPERATOR);
 
         // Validate input parameters
         assert!(!txn_hash.is_empty(), "Transaction hash cannot be empty");
@@ -298,7 +329,7 @@ impl Atlas {
             {
                 // Check all specified conditions
                 if redemption.status != RED_BTC_REDEEMED_BACK_TO_USER
-                    && redemption.verified_count >= chain_config.validators_threshold
+                    && self.is_redemption_burn_verified(&redemption, chain_config.validators_threshold)
                 {
                     // All conditions are met, proceed to update the remarks
                     redemption.remarks = remarks;
@@ -326,7 +357,7 @@ impl Atlas {
 
     pub fn update_redemption_custody_txn_id(&mut self, txn_hash: String, custody_txn_id: String) {
         self.assert_not_paused();
-        self.assert_admin();
+        self.assert_role(ROLE_REDEMPTION_OPERATOR);
 
         // Validate input parameters
         assert!(!txn_hash.is_empty(), "Transaction hash cannot be empty");
@@ -345,7 +376,7 @@ impl Atlas {
                 // Check all specified conditions
                 if redemption.status == RED_BTC_PENDING_REDEMPTION_FROM_ATLAS_TO_USER
                     && redemption.remarks.is_empty()
-                    && redemption.verified_count >= chain_config.validators_threshold
+                    && self.is_redemption_burn_verified(&redemption, chain_config.validators_threshold)
                     && redemption.btc_txn_hash.is_empty()
                 {
                     // All conditions are met, proceed to update the custody_txn_id
@@ -469,7 +500,13 @@ impl Atlas {
                     .get_chain_config(redemption.abtc_redemption_chain_id.clone())
                 {
                     // Ensure that the verified_count meets or exceeds the validators_threshold
-                    if redemption.verified_count >= chain_config.validators_threshold {
+                    if self.is_redemption_burn_verified(&redemption, chain_config.validators_threshold)
+                        && self.is_redemption_dispute_window_passed(&redemption)
+                        && !self.is_scope_paused(
+                            PAUSE_REDEMPTIONS.to_string(),
+                            redemption.abtc_redemption_chain_id.clone(),
+                        )
+                    {
                         log!(
                             "Found valid redemption with txn_hash: {} and verified_count: {} (threshold: {})",
                             txn_hash,
@@ -496,32 +533,68 @@ impl Atlas {
     ) -> bool {
         self.assert_not_paused();
 
+        let caller: AccountId = env::predecessor_account_id();
+
+        match self.apply_redemption_verification(&caller, mempool_redemption) {
+            Ok(()) => true, // success case returns true
+            Err(reason) => {
+                log!("{}", reason);
+                false
+            }
+        }
+    }
+
+    // Batch version of increment_redemption_verified_count
+    // Each mempool_redemption is verified independently, a rejected record does not abort the rest of the batch
+    // Returns one result per mempool_redemption, in the same order, with the reason for every rejected record
+    pub fn batch_increment_redemption_verified_count(
+        &mut self,
+        mempool_redemptions: Vec<RedemptionRecord>,
+    ) -> Vec<VerificationResult> {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+
+        mempool_redemptions
+            .into_iter()
+            .map(|mempool_redemption| {
+                let key = mempool_redemption.txn_hash.clone();
+                VerificationResult::from_outcome(
+                    key,
+                    self.apply_redemption_verification(&caller, mempool_redemption),
+                )
+            })
+            .collect()
+    }
+
+    // Applies the caller's vote for increment_redemption_verified_count, returns the reason if the vote is rejected
+    pub(crate) fn apply_redemption_verification(
+        &mut self,
+        caller: &AccountId,
+        mempool_redemption: RedemptionRecord,
+    ) -> Result<(), String> {
         // Validate the mempool_redemption
         if mempool_redemption.txn_hash.is_empty() {
-            log!("Invalid mempool_redemption: txn_hash is empty");
-            return false;
+            return Err("Invalid mempool_redemption: txn_hash is empty".to_string());
         }
-        
-        let caller: AccountId = env::predecessor_account_id();
 
         // Retrieve the redemption record using the txn_hash
         if let Some(mut redemption) = self.redemptions.get(&mempool_redemption.txn_hash).cloned() {
             let chain_id = redemption.abtc_redemption_chain_id.clone();
+            self.check_validator_voting_not_paused(&chain_id)?;
 
             // Use the is_validator function to check if the caller is authorized for the redemption chain ID
-            if self.is_validator(&caller, &chain_id) {
+            if self.is_validator(caller, &chain_id) {
                 // Retrieve the list of validators for this txn_hash using the getter method
                 let mut validators_list =
                     self.get_validators_by_txn_hash(redemption.txn_hash.clone());
 
                 // Check if the caller has already verified this txn_hash
-                if validators_list.contains(&caller) {
-                    log!(
+                if validators_list.contains(caller) {
+                    return Err(format!(
                         "Caller {} has already verified the transaction with txn_hash: {}.",
-                        &caller,
-                        &redemption.txn_hash
-                    );
-                    return false;
+                        caller, &redemption.txn_hash
+                    ));
                 }
 
                 // Verify that all fields of redemption and mempool_redemption are equal
@@ -536,8 +609,7 @@ impl Atlas {
                     || redemption.status != RED_ABTC_BURNT
                     || redemption.remarks != mempool_redemption.remarks
                 {
-                    log!("Mismatch between near_redemption and mempool_redemption records. Verification failed.");
-                    return false;
+                    return Err("Mismatch between near_redemption and mempool_redemption records. Verification failed.".to_string());
                 }
 
                 // Increment the verified count
@@ -548,25 +620,29 @@ impl Atlas {
                     .insert(mempool_redemption.txn_hash.clone(), redemption);
 
                 // Add the caller to the list of validators for this txn_hash
-                validators_list.push(caller);
+                validators_list.push(caller.clone());
                 self.verifications
-                    .insert(mempool_redemption.txn_hash, validators_list);
+                    .insert(mempool_redemption.txn_hash.clone(), validators_list);
+                self.record_validator_vote(
+                    caller,
+                    VOTE_REDEMPTION_VERIFIED,
+                    &mempool_redemption.txn_hash,
+                    &mempool_redemption.txn_hash,
+                    &chain_id,
+                );
 
-                true // success case returns true
+                Ok(())
             } else {
-                log!(
+                Err(format!(
                     "Caller {} is not an authorized validator for the chain ID: {}",
-                    &caller,
-                    &chain_id
-                );
-                return false;
+                    caller, &chain_id
+                ))
             }
         } else {
-            log!(
+            Err(format!(
                 "Redemption record not found for txn_hash: {}.",
                 &mempool_redemption.txn_hash
-            );
-            return false;
+            ))
         }
     }
 
@@ -578,14 +654,52 @@ impl Atlas {
     pub fn increment_redemption_btc_txn_hash_verified_count(&mut self, txn_hash: String, btc_txn_hash: String) -> bool {
         self.assert_not_paused();
 
-        // Validate input parameters
-        if txn_hash.is_empty() || btc_txn_hash.is_empty() {
-            log!("Invalid input: txn_hash or btc_txn_hash is empty");
-            return false;
+        let caller: AccountId = env::predecessor_account_id();
+
+        match self.apply_redemption_btc_txn_hash_verification(&caller, txn_hash, btc_txn_hash) {
+            Ok(()) => true, // success case returns true
+            Err(reason) => {
+                log!("{}", reason);
+                false
+            }
         }
+    }
+
+    // Batch version of increment_redemption_btc_txn_hash_verified_count taking (txn_hash, btc_txn_hash) pairs
+    // Each pair is verified independently, a rejected pair does not abort the rest of the batch
+    // Returns one result per pair, in the same order, with the reason for every rejected pair
+    pub fn batch_increment_redemption_btc_txn_hash_verified_count(
+        &mut self,
+        btc_txn_hashes: Vec<(String, String)>,
+    ) -> Vec<VerificationResult> {
+        self.assert_not_paused();
 
         let caller: AccountId = env::predecessor_account_id();
 
+        btc_txn_hashes
+            .into_iter()
+            .map(|(txn_hash, btc_txn_hash)| {
+                let key = format!("{}{}{}", txn_hash, COMMA, btc_txn_hash);
+                VerificationResult::from_outcome(
+                    key,
+                    self.apply_redemption_btc_txn_hash_verification(&caller, txn_hash, btc_txn_hash),
+                )
+            })
+            .collect()
+    }
+
+    // Applies the caller's vote for increment_redemption_btc_txn_hash_verified_count, returns the reason if the vote is rejected
+    pub(crate) fn apply_redemption_btc_txn_hash_verification(
+        &mut self,
+        caller: &AccountId,
+        txn_hash: String,
+        btc_txn_hash: String,
+    ) -> Result<(), String> {
+        // Validate input parameters
+        if txn_hash.is_empty() || btc_txn_hash.is_empty() {
+            return Err("Invalid input: txn_hash or btc_txn_hash is empty".to_string());
+        }
+
         // Retrieve the redemption record using the txn_hash
         if let Some(mut redemption) = self.redemptions.get(&txn_hash).cloned() {
             let btc_chain_id = if self.is_production_mode() {
@@ -593,9 +707,10 @@ impl Atlas {
             } else {
                 SIGNET.to_string()
             };
+            self.check_validator_voting_not_paused(&btc_chain_id)?;
 
             // Check if the caller is an authorized validator for the bitcoin chain
-            if self.is_validator(&caller, &btc_chain_id) {
+            if self.is_validator(caller, &btc_chain_id) {
 
                 // Create a unique key for the verifications map using the COMMA constant
                 let verification_key = format!("{}{}{}", txn_hash, COMMA, btc_txn_hash);
@@ -604,14 +719,11 @@ impl Atlas {
                 let mut validators_list = self.get_validators_by_txn_hash(verification_key.clone());
 
                 // Check if the caller has already verified this <txn_hash>,<btc_txn_hash>
-                if validators_list.contains(&caller) {
-                    log!(
+                if validators_list.contains(caller) {
+                    return Err(format!(
                         "Caller {} has already verified the transaction with txn_hash: {} and btc_txn_hash: {}.",
-                        &caller,
-                        &txn_hash,
-                        &btc_txn_hash
-                    );
-                    return false;
+                        caller, &txn_hash, &btc_txn_hash
+                    ));
                 }
 
                 // Verify that the redemption record's txn_hash and btc_txn_hash match the input parameters
@@ -623,28 +735,31 @@ impl Atlas {
                     self.redemptions.insert(txn_hash.clone(), redemption);
 
                     // Add the caller to the list of validators for this <txn_hash>,<btc_txn_hash>
-                    validators_list.push(caller);
-                    self.verifications.insert(verification_key, validators_list);
+                    validators_list.push(caller.clone());
+                    self.verifications.insert(verification_key.clone(), validators_list);
+                    self.record_validator_vote(
+                        caller,
+                        VOTE_REDEMPTION_BTC_TXN_HASH,
+                        &txn_hash,
+                        &verification_key,
+                        &btc_chain_id,
+                    );
 
-                    true // success case returns true
+                    Ok(())
                 } else {
-                    log!("Mismatch between redemption record and input parameters. Verification failed.");
-                    false
+                    Err("Mismatch between redemption record and input parameters. Verification failed.".to_string())
                 }
             } else {
-                log!(
+                Err(format!(
                     "Caller {} is not an authorized validator for the bitcoin chain: {}",
-                    &caller,
-                    &btc_chain_id
-                );
-                false
+                    caller, &btc_chain_id
+                ))
             }
         } else {
-            log!(
+            Err(format!(
                 "Redemption record not found for txn_hash: {}.",
                 &txn_hash
-            );
-            false
+            ))
         }
     }
 }
@@ -0,0 +1,310 @@
+use crate::atlas::Atlas;
+use crate::constants::near_gas::*;
+use crate::constants::network_type::{EVM, NEAR};
+use crate::constants::pause_scope::PAUSE_MINTING;
+use crate::constants::reserve_attestation::*;
+use crate::constants::role::*;
+use crate::constants::status::*;
+use crate::modules::events::emit_event;
+use crate::modules::signer::*;
+use crate::modules::structs::{
+    ChainReserveRecord, ChainSupply, ReserveAttestationRecord, ReserveTotalsRecord, ReservesReport,
+};
+use crate::AtlasExt;
+use ethabi::{Token, Uint};
+use near_sdk::env::keccak256;
+use near_sdk::json_types::U128;
+use near_sdk::{env, log, near_bindgen, NearToken, Promise, PromiseError};
+use serde_json::json;
+
+// keccak256("\x19\x01" || domainSeparator || hashStruct(attestation)), as computed by EIP-712 verifiers
+fn reserve_attestation_digest(attestation: &ReserveAttestationRecord) -> Vec<u8> {
+    let domain_separator = keccak256(&ethabi::encode(&[
+        Token::FixedBytes(keccak256(EIP712_DOMAIN_TYPE.as_bytes())),
+        Token::FixedBytes(keccak256(EIP712_DOMAIN_NAME.as_bytes())),
+        Token::FixedBytes(keccak256(EIP712_DOMAIN_VERSION.as_bytes())),
+        Token::FixedBytes(keccak256(env::current_account_id().as_str().as_bytes())),
+    ]));
+
+    let struct_hash = keccak256(&ethabi::encode(&[
+        Token::FixedBytes(keccak256(RESERVE_ATTESTATION_TYPE.as_bytes())),
+        Token::Uint(Uint::from(attestation.deposited)),
+        Token::Uint(Uint::from(attestation.redeemed)),
+        Token::Uint(Uint::from(attestation.refunded)),
+        Token::Uint(Uint::from(attestation.minted)),
+        Token::Uint(Uint::from(attestation.burned)),
+        Token::Uint(Uint::from(attestation.block_height)),
+        Token::Uint(Uint::from(attestation.timestamp)),
+    ]));
+
+    let mut message = vec![0x19, 0x01];
+    message.extend_from_slice(&domain_separator);
+    message.extend_from_slice(&struct_hash);
+    keccak256(&message)
+}
+
+#[near_bindgen]
+impl Atlas {
+    // BTC held by Atlas against the atBTC expected to circulate on each chain
+    // A healthy protocol has expected_circulating_supply no greater than btc_reserve
+    pub fn get_reserves(&self) -> ReservesReport {
+        let totals = &self.reserve_totals;
+
+        let chains: Vec<ChainSupply> = self
+            .chain_reserves
+            .iter()
+            .map(|(chain_id, reserve)| ChainSupply {
+                chain_id: chain_id.clone(),
+                minted: reserve.minted,
+                burned: reserve.burned,
+                circulating_supply: reserve.minted as i64 - reserve.burned as i64,
+            })
+            .collect();
+
+        ReservesReport {
+            deposited: totals.deposited,
+            redeemed: totals.redeemed,
+            refunded: totals.refunded,
+            btc_reserve: totals.deposited as i64 - (totals.redeemed + totals.refunded) as i64,
+            minted: chains.iter().map(|chain| chain.minted).sum(),
+            burned: chains.iter().map(|chain| chain.burned).sum(),
+            expected_circulating_supply: chains.iter().map(|chain| chain.circulating_supply).sum(),
+            chains,
+        }
+    }
+
+    // Recomputes the running totals from the stored records, for deployments that predate them
+    pub fn rebuild_reserve_totals(&mut self) {
+        self.assert_owner();
+
+        let mut totals = ReserveTotalsRecord::default();
+        self.chain_reserves.clear();
+
+        let deposits: Vec<_> = self.deposits.values().cloned().collect();
+        for deposit in deposits {
+            if deposit.status != DEP_BTC_PENDING_MEMPOOL {
+                totals.deposited += deposit.btc_amount;
+            }
+            if deposit.status == DEP_BTC_REFUNDED {
+                totals.refunded += deposit.btc_amount;
+            }
+            if deposit.status == DEP_BTC_MINTED_INTO_ABTC {
+                self.update_chain_reserve(&deposit.receiving_chain_id, |reserve| {
+                    reserve.minted += deposit.btc_amount
+                });
+            }
+        }
+
+        let redemptions: Vec<_> = self.redemptions.values().cloned().collect();
+        for redemption in redemptions {
+            if redemption.status == RED_BTC_REDEEMED_BACK_TO_USER {
+                totals.redeemed += redemption.abtc_amount;
+            }
+            self.update_chain_reserve(&redemption.abtc_redemption_chain_id, |reserve| {
+                reserve.burned += redemption.abtc_amount
+            });
+        }
+
+        // Bridged atBTC is burned on the origin chain and counts on the destination chain once its mint is verified
+        // or its NEAR mint completed
+        let bridgings: Vec<_> = self.bridgings.values().cloned().collect();
+        for bridging in bridgings {
+            self.update_chain_reserve(&bridging.origin_chain_id, |reserve| {
+                reserve.burned += bridging.abtc_amount
+            });
+            if bridging.status == BRG_ABTC_MINTED_TO_DEST {
+                self.update_chain_reserve(&bridging.dest_chain_id, |reserve| {
+                    reserve.minted += bridging.abtc_amount - bridging.fee_amount
+                });
+            }
+        }
+
+        self.reserve_totals = totals;
+
+        emit_event(
+            "reserve_totals_rebuilt",
+            json!({
+                "deposited": self.reserve_totals.deposited,
+                "redeemed": self.reserve_totals.redeemed,
+                "refunded": self.reserve_totals.refunded,
+            }),
+        );
+    }
+
+    pub fn get_reserve_attestation(&self) -> Option<ReserveAttestationRecord> {
+        self.reserve_attestation.clone()
+    }
+
+    // Signs an EIP-712 statement of the current reserve totals through the MPC signer
+    // The EVM derivation path is the one minting uses, so verifiers recover the same address that mints atBTC
+    pub fn create_reserve_attestation(&mut self) -> Promise {
+        self.assert_not_paused();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
+
+        let reserves = self.get_reserves();
+        let mut attestation = ReserveAttestationRecord {
+            deposited: reserves.deposited,
+            redeemed: reserves.redeemed,
+            refunded: reserves.refunded,
+            minted: reserves.minted,
+            burned: reserves.burned,
+            block_height: env::block_height(),
+            timestamp: env::block_timestamp() / 1_000_000_000,
+            digest: "".to_string(),
+            signature: "".to_string(),
+        };
+        let digest = reserve_attestation_digest(&attestation);
+        attestation.digest = format!("0x{}", hex::encode(&digest));
+
+        ext_signer::ext(self.global_params.get_mpc_contract())
+            .with_attached_deposit(NearToken::from_millinear(500))
+            .sign(SignRequest::new(
+                digest
+                    .try_into()
+                    .unwrap_or_else(|e| panic!("Failed to convert payload {:?}", e)),
+                EVM.to_string(),
+                0,
+            ))
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(SIGN_CALLBACK_GAS)
+                    .reserve_attestation_callback(attestation),
+            )
+    }
+
+    // Stores the signed attestation unless a newer one was stored while this one was being signed
+    #[private]
+    pub fn reserve_attestation_callback(
+        &mut self,
+        attestation: ReserveAttestationRecord,
+        #[callback_result] result: Result<SignResult, PromiseError>,
+    ) -> Option<ReserveAttestationRecord> {
+        let sign_result = match result {
+            Ok(sign_result) => sign_result,
+            Err(_) => {
+                log!("Failed to sign the reserve attestation at block {}", attestation.block_height);
+                return None;
+            }
+        };
+
+        if let Some(latest) = &self.reserve_attestation {
+            if latest.block_height > attestation.block_height {
+                log!("A newer reserve attestation is already stored");
+                return None;
+            }
+        }
+
+        // big_r is the compressed point, its x coordinate is r
+        let mut attestation = attestation;
+        attestation.signature = format!(
+            "0x{}{}{:02x}",
+            &sign_result.big_r.affine_point[2..],
+            sign_result.s.scalar,
+            sign_result.recovery_id + 27
+        )
+        .to_lowercase();
+        self.reserve_attestation = Some(attestation.clone());
+
+        emit_event(
+            "reserves_attested",
+            json!({
+                "block_height": attestation.block_height,
+                "digest": attestation.digest,
+                "signature": attestation.signature,
+            }),
+        );
+
+        Some(attestation)
+    }
+
+    // Drift in sats between the NEAR atBTC supply and the recorded supply that pauses NEAR minting, 0 never pauses
+    pub fn set_supply_drift_threshold(&mut self, threshold: u64) {
+        self.assert_not_paused();
+        self.assert_role(ROLE_CONFIG_MANAGER);
+
+        self.supply_drift_threshold = threshold;
+    }
+
+    pub fn get_supply_drift_threshold(&self) -> u64 {
+        self.supply_drift_threshold
+    }
+
+    // Reads ft_total_supply of the NEAR atBTC token and compares it with the recorded supply of the NEAR chain
+    // Guardian only because a drift over the threshold pauses minting on NEAR
+    pub fn reconcile_near_supply(&mut self) -> Promise {
+        self.assert_not_paused();
+        self.assert_role(ROLE_PAUSE_GUARDIAN);
+
+        let chain_id = self
+            .chain_configs
+            .get_chain_configs()
+            .into_iter()
+            .find(|chain_config| chain_config.network_type == NEAR)
+            .map(|chain_config| chain_config.chain_id)
+            .expect("NEAR atBTC chain config not found");
+        let abtc_account_id = self
+            .get_near_abtc_account_id()
+            .expect("NEAR atBTC chain config not found");
+
+        Promise::new(abtc_account_id)
+            .function_call(
+                "ft_total_supply".to_string(),
+                b"{}".to_vec(),
+                NearToken::from_yoctonear(0),
+                GAS_FOR_FT_TOTAL_SUPPLY,
+            )
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(GAS_FOR_SUPPLY_RECONCILIATION_CALLBACK)
+                    .reconcile_near_supply_callback(chain_id),
+            )
+    }
+
+    // Emits supply_reconciled and returns the drift, total supply minus recorded supply
+    // A drift over the threshold pauses minting on the chain as the contract itself, like the mint circuit breaker
+    #[private]
+    pub fn reconcile_near_supply_callback(
+        &mut self,
+        chain_id: String,
+        #[callback_result] result: Result<U128, PromiseError>,
+    ) -> Option<i64> {
+        let total_supply = match result {
+            Ok(total_supply) => total_supply.0 as i64,
+            Err(_) => {
+                env::log_str("Failed to read the NEAR atBTC total supply");
+                return None;
+            }
+        };
+
+        let reserve = self.chain_reserves.get(&chain_id).cloned().unwrap_or_default();
+        let recorded_supply = reserve.minted as i64 - reserve.burned as i64;
+        let drift = total_supply - recorded_supply;
+        let threshold = self.supply_drift_threshold;
+
+        emit_event(
+            "supply_reconciled",
+            json!({
+                "chain_id": chain_id,
+                "total_supply": total_supply,
+                "recorded_supply": recorded_supply,
+                "drift": drift,
+                "threshold": threshold,
+            }),
+        );
+
+        if threshold > 0 && drift.unsigned_abs() > threshold {
+            self.insert_paused_scope(PAUSE_MINTING, &chain_id, env::current_account_id());
+        }
+
+        Some(drift)
+    }
+}
+
+impl Atlas {
+    // Applies update to the supply totals of chain_id, creating them on first use
+    pub fn update_chain_reserve(&mut self, chain_id: &str, update: impl FnOnce(&mut ChainReserveRecord)) {
+        let mut reserve = self.chain_reserves.get(chain_id).cloned().unwrap_or_default();
+        update(&mut reserve);
+        self.chain_reserves.insert(chain_id.to_string(), reserve);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::atlas::Atlas;
+use crate::constants::role::*;
+use crate::modules::events::emit_event;
+use crate::AtlasExt;
+use near_sdk::{env, near_bindgen, AccountId};
+use serde_json::json;
+
+#[near_bindgen]
+impl Atlas {
+    // Grants a role to an account, owner only
+    // Not blocked while paused so the owner can always appoint a pause guardian
+    pub fn grant_role(&mut self, account_id: AccountId, role: String) {
+        self.assert_owner();
+
+        assert!(ROLES.contains(&role.as_str()), "Invalid role");
+
+        let mut roles = self.roles.get(&account_id).cloned().unwrap_or_default();
+        assert!(!roles.contains(&role), "Account already has the role");
+        roles.push(role.clone());
+        self.roles.insert(account_id.clone(), roles);
+
+        emit_event(
+            "role_granted",
+            json!({
+                "account_id": account_id,
+                "role": role,
+            }),
+        );
+    }
+
+    pub fn revoke_role(&mut self, account_id: AccountId, role: String) {
+        self.assert_owner();
+
+        let mut roles = self.roles.get(&account_id).cloned().unwrap_or_default();
+        assert!(roles.contains(&role), "Account does not have the role");
+        roles.retain(|held_role| held_role != &role);
+
+        if roles.is_empty() {
+            self.roles.remove(&account_id);
+        } else {
+            self.roles.insert(account_id.clone(), roles);
+        }
+
+        emit_event(
+            "role_revoked",
+            json!({
+                "account_id": account_id,
+                "role": role,
+            }),
+        );
+    }
+
+    pub fn get_roles(&self, account_id: AccountId) -> Vec<String> {
+        self.roles.get(&account_id).cloned().unwrap_or_default()
+    }
+
+    pub fn get_role_members(&self, role: String) -> Vec<AccountId> {
+        self.roles
+            .iter()
+            .filter(|(_, roles)| roles.contains(&role))
+            .map(|(account_id, _)| account_id.clone())
+            .collect()
+    }
+
+    pub fn has_role(&self, account_id: AccountId, role: String) -> bool {
+        self.roles
+            .get(&account_id)
+            .map_or(false, |roles| roles.contains(&role))
+    }
+}
+
+impl Atlas {
+    pub fn assert_role(&self, role: &str) {
+        assert!(
+            self.has_role(env::predecessor_account_id(), role.to_string()),
+            "Caller does not have the {} role",
+            role
+        );
+    }
+
+    // Roles matching the permissions the owner accounts had before roles were introduced
+    pub fn grant_default_roles(&mut self) {
+        let default_roles = [
+            (self.owner_id.clone(), ROLE_PAUSE_GUARDIAN),
+            (self.owner_id.clone(), ROLE_UPGRADER),
+            (self.admin_id.clone(), ROLE_DEPOSIT_OPERATOR),
+            (self.admin_id.clone(), ROLE_REDEMPTION_OPERATOR),
+            (self.admin_id.clone(), ROLE_REFUND_OPERATOR),
+            (self.global_params.owner_id().clone(), ROLE_FEE_MANAGER),
+            (self.global_params.owner_id().clone(), ROLE_CONFIG_MANAGER),
+            (self.chain_configs.get_chain_configs_owner_id(), ROLE_CONFIG_MANAGER),
+        ];
+
+        for (account_id, role) in default_roles {
+            let mut roles = self.roles.get(&account_id).cloned().unwrap_or_default();
+            if !roles.iter().any(|held_role| held_role == role) {
+                roles.push(role.to_string());
+            }
+            self.roles.insert(account_id, roles);
+        }
+    }
+
+    // Hands the roles of a replaced owner or admin to its successor
+    pub fn transfer_roles(&mut self, from: &AccountId, to: &AccountId) {
+        let transferred_roles = match self.roles.remove(from) {
+            Some(roles) => roles,
+            None => return,
+        };
+
+        let mut roles = self.roles.get(to).cloned().unwrap_or_default();
+        for role in transferred_roles {
+            if !roles.contains(&role) {
+                roles.push(role);
+            }
+        }
+        self.roles.insert(to.clone(), roles);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::chain_configs::ChainConfigs;
-use crate::global_params::GlobalParams;
+use crate::global_params::{GlobalParams, OldGlobalParams};
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
 use near_sdk::near_bindgen;
 use near_sdk::store::IterableMap;
 use near_sdk::AccountId;
@@ -14,12 +15,78 @@ pub struct Atlas {
     pub redemptions: IterableMap<String, RedemptionRecord>,
     pub validators: IterableMap<AccountId, Vec<String>>, // list of validators: <AccountId -> Vector of authorised chains (chain_id)>
     pub verifications: IterableMap<String, Vec<AccountId>>, // list of verifications: <Txn Hash of deposit/redemption/bridging -> Vector of validators (AccountId)>
+    pub btc_headers: IterableMap<String, BtcBlockHeaderRecord>, // BTC light client headers: <Block hash -> Header record>
+    pub btc_main_chain: IterableMap<u64, String>, // BTC light client main chain: <Height -> Block hash>
+    pub btc_deposit_proofs: IterableMap<String, BtcDepositProofRecord>, // deposits confirmed by inclusion proof: <BTC txn hash -> Proof record>
+    pub evm_headers: IterableMap<String, EvmBlockHeaderRecord>, // co-signed EVM headers: <chain_id,block_hash -> Header record>
+    pub evm_receipt_proofs: IterableMap<String, EvmReceiptProofRecord>, // mints/burns proven by receipt: <Txn Hash of deposit/redemption -> Proof record>
+    pub attestation_keys: IterableMap<AccountId, AttestationKeyRecord>, // off-chain attestation keys: <Validator AccountId -> Key record>
+    pub validator_bonds: IterableMap<AccountId, ValidatorBondRecord>, // locked NEAR / atBTC: <Validator AccountId -> Bond record>
+    pub validator_weights: IterableMap<String, u64>, // voting weights: <chain_id,AccountId -> Weight>, validators without an entry weigh 1
+    pub weight_thresholds: IterableMap<String, WeightThresholdRecord>, // weight thresholds: <chain_id -> Threshold record>
+    pub verified_weights: IterableMap<String, u64>, // accumulated weight: <Same key as verifications -> Weight>
+    pub validator_votes: IterableMap<AccountId, Vec<ValidatorVoteRecord>>, // reverse index of outstanding votes: <Validator AccountId -> Votes on pending records>
+    pub validator_stats: IterableMap<String, ValidatorStatsRecord>, // liveness: <chain_id,AccountId -> Stats record>
+    pub vote_buckets: IterableMap<String, VoteBucketRecord>, // hourly counts kept for 30 days: <chain_id,slot -> Records opened> and <chain_id,AccountId,slot -> Votes cast>
+    pub validator_rewards: IterableMap<AccountId, u64>, // claimable rewards in atBTC sats: <Validator AccountId -> Amount>
+    pub reward_claims: IterableMap<String, RewardClaimRecord>, // reward payouts: <Claim ID -> Claim record>
+    pub dispute_windows: IterableMap<String, u64>, // dispute window length in seconds: <chain_id -> Seconds>
+    pub dispute_window_starts: IterableMap<String, DisputeWindowRecord>, // threshold reached: <Deposit btc_txn_hash or redemption txn_hash -> Window record>
+    pub disputes: IterableMap<String, DisputeRecord>, // flagged records: <Deposit btc_txn_hash or redemption txn_hash -> Dispute record>
+    pub proposals: IterableMap<u64, ProposalRecord>, // governance proposals: <Proposal ID -> Proposal record>
+    pub operation_delays: IterableMap<String, u64>, // owner operation delays in seconds: <Operation type -> Seconds>
+    pub scheduled_operations: IterableMap<u64, ScheduledOperationRecord>, // timelocked owner operations: <Operation ID -> Operation record>
+    pub roles: IterableMap<AccountId, Vec<String>>, // <Account ID -> Roles held by the account>
+    pub paused_scopes: IterableMap<String, PausedScopeRecord>, // <Scope or Scope,Chain ID -> Pause record>
+    pub mint_limits: IterableMap<String, MintLimitRecord>, // per-chain mint limits: <Chain ID -> Mint limits>
+    pub mint_volumes: IterableMap<String, Vec<MintVolumeRecord>>, // mints within the daily window: <Chain ID -> Mints>
+    pub mint_allowances: IterableMap<String, MintAllowanceRecord>, // reviewed headroom over the mint limits: <Chain ID, empty for global -> Allowance>
+    pub chain_reserves: IterableMap<String, ChainReserveRecord>, // atBTC supply totals: <Chain ID -> Totals>
+    pub evm_nonces: IterableMap<String, EvmNonceRecord>, // nonces of the MPC-derived EVM signers: <Chain ID,Derivation path -> Nonces>
+    pub bridgings: IterableMap<String, BridgingRecord>, // list of bridgings: <Txn Hash of the origin burn -> Bridging>
+    pub evm_admin_functions: IterableMap<String, EvmAdminFunctionRecord>, // allowlisted atBTC admin calls: <Function signature -> Function>
+    pub evm_base_fees: IterableMap<String, Vec<BaseFeeRecord>>, // latest base fee posted by each validator: <Chain ID -> Base fees>
+    pub mint_attempts: IterableMap<String, Vec<MintAttemptRecord>>, // signed EVM mint transactions: <Deposit btc_txn_hash or bridging txn_hash -> Attempts>
+    pub evm_gas_bounds: IterableMap<String, EvmGasBoundsRecord>, // bounds on signed EVM gas values: <Chain ID -> Bounds>
+    pub evm_cosigned_headers: IterableMap<String, String>, // first co-signed EVM header per height: <chain_id,block_number -> Block hash>
     pub owner_id: AccountId,
     pub proposed_owner_id: Option<AccountId>,
     pub admin_id: AccountId,
     pub proposed_admin_id: Option<AccountId>,
     pub global_params: GlobalParams,
     pub chain_configs: ChainConfigs,
+    pub last_evm_tx: Option<Vec<u8>>, // no longer written, signed mint payloads are kept on their mint attempts
+    pub paused: bool,
+    pub production_mode: bool,
+    pub btc_tip_block_hash: String,
+    pub btc_confirmation_depth: u64,
+    pub validator_bond_params: ValidatorBondParams,
+    pub slashed_near_amount: U128,
+    pub slashed_abtc_amount: U128,
+    pub reward_pool: RewardPoolRecord,
+    pub governance_params: GovernanceParams,
+    pub global_mint_limits: MintLimitRecord,
+    pub reserve_totals: ReserveTotalsRecord,
+    pub reserve_attestation: Option<ReserveAttestationRecord>, // latest signed reserves statement
+    pub supply_drift_threshold: u64, // sats the NEAR atBTC supply may drift from the recorded supply before minting pauses, 0 = never pause
+    pub proposal_nonce: u64,
+    pub operation_nonce: u64,
+}
+
+// Layout of Atlas deployed before the light clients, validator bonds and roles, read by Atlas::migrate
+#[derive(BorshDeserialize, BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct OldAtlas {
+    pub deposits: IterableMap<String, DepositRecord>,
+    pub redemptions: IterableMap<String, RedemptionRecord>,
+    pub validators: IterableMap<AccountId, Vec<String>>,
+    pub verifications: IterableMap<String, Vec<AccountId>>,
+    pub owner_id: AccountId,
+    pub proposed_owner_id: Option<AccountId>,
+    pub admin_id: AccountId,
+    pub proposed_admin_id: Option<AccountId>,
+    pub global_params: OldGlobalParams,
+    pub chain_configs: ChainConfigs,
     pub last_evm_tx: Option<Vec<u8>>,
     pub paused: bool,
     pub production_mode: bool,
@@ -63,6 +130,430 @@ pub struct RedemptionRecord {
     pub custody_txn_id: String,
 }
 
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct BridgingRecord {
+    pub txn_hash: String, //`${origin_chain_id}${DELIMITER.COMMA}${transactionHash}` of the burnBridge transaction
+    pub origin_chain_id: String,
+    pub origin_chain_address: String,
+    pub dest_chain_id: String,
+    pub dest_chain_address: String,
+    pub dest_txn_hash: String,
+    pub abtc_amount: u64, // burned on the origin chain
+    pub fee_amount: u64,  // kept out of the mint on the destination chain
+    pub timestamp: u64,
+    pub status: u8,
+    pub remarks: String,
+    pub date_created: u64,
+    pub verified_count: u8,
+    pub retry_count: u8, // failed NEAR mint_bridge calls and rollbacks
+    pub dest_txn_hash_verified_count: u8,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct BtcBlockHeaderRecord {
+    pub block_hash: String,
+    pub prev_block_hash: String,
+    pub merkle_root: String,
+    pub height: u64,
+    pub time: u32,
+    pub bits: u32,
+    pub chain_work: String, // cumulative chain work as a decimal string
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct BtcDepositProofRecord {
+    pub btc_txn_hash: String,
+    pub block_hash: String,
+    pub block_height: u64,
+    pub timestamp: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct EvmBlockHeaderRecord {
+    pub chain_id: String,
+    pub block_hash: String,
+    pub block_number: u64,
+    pub transactions_root: String,
+    pub receipts_root: String,
+    pub verified_count: u8,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct EvmReceiptProofRecord {
+    pub record_key: String,
+    pub chain_id: String,
+    pub block_hash: String,
+    pub block_number: u64,
+    pub tx_index: u64,
+    pub timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct BtcDepositTxDetails {
+    pub btc_txn_hash: String,
+    pub btc_sender_address: String,
+    pub receiving_chain_id: String,
+    pub receiving_address: String,
+    pub btc_amount: u64,
+    pub fee_amount: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct AttestationKeyRecord {
+    pub key_type: String,   // ed25519 or secp256k1
+    pub public_key: String, // hex, 32 bytes for ed25519, 64 byte uncompressed point for secp256k1
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone, Default)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct ValidatorBondRecord {
+    pub near_amount: U128,
+    pub abtc_amount: U128,
+    pub unbonding_near_amount: U128,   // requested for withdrawal, still slashable until unbonding_available_at
+    pub unbonding_abtc_amount: U128,
+    pub unbonding_available_at: u64,   // timestamp in seconds
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct ValidatorBondParams {
+    pub min_near_bond: U128,          // 0 disables NEAR as a bonding option
+    pub min_abtc_bond: U128,          // 0 disables atBTC as a bonding option
+    pub unbonding_period: u64,        // seconds
+    pub challenger_reward_bps: u16,   // share of a slashed bond paid to the challenger
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct WeightThresholdRecord {
+    pub threshold_type: String, // absolute or fraction
+    pub value: u64,             // weight for absolute, basis points of the total weight for fraction
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct ValidatorVoteRecord {
+    pub vote_type: String,
+    pub record_key: String,       // btc_txn_hash, txn_hash or chain_id,block_hash of the voted record
+    pub verification_key: String, // key of the vote in the verifications map
+    pub chain_id: String,
+    pub weight: u64,              // weight counted for this vote
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone, Default)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct ValidatorStatsRecord {
+    pub vote_count: u64,
+    pub revoked_vote_count: u64,
+    pub first_vote_timestamp: u64,
+    pub last_vote_timestamp: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct VoteBucketRecord {
+    pub bucket: u64, // hour the count belongs to, the slot is reused for bucket + VOTE_BUCKET_SLOTS
+    pub count: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ValidatorParticipation {
+    pub account_id: AccountId,
+    pub vote_count: u64,         // votes cast within the window
+    pub record_count: u64,       // records voted on by any validator of the chain within the window
+    pub participation_bps: u64,  // vote_count / record_count in basis points
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone, Default)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct RewardPoolRecord {
+    pub total_accrued: u64,   // fee share received by the pool
+    pub total_credited: u64,  // part of total_accrued credited to validators, the rest is unallocated
+    pub total_claimed: u64,
+    pub claim_nonce: u64,
+    pub total_funded: u64,    // atBTC sent to the contract to pay claims, claims never exceed it
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct RewardClaimRecord {
+    pub claim_id: String,
+    pub account_id: AccountId,
+    pub chain_id: String,
+    pub receiving_address: String,
+    pub amount: u64,
+    pub status: u8,
+    pub timestamp: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct DisputeWindowRecord {
+    pub chain_id: String, // chain whose dispute window applies
+    pub started_at: u64,  // timestamp the verification threshold was first reached
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct DisputeRecord {
+    pub record_type: String, // deposit or redemption
+    pub record_key: String,
+    pub chain_id: String,
+    pub flagged_by: AccountId,
+    pub evidence: String,
+    pub status: u8,
+    pub flagged_at: u64,
+    pub resolved_at: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone, Default)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct GovernanceParams {
+    pub council_type: String, // validators or members, empty while governance is disabled
+    pub council_members: Vec<AccountId>,
+    pub quorum_bps: u16,      // share of the council that has to approve, in basis points
+    pub voting_period: u64,   // seconds a proposal accepts votes after it is submitted
+    pub timelock: u64,        // seconds between approval and execution
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct ProposalVote {
+    pub account_id: AccountId,
+    pub approve: bool,
+    pub timestamp: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct ProposalRecord {
+    pub proposal_id: u64,
+    pub proposal_type: String,
+    pub value: String,
+    pub proposer: AccountId,
+    pub status: u8,
+    pub votes: Vec<ProposalVote>,
+    pub created_at: u64,
+    pub approved_at: u64,
+    pub executed_at: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct ScheduledOperationRecord {
+    pub operation_id: u64,
+    pub operation_type: String,
+    pub value: String,
+    pub scheduled_by: AccountId,
+    pub status: u8,
+    pub scheduled_at: u64,
+    pub eta: u64,         // earliest timestamp the operation can be executed
+    pub resolved_at: u64, // timestamp the operation was executed or cancelled
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct PausedScopeRecord {
+    pub scope: String,
+    pub chain_id: String, // empty when the scope is paused on every chain
+    pub paused_by: AccountId,
+    pub paused_at: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone, Default)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct MintLimitRecord {
+    pub hourly_limit: u64, // sats minted per rolling hour, 0 for no limit
+    pub daily_limit: u64,  // sats minted per rolling day, 0 for no limit
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct MintVolumeRecord {
+    pub record_key: String, // deposit btc_txn_hash or bridging txn_hash the mint is for
+    pub amount: u64,
+    pub timestamp: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct MintAllowanceRecord {
+    pub amount: u64,     // sats added to the hourly and daily limits
+    pub expires_at: u64, // once the reviewed mints have left the daily window
+    pub granted_by: AccountId,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MintCapacity {
+    pub chain_id: String,
+    pub hourly_remaining: Option<u64>, // None when neither the chain nor the global hourly limit is set
+    pub daily_remaining: Option<u64>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone, Default)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct ReserveTotalsRecord {
+    pub deposited: u64, // sats of deposits confirmed into Atlas
+    pub redeemed: u64,  // atBTC of redemptions paid out in BTC
+    pub refunded: u64,  // sats of failed deposits refunded in BTC
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone, Default)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct ChainReserveRecord {
+    pub minted: u64, // atBTC minted for deposits
+    pub burned: u64, // atBTC burned for redemptions
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ChainSupply {
+    pub chain_id: String,
+    pub minted: u64,
+    pub burned: u64,
+    pub circulating_supply: i64, // minted - burned
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ReservesReport {
+    pub deposited: u64,
+    pub redeemed: u64,
+    pub refunded: u64,
+    pub btc_reserve: i64, // deposited - redeemed - refunded
+    pub minted: u64,
+    pub burned: u64,
+    pub expected_circulating_supply: i64, // sum of the chains' circulating supply
+    pub chains: Vec<ChainSupply>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct PendingNonceRecord {
+    pub nonce: u64,
+    pub record_key: String, // deposit, bridging or admin call the transaction was signed for
+    pub signed_at: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct EvmNonceRecord {
+    pub chain_id: String,
+    pub path: String, // MPC derivation path of the signer
+    pub next_nonce: u64, // handed out to the next signed transaction
+    pub verified_nonce: Option<u64>, // latest on-chain nonce verified by the chain's validators
+    pub pending: Vec<PendingNonceRecord>, // handed out at or above the verified nonce, ordered by nonce
+    pub votes: Vec<NonceVoteRecord>, // latest nonce voted by each validator
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct NonceVoteRecord {
+    pub validator: AccountId,
+    pub nonce: u64,
+    pub submitted_at: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct MintAttemptRecord {
+    pub attempt_type: String, // mint, speed_up or cancel
+    pub chain_id: String,
+    pub nonce: u64,
+    pub gas: U128,
+    pub max_fee_per_gas: U128,
+    pub max_priority_fee_per_gas: U128,
+    pub tx_hash: String, // hash of the signed transaction, empty until the MPC signature is returned
+    pub from_address: String, // signer recovered from the MPC signature
+    pub evm_tx: String, // serialized JSON of the unsigned transaction the signature is requested for
+    pub signed_at: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct BaseFeeRecord {
+    pub validator: AccountId,
+    pub block_number: u64, // EVM block the base fee was read from
+    pub base_fee: u64, // in wei
+    pub submitted_at: u64,
+}
+
+// Bounds on the EIP-1559 gas values of transactions signed for a chain, 0 leaves a bound unset
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone, Default)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct EvmGasBoundsRecord {
+    pub max_gas: u64,
+    pub max_fee_per_gas: u64,
+    pub max_priority_fee_per_gas: u64,
+    pub default_priority_fee_per_gas: u64, // tip signed when the caller leaves max_priority_fee_per_gas out
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct EvmFeeEstimate {
+    pub median_base_fee: u64,
+    pub samples: u32, // fresh base fees the median is taken over
+    pub max_fee_per_gas: u64,
+    pub max_priority_fee_per_gas: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct EvmAdminFunctionRecord {
+    pub signature: String,        // canonical Solidity signature, e.g. setMinter(address,bool)
+    pub selector: String,         // hex of the first 4 bytes of keccak256(signature)
+    pub param_types: Vec<String>, // ABI types the call arguments are encoded as
+    pub added_by: AccountId,
+    pub added_at: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct ReserveAttestationRecord {
+    pub deposited: u64,
+    pub redeemed: u64,
+    pub refunded: u64,
+    pub minted: u64,
+    pub burned: u64,
+    pub block_height: u64,
+    pub timestamp: u64,
+    pub digest: String,    // hex EIP-712 digest of the fields above
+    pub signature: String, // hex r || s || v of the MPC signature over digest, v is 27 or 28
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ValidatorAttestation {
+    pub validator_id: AccountId,
+    pub signature: String, // hex, 64 bytes for ed25519, 65 bytes r || s || v for secp256k1
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VerificationResult {
+    pub key: String,
+    pub verified: bool,
+    pub reason: String,
+}
+
+impl VerificationResult {
+    pub fn from_outcome(key: String, outcome: Result<(), String>) -> Self {
+        match outcome {
+            Ok(()) => Self {
+                key,
+                verified: true,
+                reason: "".to_string(),
+            },
+            Err(reason) => Self {
+                key,
+                verified: false,
+                reason,
+            },
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct UtxoInput {
     pub txid: String,
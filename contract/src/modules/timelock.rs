@@ -0,0 +1,262 @@
+use crate::atlas::Atlas;
+use crate::constants::delimiter::COMMA;
+use crate::constants::governance::*;
+use crate::constants::role::*;
+use crate::constants::status::*;
+use crate::constants::timelock::*;
+use crate::modules::events::emit_event;
+use crate::modules::structs::ScheduledOperationRecord;
+use crate::AtlasExt;
+use near_sdk::{env, near_bindgen};
+use serde_json::json;
+
+fn current_timestamp() -> u64 {
+    env::block_timestamp() / 1_000_000_000
+}
+
+// Owner operations that can be given a delay
+const TIMELOCKED_OPERATIONS: [&str; 11] = [
+    PROPOSAL_SET_MPC_CONTRACT,
+    PROPOSAL_UPDATE_FEE_DEPOSIT_BPS,
+    PROPOSAL_UPDATE_FEE_REDEMPTION_BPS,
+    PROPOSAL_UPDATE_FEE_BRIDGING_BPS,
+    PROPOSAL_UPDATE_FEE_BABYLON_REWARDS_BPS,
+    PROPOSAL_UPDATE_FEE_VALIDATOR_REWARDS_BPS,
+    PROPOSAL_UPDATE_TREASURY_ADDRESS,
+    PROPOSAL_UPDATE_BTC_ATLAS_DEPOSIT_ADDRESS,
+    PROPOSAL_SET_CHAIN_CONFIGS,
+    OPERATION_UPDATE_CONTRACT,
+    OPERATION_SET_OPERATION_DELAY,
+];
+
+// Splits a set_operation_delay value into the operation type and its new delay
+fn parse_delay_value(value: &str) -> (String, u64) {
+    let (operation_type, delay) = value
+        .split_once(COMMA)
+        .unwrap_or_else(|| env::panic_str("Invalid operation delay value"));
+    assert!(
+        TIMELOCKED_OPERATIONS.contains(&operation_type),
+        "Invalid operation type"
+    );
+    let delay = delay
+        .parse::<u64>()
+        .unwrap_or_else(|_| env::panic_str("Invalid operation delay value"));
+    (operation_type.to_string(), delay)
+}
+
+#[near_bindgen]
+impl Atlas {
+    pub fn get_operation_delay(&self, operation_type: String) -> u64 {
+        self.operation_delays.get(&operation_type).copied().unwrap_or(0)
+    }
+
+    pub fn get_scheduled_operation(&self, operation_id: u64) -> Option<ScheduledOperationRecord> {
+        self.scheduled_operations.get(&operation_id).cloned()
+    }
+
+    // Operations waiting for execution, each with the eta it becomes executable at
+    pub fn get_pending_operations(&self) -> Vec<ScheduledOperationRecord> {
+        self.scheduled_operations
+            .values()
+            .filter(|operation| operation.status == OPERATION_SCHEDULED)
+            .cloned()
+            .collect()
+    }
+
+    // Raises the delay of an operation immediately, lowering a delay has to be scheduled as a set_operation_delay operation
+    // that waits for the current delay of the operation it lowers
+    pub fn set_operation_delay(&mut self, operation_type: String, delay: u64) {
+        self.assert_not_paused();
+        self.assert_owner();
+
+        assert!(
+            TIMELOCKED_OPERATIONS.contains(&operation_type.as_str()),
+            "Invalid operation type"
+        );
+        assert!(
+            delay >= self.get_operation_delay(operation_type.clone()),
+            "Lowering an operation delay has to be scheduled"
+        );
+
+        self.operation_delays.insert(operation_type, delay);
+    }
+
+    // Schedules an owner operation, executable once its delay has passed
+    // Values use the governance proposal format, update_contract takes the hex sha256 of the new code
+    pub fn schedule_operation(&mut self, operation_type: String, value: String) -> u64 {
+        self.assert_not_paused();
+        self.assert_operation_role(&operation_type);
+
+        let delay = match operation_type.as_str() {
+            OPERATION_UPDATE_CONTRACT => {
+                assert!(
+                    hex::decode(&value).map_or(false, |hash| hash.len() == 32),
+                    "Invalid code hash"
+                );
+                self.get_operation_delay(operation_type.clone())
+            }
+            // Lowering a delay waits for the delay being lowered
+            OPERATION_SET_OPERATION_DELAY => {
+                let (target_operation_type, _) = parse_delay_value(&value);
+                self.get_operation_delay(target_operation_type)
+            }
+            _ => {
+                self.assert_not_governed();
+                self.validate_proposal(&operation_type, &value);
+                self.get_operation_delay(operation_type.clone())
+            }
+        };
+
+        self.operation_nonce += 1;
+        let operation_id = self.operation_nonce;
+        let operation = ScheduledOperationRecord {
+            operation_id,
+            operation_type,
+            value,
+            scheduled_by: env::predecessor_account_id(),
+            status: OPERATION_SCHEDULED,
+            scheduled_at: current_timestamp(),
+            eta: current_timestamp() + delay,
+            resolved_at: 0,
+        };
+        self.scheduled_operations
+            .insert(operation_id, operation.clone());
+
+        emit_event(
+            "operation_scheduled",
+            json!({
+                "operation_id": operation_id,
+                "operation_type": operation.operation_type,
+                "value": operation.value,
+                "eta": operation.eta,
+            }),
+        );
+
+        operation_id
+    }
+
+    // Applies a scheduled operation whose eta has passed, contract updates are executed by calling update_contract with the code
+    pub fn execute_operation(&mut self, operation_id: u64) {
+        self.assert_not_paused();
+
+        let operation = self.get_executable_operation(operation_id);
+        self.assert_operation_role(&operation.operation_type);
+
+        match operation.operation_type.as_str() {
+            OPERATION_UPDATE_CONTRACT => {
+                env::panic_str("Contract updates are executed through update_contract")
+            }
+            OPERATION_SET_OPERATION_DELAY => {
+                let (target_operation_type, delay) = parse_delay_value(&operation.value);
+                self.operation_delays.insert(target_operation_type, delay);
+            }
+            _ => {
+                self.assert_not_governed();
+                self.apply_proposal(&operation.operation_type, &operation.value);
+            }
+        }
+
+        self.resolve_operation(operation, OPERATION_EXECUTED, "operation_executed");
+    }
+
+    pub fn cancel_operation(&mut self, operation_id: u64) {
+        self.assert_not_paused();
+
+        let operation = self
+            .scheduled_operations
+            .get(&operation_id)
+            .cloned()
+            .expect("Operation not found");
+        self.assert_operation_role(&operation.operation_type);
+        assert!(
+            operation.status == OPERATION_SCHEDULED,
+            "Operation is not scheduled"
+        );
+
+        self.resolve_operation(operation, OPERATION_CANCELLED, "operation_cancelled");
+    }
+}
+
+impl Atlas {
+    // Direct owner calls are only allowed while the operation has no delay
+    pub fn assert_not_timelocked(&self, operation_type: &str) {
+        assert!(
+            self.get_operation_delay(operation_type.to_string()) == 0,
+            "Operation is timelocked and has to be scheduled"
+        );
+    }
+
+    // Marks the scheduled update matching the code as executed, no-op while update_contract has no delay
+    pub fn consume_scheduled_contract_update(&mut self, code: &[u8]) {
+        if self.get_operation_delay(OPERATION_UPDATE_CONTRACT.to_string()) == 0 {
+            return;
+        }
+
+        let code_hash = hex::encode(env::sha256(code));
+        let operation_id = self
+            .scheduled_operations
+            .values()
+            .find(|operation| {
+                operation.operation_type == OPERATION_UPDATE_CONTRACT
+                    && operation.status == OPERATION_SCHEDULED
+                    && operation.value == code_hash
+            })
+            .map(|operation| operation.operation_id)
+            .expect("Contract update has not been scheduled");
+
+        let operation = self.get_executable_operation(operation_id);
+        self.resolve_operation(operation, OPERATION_EXECUTED, "operation_executed");
+    }
+
+    // Fee operations need the FeeManager role, MPC contract, addresses and chain configs the ConfigManager role, contract updates the Upgrader role
+    // Operation delays stay with the owner
+    fn assert_operation_role(&self, operation_type: &str) {
+        assert!(
+            TIMELOCKED_OPERATIONS.contains(&operation_type),
+            "Invalid operation type"
+        );
+
+        match operation_type {
+            OPERATION_SET_OPERATION_DELAY => self.assert_owner(),
+            OPERATION_UPDATE_CONTRACT => self.assert_role(ROLE_UPGRADER),
+            PROPOSAL_SET_MPC_CONTRACT
+            | PROPOSAL_UPDATE_TREASURY_ADDRESS
+            | PROPOSAL_UPDATE_BTC_ATLAS_DEPOSIT_ADDRESS
+            | PROPOSAL_SET_CHAIN_CONFIGS => self.assert_role(ROLE_CONFIG_MANAGER),
+            _ => self.assert_role(ROLE_FEE_MANAGER),
+        }
+    }
+
+    fn get_executable_operation(&self, operation_id: u64) -> ScheduledOperationRecord {
+        let operation = self
+            .scheduled_operations
+            .get(&operation_id)
+            .cloned()
+            .expect("Operation not found");
+        assert!(
+            operation.status == OPERATION_SCHEDULED,
+            "Operation is not scheduled"
+        );
+        assert!(
+            current_timestamp() >= operation.eta,
+            "Operation delay has not passed"
+        );
+        operation
+    }
+
+    fn resolve_operation(&mut self, mut operation: ScheduledOperationRecord, status: u8, event: &str) {
+        operation.status = status;
+        operation.resolved_at = current_timestamp();
+        self.scheduled_operations
+            .insert(operation.operation_id, operation.clone());
+
+        emit_event(
+            event,
+            json!({
+                "operation_id": operation.operation_id,
+                "operation_type": operation.operation_type,
+                "value": operation.value,
+            }),
+        );
+    }
+}
@@ -8,7 +8,9 @@ use serde_json::json;
 use serde_json::Value;
 
 use crate::constants::delimiter::COMMA;
+use crate::constants::governance::*;
 use crate::constants::network_type::*;
+use crate::constants::role::*;
 use crate::constants::status::*;
 
 #[near_bindgen]
@@ -69,51 +71,85 @@ impl Atlas {
 
     pub fn update_fee_deposit_bps(&mut self, fee_deposit_bps: u16) {
         self.assert_not_paused();
-        self.global_params.update_fee_deposit_bps(fee_deposit_bps);
+        self.assert_role(ROLE_FEE_MANAGER);
+        self.assert_not_governed();
+        self.assert_not_timelocked(PROPOSAL_UPDATE_FEE_DEPOSIT_BPS);
+        self.global_params.apply_fee_deposit_bps(fee_deposit_bps);
     }
 
     pub fn update_fee_redemption_bps(&mut self, fee_redemption_bps: u16) {
         self.assert_not_paused();
-        self.global_params
-            .update_fee_redemption_bps(fee_redemption_bps);
+        self.assert_role(ROLE_FEE_MANAGER);
+        self.assert_not_governed();
+        self.assert_not_timelocked(PROPOSAL_UPDATE_FEE_REDEMPTION_BPS);
+        self.global_params.apply_fee_redemption_bps(fee_redemption_bps);
     }
 
     pub fn update_fee_bridging_bps(&mut self, fee_bridging_bps: u16) {
         self.assert_not_paused();
-        self.global_params.update_fee_bridging_bps(fee_bridging_bps);
+        self.assert_role(ROLE_FEE_MANAGER);
+        self.assert_not_governed();
+        self.assert_not_timelocked(PROPOSAL_UPDATE_FEE_BRIDGING_BPS);
+        self.global_params.apply_fee_bridging_bps(fee_bridging_bps);
     }
 
     pub fn update_fee_babylon_rewards_bps(&mut self, fee_babylon_rewards_bps: u16) {
         self.assert_not_paused();
-        self.global_params
-            .update_fee_babylon_rewards_bps(fee_babylon_rewards_bps);
+        self.assert_role(ROLE_FEE_MANAGER);
+        self.assert_not_governed();
+        self.assert_not_timelocked(PROPOSAL_UPDATE_FEE_BABYLON_REWARDS_BPS);
+        self.global_params.apply_fee_babylon_rewards_bps(fee_babylon_rewards_bps);
+    }
+
+    pub fn update_fee_validator_rewards_bps(&mut self, fee_validator_rewards_bps: u16) {
+        self.assert_not_paused();
+        self.assert_role(ROLE_FEE_MANAGER);
+        self.assert_not_governed();
+        self.assert_not_timelocked(PROPOSAL_UPDATE_FEE_VALIDATOR_REWARDS_BPS);
+        self.global_params.apply_fee_validator_rewards_bps(fee_validator_rewards_bps);
     }
 
     pub fn update_btc_staking_cap(&mut self, btc_staking_cap: u64) {
         self.assert_not_paused();
-        self.global_params.update_btc_staking_cap(btc_staking_cap);
+        self.assert_role(ROLE_CONFIG_MANAGER);
+        self.assert_not_governed();
+        self.global_params.apply_btc_staking_cap(btc_staking_cap);
     }
 
     pub fn update_btc_max_staking_amount(&mut self, btc_max_staking_amount: u64) {
         self.assert_not_paused();
-        self.global_params
-            .update_btc_max_staking_amount(btc_max_staking_amount);
+        self.assert_role(ROLE_CONFIG_MANAGER);
+        self.assert_not_governed();
+        self.global_params.apply_btc_max_staking_amount(btc_max_staking_amount);
     }
 
     pub fn update_btc_min_staking_amount(&mut self, btc_min_staking_amount: u64) {
         self.assert_not_paused();
-        self.global_params
-            .update_btc_min_staking_amount(btc_min_staking_amount);
+        self.assert_role(ROLE_CONFIG_MANAGER);
+        self.assert_not_governed();
+        self.global_params.apply_btc_min_staking_amount(btc_min_staking_amount);
     }
 
     pub fn update_treasury_address(&mut self, treasury_address: String) {
         self.assert_not_paused();
-        self.global_params.update_treasury_address(treasury_address);
+        self.assert_role(ROLE_CONFIG_MANAGER);
+        self.assert_not_governed();
+        self.assert_not_timelocked(PROPOSAL_UPDATE_TREASURY_ADDRESS);
+        self.global_params.apply_treasury_address(treasury_address);
+    }
+
+    pub fn update_btc_atlas_deposit_address(&mut self, btc_atlas_deposit_address: String) {
+        self.assert_not_paused();
+        self.assert_role(ROLE_CONFIG_MANAGER);
+        self.assert_not_governed();
+        self.assert_not_timelocked(PROPOSAL_UPDATE_BTC_ATLAS_DEPOSIT_ADDRESS);
+        self.global_params.apply_btc_atlas_deposit_address(btc_atlas_deposit_address);
     }
 
     pub fn update_max_retry_count(&mut self, max_retry_count: u8) {
         self.assert_not_paused();
-        self.global_params.update_max_retry_count(max_retry_count);
+        self.assert_role(ROLE_CONFIG_MANAGER);
+        self.global_params.apply_max_retry_count(max_retry_count);
     }
 
     pub fn get_chain_configs_owner_id(&self) -> AccountId {
@@ -134,13 +170,18 @@ impl Atlas {
 
     pub fn set_chain_configs_from_json(&mut self, new_json_data: String) {
         self.assert_not_paused();
-        self.chain_configs
-            .set_chain_configs_from_json(new_json_data);
+        self.assert_role(ROLE_CONFIG_MANAGER);
+        self.assert_not_governed();
+        self.assert_not_timelocked(PROPOSAL_SET_CHAIN_CONFIGS);
+        self.chain_configs.apply_chain_configs_from_json(new_json_data);
     }
 
     pub fn set_mpc_contract(&mut self, new_mpc_contract: AccountId) {
         self.assert_not_paused();
-        self.global_params.set_mpc_contract(new_mpc_contract);
+        self.assert_role(ROLE_CONFIG_MANAGER);
+        self.assert_not_governed();
+        self.assert_not_timelocked(PROPOSAL_SET_MPC_CONTRACT);
+        self.global_params.apply_mpc_contract(new_mpc_contract);
     }
 
     pub fn propose_new_global_params_owner(&mut self, proposed_owner_id: AccountId) {
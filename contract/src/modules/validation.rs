@@ -2,6 +2,7 @@ use near_sdk::{
     AccountId, near_bindgen, env
 };
 use crate::atlas::Atlas;
+use crate::constants::delimiter::COMMA;
 use crate::AtlasExt;
 
 #[near_bindgen]
@@ -34,13 +35,14 @@ impl Atlas {
 
     // Getter for validators: Check if a specific chain_id is associated with the account_id
     // Returns true if is an authorised validator, returns false if not a validator for this chain_id
+    // Validators without the required bond are not authorised while bonding is enabled
     pub fn is_validator(&self, account_id: &AccountId, chain_id: &String) -> bool {
         // Validate input parameters
         assert!(!account_id.to_string().is_empty(), "Account ID cannot be empty");
         assert!(!chain_id.is_empty(), "Chain ID cannot be empty");
 
         if let Some(chains) = self.validators.get(account_id) {
-            chains.contains(chain_id)
+            chains.contains(chain_id) && self.is_validator_bonded(account_id)
         } else {
             false
         }
@@ -63,6 +65,7 @@ impl Atlas {
     }
 
     // Setter for validators: Remove chain_id from the account_id in validators map
+    // Votes of the validator for this chain_id on records that are still pending are revoked
     pub fn remove_validator(&mut self, account_id: AccountId, chain_id: String) {
         self.assert_not_paused();
         self.assert_owner();
@@ -74,11 +77,14 @@ impl Atlas {
         if let Some(mut chains) = self.validators.get(&account_id).cloned() {
             if let Some(index) = chains.iter().position(|x| *x == chain_id) {
                 chains.remove(index);
+                self.validator_weights
+                    .remove(&format!("{}{}{}", chain_id, COMMA, account_id));
                 if chains.is_empty() {
                     self.validators.remove(&account_id); // Remove entry if no chains left
                 } else {
-                    self.validators.insert(account_id, chains);
+                    self.validators.insert(account_id.clone(), chains);
                 }
+                self.revoke_outstanding_votes(&account_id, &chain_id);
             } else {
                 env::log_str(&format!("Chain ID {} not found for account {}", chain_id, account_id));
             }
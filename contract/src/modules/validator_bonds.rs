@@ -0,0 +1,347 @@
+use crate::atlas::Atlas;
+use crate::constants::near_gas::*;
+use crate::constants::network_type::NEAR;
+use crate::constants::validator_bond::*;
+use crate::modules::structs::{ValidatorBondParams, ValidatorBondRecord};
+use crate::AtlasExt;
+use near_sdk::json_types::U128;
+use near_sdk::{
+    env, log, near_bindgen, AccountId, NearToken, Promise, PromiseError, PromiseOrValue,
+};
+use serde_json::json;
+use std::str::FromStr;
+
+#[near_bindgen]
+impl Atlas {
+    // Locks the attached NEAR as the caller's validator bond
+    #[payable]
+    pub fn bond_validator_near(&mut self) {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+        let amount = env::attached_deposit().as_yoctonear();
+
+        assert!(amount > 0, "Attached deposit must be greater than 0");
+        assert!(
+            self.validators.get(&caller).is_some(),
+            "Only a validator can bond"
+        );
+
+        let mut bond = self.validator_bonds.get(&caller).cloned().unwrap_or_default();
+        bond.near_amount = U128(bond.near_amount.0 + amount);
+        self.validator_bonds.insert(caller.clone(), bond);
+
+        log!("Validator {} bonded {} yoctoNEAR", caller, amount);
+    }
+
+    // NEP-141 receiver: atBTC sent from the NEAR atBTC token contract with msg "bond" is locked as the sender's validator bond
+    // and atBTC sent with msg "rewards" funds the validator reward pool
+    // Returns the unused amount, so transfers with another msg or bonds from non-validators are refunded
+    pub fn ft_on_transfer(
+        &mut self,
+        sender_id: AccountId,
+        amount: U128,
+        msg: String,
+    ) -> PromiseOrValue<U128> {
+        self.assert_not_paused();
+
+        assert_eq!(
+            Some(env::predecessor_account_id()),
+            self.get_near_abtc_account_id(),
+            "Only atBTC can be transferred"
+        );
+
+        if msg == REWARD_FUNDING_MSG {
+            return self.fund_reward_pool(sender_id, amount);
+        }
+
+        if msg != BOND_TRANSFER_MSG {
+            log!("Unknown transfer msg \"{}\", refunding {} atBTC", msg, amount.0);
+            return PromiseOrValue::Value(amount);
+        }
+
+        if self.validators.get(&sender_id).is_none() {
+            log!("{} is not a validator, refunding {} atBTC", sender_id, amount.0);
+            return PromiseOrValue::Value(amount);
+        }
+
+        let mut bond = self.validator_bonds.get(&sender_id).cloned().unwrap_or_default();
+        bond.abtc_amount = U128(bond.abtc_amount.0 + amount.0);
+        self.validator_bonds.insert(sender_id.clone(), bond);
+
+        log!("Validator {} bonded {} atBTC", sender_id, amount.0);
+
+        PromiseOrValue::Value(U128(0))
+    }
+
+    // Moves part of the caller's bond into unbonding, it stays slashable until the unbonding period ends
+    pub fn request_validator_unbond(&mut self, near_amount: U128, abtc_amount: U128) {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+        let mut bond = self
+            .validator_bonds
+            .get(&caller)
+            .cloned()
+            .expect("No bond found for caller");
+
+        assert!(
+            near_amount.0 <= bond.near_amount.0 && abtc_amount.0 <= bond.abtc_amount.0,
+            "Unbond amount exceeds bonded amount"
+        );
+        assert!(
+            near_amount.0 > 0 || abtc_amount.0 > 0,
+            "Unbond amount must be greater than 0"
+        );
+
+        bond.near_amount = U128(bond.near_amount.0 - near_amount.0);
+        bond.abtc_amount = U128(bond.abtc_amount.0 - abtc_amount.0);
+        bond.unbonding_near_amount = U128(bond.unbonding_near_amount.0 + near_amount.0);
+        bond.unbonding_abtc_amount = U128(bond.unbonding_abtc_amount.0 + abtc_amount.0);
+        bond.unbonding_available_at =
+            env::block_timestamp() / 1_000_000_000 + self.validator_bond_params.unbonding_period;
+
+        self.validator_bonds.insert(caller, bond);
+    }
+
+    // Returns the caller's unbonded NEAR and atBTC once the unbonding period has passed
+    pub fn withdraw_validator_unbonded(&mut self) -> Promise {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+        let mut bond = self
+            .validator_bonds
+            .get(&caller)
+            .cloned()
+            .expect("No bond found for caller");
+
+        assert!(
+            bond.unbonding_near_amount.0 > 0 || bond.unbonding_abtc_amount.0 > 0,
+            "Nothing to withdraw"
+        );
+        assert!(
+            env::block_timestamp() / 1_000_000_000 >= bond.unbonding_available_at,
+            "Unbonding period has not ended"
+        );
+
+        let near_amount = bond.unbonding_near_amount.0;
+        let abtc_amount = bond.unbonding_abtc_amount.0;
+        bond.unbonding_near_amount = U128(0);
+        bond.unbonding_abtc_amount = U128(0);
+
+        if bond.near_amount.0 == 0 && bond.abtc_amount.0 == 0 {
+            self.validator_bonds.remove(&caller);
+        } else {
+            self.validator_bonds.insert(caller.clone(), bond);
+        }
+
+        self.transfer_bond(&caller, near_amount, abtc_amount)
+    }
+
+    // Challenge: the validator verified a deposit record the BTC light client later proves wrong
+    // raw_tx_hex is the deposit transaction, proven by a merkle inclusion proof in a confirmed main chain block (see verify_deposit_btc_inclusion_proof)
+    // The challenge succeeds if the deposit record the validator voted for does not match the fields decoded from the transaction
+    // A deposit's minted txn hash is written once and minted txn hash votes are keyed on it, so a validator cannot vote for
+    // two minted txn hashes of one deposit, false attestations are challenged against the proven transaction here
+    pub fn challenge_disproven_deposit_vote(
+        &mut self,
+        validator_id: AccountId,
+        raw_tx_hex: String,
+        block_hash: String,
+        merkle_proof: Vec<String>,
+        tx_index: u64,
+    ) -> Promise {
+        self.assert_not_paused();
+
+        let details = self.parse_deposit_btc_raw_tx(raw_tx_hex);
+        if let Err(reason) =
+            self.check_btc_inclusion_proof(&details.btc_txn_hash, &block_hash, &merkle_proof, tx_index)
+        {
+            env::panic_str(&reason);
+        }
+
+        let deposit = self
+            .deposits
+            .get(&details.btc_txn_hash)
+            .expect("Deposit record not found");
+        assert!(
+            self.get_validators_by_txn_hash(details.btc_txn_hash.clone())
+                .contains(&validator_id),
+            "Validator has not verified the deposit"
+        );
+        assert!(
+            (!details.btc_sender_address.is_empty()
+                && deposit.btc_sender_address != details.btc_sender_address)
+                || deposit.receiving_chain_id != details.receiving_chain_id
+                || deposit.receiving_address != details.receiving_address
+                || deposit.btc_amount != details.btc_amount
+                || deposit.fee_amount != details.fee_amount,
+            "Deposit record matches the proven transaction"
+        );
+
+        self.slash_validator_bond(&validator_id, env::predecessor_account_id())
+    }
+
+    pub fn set_validator_bond_params(
+        &mut self,
+        min_near_bond: U128,
+        min_abtc_bond: U128,
+        unbonding_period: u64,
+        challenger_reward_bps: u16,
+    ) {
+        self.assert_owner();
+
+        assert!(
+            challenger_reward_bps as u128 <= BPS_DENOMINATOR,
+            "Challenger reward cannot exceed 10000 basis points"
+        );
+
+        self.validator_bond_params = ValidatorBondParams {
+            min_near_bond,
+            min_abtc_bond,
+            unbonding_period,
+            challenger_reward_bps,
+        };
+    }
+
+    // Restores atBTC whose ft_transfer failed as an unbonded amount receiver_id can withdraw again
+    #[private]
+    pub fn resolve_bond_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        abtc_amount: U128,
+        #[callback_result] result: Result<(), PromiseError>,
+    ) {
+        if result.is_ok() {
+            return;
+        }
+
+        let mut bond = self
+            .validator_bonds
+            .get(&receiver_id)
+            .cloned()
+            .unwrap_or_else(|| ValidatorBondRecord {
+                unbonding_available_at: env::block_timestamp() / 1_000_000_000,
+                ..Default::default()
+            });
+        bond.unbonding_abtc_amount = U128(bond.unbonding_abtc_amount.0 + abtc_amount.0);
+        self.validator_bonds.insert(receiver_id.clone(), bond);
+
+        log!(
+            "atBTC transfer of {} to {} failed, restored as unbonded atBTC",
+            abtc_amount.0,
+            receiver_id
+        );
+    }
+
+    pub fn get_validator_bond_params(&self) -> ValidatorBondParams {
+        self.validator_bond_params.clone()
+    }
+
+    pub fn get_validator_bond(&self, account_id: AccountId) -> Option<ValidatorBondRecord> {
+        self.validator_bonds.get(&account_id).cloned()
+    }
+
+    // Returns (slashed NEAR, slashed atBTC) kept by the contract after paying challengers
+    pub fn get_slashed_bond_totals(&self) -> (U128, U128) {
+        (self.slashed_near_amount, self.slashed_abtc_amount)
+    }
+
+    // A validator is eligible to vote if bonding is disabled or its active bond meets one of the enabled minimums
+    pub fn is_validator_bonded(&self, account_id: &AccountId) -> bool {
+        let params = &self.validator_bond_params;
+        if params.min_near_bond.0 == 0 && params.min_abtc_bond.0 == 0 {
+            return true;
+        }
+
+        match self.validator_bonds.get(account_id) {
+            Some(bond) => {
+                (params.min_near_bond.0 > 0 && bond.near_amount.0 >= params.min_near_bond.0)
+                    || (params.min_abtc_bond.0 > 0 && bond.abtc_amount.0 >= params.min_abtc_bond.0)
+            }
+            None => false,
+        }
+    }
+
+    // Slashes the whole bond of a validator, including amounts still unbonding, and removes it from all chains
+    // challenger_reward_bps of the slashed bond is paid to the challenger, the remainder stays with the contract
+    fn slash_validator_bond(&mut self, validator_id: &AccountId, challenger: AccountId) -> Promise {
+        assert!(
+            &challenger != validator_id,
+            "Validator cannot challenge its own votes"
+        );
+
+        let bond = self
+            .validator_bonds
+            .remove(validator_id)
+            .expect("Validator has no bond to slash");
+
+        let near_amount = bond.near_amount.0 + bond.unbonding_near_amount.0;
+        let abtc_amount = bond.abtc_amount.0 + bond.unbonding_abtc_amount.0;
+        let reward_bps = self.validator_bond_params.challenger_reward_bps as u128;
+        let near_reward = near_amount * reward_bps / BPS_DENOMINATOR;
+        let abtc_reward = abtc_amount * reward_bps / BPS_DENOMINATOR;
+
+        self.slashed_near_amount = U128(self.slashed_near_amount.0 + near_amount - near_reward);
+        self.slashed_abtc_amount = U128(self.slashed_abtc_amount.0 + abtc_amount - abtc_reward);
+        if let Some(chains) = self.validators.remove(validator_id) {
+            for chain_id in chains {
+                self.revoke_outstanding_votes(validator_id, &chain_id);
+            }
+        }
+
+        log!(
+            "Slashed validator {}: {} yoctoNEAR and {} atBTC, challenger {} rewarded {} yoctoNEAR and {} atBTC",
+            validator_id,
+            near_amount,
+            abtc_amount,
+            challenger,
+            near_reward,
+            abtc_reward
+        );
+
+        self.transfer_bond(&challenger, near_reward, abtc_reward)
+    }
+}
+
+impl Atlas {
+    // atBTC token account on NEAR, taken from the NEAR chain config
+    pub fn get_near_abtc_account_id(&self) -> Option<AccountId> {
+        self.chain_configs
+            .get_chain_configs()
+            .into_iter()
+            .find(|chain_config| chain_config.network_type == NEAR)
+            .and_then(|chain_config| AccountId::from_str(&chain_config.abtc_address).ok())
+    }
+
+    // Sends NEAR and atBTC to receiver, atBTC through ft_transfer on the NEAR atBTC token
+    // atBTC the token fails to transfer is restored by resolve_bond_transfer
+    fn transfer_bond(&self, receiver: &AccountId, near_amount: u128, abtc_amount: u128) -> Promise {
+        let mut promise = Promise::new(receiver.clone())
+            .transfer(NearToken::from_yoctonear(near_amount));
+
+        if abtc_amount > 0 {
+            let abtc_account_id = self
+                .get_near_abtc_account_id()
+                .expect("NEAR atBTC chain config not found");
+            let args = json!({
+                "receiver_id": receiver,
+                "amount": U128(abtc_amount),
+            })
+            .to_string()
+            .into_bytes();
+
+            promise = promise.and(
+                Promise::new(abtc_account_id)
+                    .function_call("ft_transfer".to_string(), args, ONE_YOCTO, GAS_FOR_FT_TRANSFER)
+                    .then(
+                        Self::ext(env::current_account_id())
+                            .with_static_gas(GAS_FOR_BOND_TRANSFER_CALLBACK)
+                            .resolve_bond_transfer(receiver.clone(), U128(abtc_amount)),
+                    ),
+            );
+        }
+
+        promise
+    }
+}
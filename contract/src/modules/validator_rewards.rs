@@ -0,0 +1,296 @@
+use crate::atlas::Atlas;
+use crate::constants::delimiter::COMMA;
+use crate::constants::near_gas::*;
+use crate::constants::network_type::{EVM, NEAR};
+use crate::constants::role::ROLE_DEPOSIT_OPERATOR;
+use crate::constants::status::*;
+use crate::constants::validator_bond::BPS_DENOMINATOR;
+use crate::modules::events::emit_event;
+use crate::modules::structs::{RewardClaimRecord, RewardPoolRecord};
+use crate::AtlasExt;
+use ethers_core::types::{H160, U256};
+use near_sdk::env::keccak256;
+use near_sdk::json_types::U128;
+use near_sdk::{env, log, near_bindgen, AccountId, Promise, PromiseError, PromiseOrValue};
+use serde_json::json;
+
+#[near_bindgen]
+impl Atlas {
+    pub fn get_validator_rewards(&self, account_id: AccountId) -> u64 {
+        self.validator_rewards.get(&account_id).copied().unwrap_or(0)
+    }
+
+    pub fn get_reward_pool(&self) -> RewardPoolRecord {
+        self.reward_pool.clone()
+    }
+
+    pub fn get_reward_claim(&self, claim_id: String) -> Option<RewardClaimRecord> {
+        self.reward_claims.get(&claim_id).cloned()
+    }
+
+    // Pays all accrued rewards of the caller to receiver_id out of the atBTC funded into the reward pool
+    // Rewards are credited back if the transfer fails
+    pub fn claim_validator_rewards_near(&mut self, receiver_id: AccountId) -> Promise {
+        self.assert_not_paused();
+
+        let caller: AccountId = env::predecessor_account_id();
+        let abtc_account_id = self
+            .get_near_abtc_account_id()
+            .expect("NEAR atBTC chain config not found");
+        let chain_id = self
+            .chain_configs
+            .get_chain_configs()
+            .into_iter()
+            .find(|chain_config| chain_config.network_type == NEAR)
+            .map(|chain_config| chain_config.chain_id)
+            .expect("NEAR atBTC chain config not found");
+
+        let claim = self.open_reward_claim(&caller, chain_id, receiver_id.to_string());
+
+        let storage_deposit_args = json!({
+            "account_id": receiver_id
+        })
+        .to_string()
+        .into_bytes();
+
+        let transfer_args = json!({
+            "receiver_id": receiver_id,
+            "amount": U128(claim.amount as u128),
+            "memo": claim.claim_id
+        })
+        .to_string()
+        .into_bytes();
+
+        Promise::new(abtc_account_id.clone())
+            .function_call(
+                "storage_deposit".to_string(),
+                storage_deposit_args,
+                MIN_STORAGE_DEPOSIT,
+                GAS_FOR_STORAGE_DEPOSIT,
+            )
+            .then(Promise::new(abtc_account_id).function_call(
+                "ft_transfer".to_string(),
+                transfer_args,
+                ONE_YOCTO,
+                GAS_FOR_FT_TRANSFER,
+            ))
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(GAS_FOR_REWARD_CLAIM_CALLBACK)
+                    .reward_claim_callback(claim.claim_id),
+            )
+    }
+
+    #[private]
+    pub fn reward_claim_callback(
+        &mut self,
+        claim_id: String,
+        #[callback_result] result: Result<(), PromiseError>,
+    ) {
+        let mut claim = self
+            .reward_claims
+            .get(&claim_id)
+            .cloned()
+            .expect("Reward claim not found");
+
+        if result.is_ok() {
+            claim.status = REWARD_CLAIM_COMPLETED;
+        } else {
+            // Transfer failed, credit the rewards back so they can be claimed again
+            claim.status = REWARD_CLAIM_FAILED;
+            let rewards = self.get_validator_rewards(claim.account_id.clone()) + claim.amount;
+            self.validator_rewards.insert(claim.account_id.clone(), rewards);
+            self.reward_pool.total_claimed -= claim.amount;
+            log!("Reward claim {} failed, credited {} back to {}", claim_id, claim.amount, claim.account_id);
+        }
+
+        self.reward_claims.insert(claim_id, claim);
+    }
+
+    // Claims all accrued rewards of the caller to an EVM address
+    // The claim stays pending until a deposit operator signs its transfer with create_reward_claim_signed_tx
+    pub fn claim_validator_rewards_evm(&mut self, chain_id: String, receiving_address: String) -> String {
+        self.assert_not_paused();
+
+        let chain_config = self
+            .chain_configs
+            .get_chain_config(chain_id.clone())
+            .expect("Chain ID not found");
+        assert!(chain_config.network_type == EVM, "Chain ID is not an EVM chain");
+        assert!(
+            Self::is_valid_eth_address(receiving_address.clone()),
+            "Invalid EVM receiving address"
+        );
+
+        let caller: AccountId = env::predecessor_account_id();
+        self.open_reward_claim(&caller, chain_id, receiving_address)
+            .claim_id
+    }
+
+    // Signs an atBTC transfer paying out a pending EVM reward claim from the signer's funded reward balance on the chain
+    // Rewards are paid out of fees already collected, so the claim never mints atBTC
+    // A claim can only be signed once, so it cannot be paid out twice under different nonces
+    pub fn create_reward_claim_signed_tx(
+        &mut self,
+        claim_id: String,
+        gas: u128,
+        max_fee_per_gas: Option<u128>,
+        max_priority_fee_per_gas: Option<u128>,
+    ) -> PromiseOrValue<String> {
+        self.assert_not_paused();
+        self.assert_role(ROLE_DEPOSIT_OPERATOR);
+
+        let mut claim = self
+            .reward_claims
+            .get(&claim_id)
+            .cloned()
+            .expect("Reward claim not found");
+        assert!(claim.status == REWARD_CLAIM_PENDING, "Reward claim is not pending");
+
+        let destination = H160::from_slice(
+            &hex::decode(claim.receiving_address.trim_start_matches("0x"))
+                .expect("Invalid hex address"),
+        );
+        let data = Self::encode_transfer_function_call(destination, U256::from(claim.amount));
+        let chain_id = claim.chain_id.clone();
+
+        claim.status = REWARD_CLAIM_SIGNED;
+        self.reward_claims.insert(claim_id.clone(), claim);
+
+        self.sign_abtc_call(
+            chain_id,
+            data,
+            &claim_id,
+            gas,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        )
+    }
+}
+
+impl Atlas {
+    // Moves the fee share of a finished record into the reward pool and credits it to the validators whose votes
+    // reached the threshold on each (verification_key, chain_id), one equal share per useful vote
+    // The share of votes that were not needed, or of steps proven without votes, stays unallocated in the pool
+    pub fn credit_validator_rewards(&mut self, record_key: &str, fee_amount: u64, verifications: &[(String, String)]) {
+        let reward = (fee_amount as u128
+            * self.global_params.get_fee_validator_rewards_bps() as u128
+            / BPS_DENOMINATOR) as u64;
+        if reward == 0 {
+            return;
+        }
+        self.reward_pool.total_accrued += reward;
+
+        let useful_votes: Vec<AccountId> = verifications
+            .iter()
+            .flat_map(|(verification_key, chain_id)| self.get_useful_voters(verification_key, chain_id))
+            .collect();
+        if useful_votes.is_empty() {
+            return;
+        }
+
+        let reward_per_vote = reward / useful_votes.len() as u64;
+        for validator in useful_votes.iter() {
+            let rewards = self.get_validator_rewards(validator.clone()) + reward_per_vote;
+            self.validator_rewards.insert(validator.clone(), rewards);
+        }
+        self.reward_pool.total_credited += reward_per_vote * useful_votes.len() as u64;
+
+        emit_event(
+            "validator_rewards_credited",
+            json!({
+                "record_key": record_key,
+                "reward": reward.to_string(),
+                "reward_per_vote": reward_per_vote.to_string(),
+                "validators": useful_votes,
+            }),
+        );
+    }
+
+    // Voters of a verification key in voting order, up to the one whose vote reached the chain's threshold
+    fn get_useful_voters(&self, verification_key: &str, chain_id: &str) -> Vec<AccountId> {
+        let chain_config = match self.chain_configs.get_chain_config(chain_id.to_string()) {
+            Some(chain_config) => chain_config,
+            None => return Vec::new(),
+        };
+        // Weighted chains count validator weights, the others count validators
+        let required_weight = self.get_required_verified_weight(chain_id.to_string());
+        let threshold = required_weight.unwrap_or(chain_config.validators_threshold as u64);
+
+        let mut useful_voters = Vec::new();
+        let mut accumulated = 0u64;
+        for validator in self.get_validators_by_txn_hash(verification_key.to_string()) {
+            accumulated += if required_weight.is_some() {
+                self.get_validator_weight(validator.clone(), chain_id.to_string())
+            } else {
+                1
+            };
+            useful_voters.push(validator);
+
+            if accumulated >= threshold {
+                break;
+            }
+        }
+        useful_voters
+    }
+
+    // Adds atBTC sent with the reward funding msg to the balance claims are paid from
+    // Rewards are paid out of fees already collected, so claims never mint atBTC
+    pub fn fund_reward_pool(&mut self, sender_id: AccountId, amount: U128) -> PromiseOrValue<U128> {
+        self.reward_pool.total_funded += amount.0 as u64;
+
+        emit_event(
+            "reward_pool_funded",
+            json!({
+                "sender_id": sender_id,
+                "amount": amount,
+                "total_funded": self.reward_pool.total_funded.to_string(),
+            }),
+        );
+
+        PromiseOrValue::Value(U128(0))
+    }
+
+    // Helper function to encode the ERC-20 transfer function call
+    fn encode_transfer_function_call(to_address: H160, amount: U256) -> Vec<u8> {
+        let function_selector = &keccak256("transfer(address,uint256)".as_bytes())[0..4];
+
+        let mut encoded = ethabi::encode(&[ethabi::Token::Address(to_address), ethabi::Token::Uint(amount)]);
+
+        let mut function_call_data = Vec::with_capacity(function_selector.len() + encoded.len());
+        function_call_data.extend_from_slice(function_selector);
+        function_call_data.append(&mut encoded);
+
+        function_call_data
+    }
+
+    // Deducts all accrued rewards of the validator into a new claim record
+    fn open_reward_claim(&mut self, validator: &AccountId, chain_id: String, receiving_address: String) -> RewardClaimRecord {
+        let amount = self.get_validator_rewards(validator.clone());
+        assert!(amount > 0, "No rewards to claim");
+        assert!(
+            self.reward_pool.total_claimed + amount <= self.reward_pool.total_funded,
+            "Reward pool is not funded enough to pay the claim"
+        );
+
+        self.reward_pool.claim_nonce += 1;
+        self.reward_pool.total_claimed += amount;
+        self.validator_rewards.remove(validator);
+
+        let claim = RewardClaimRecord {
+            claim_id: format!("REWARD{}{}", COMMA, self.reward_pool.claim_nonce),
+            account_id: validator.clone(),
+            chain_id,
+            receiving_address,
+            amount,
+            status: REWARD_CLAIM_PENDING,
+            timestamp: env::block_timestamp() / 1_000_000_000,
+        };
+        self.reward_claims.insert(claim.claim_id.clone(), claim.clone());
+
+        log!("Opened reward claim {} of {} for validator {}", claim.claim_id, amount, validator);
+
+        claim
+    }
+}
+
@@ -0,0 +1,153 @@
+use crate::atlas::Atlas;
+use crate::constants::delimiter::COMMA;
+use crate::constants::validator_bond::BPS_DENOMINATOR;
+use crate::constants::validator_stats::*;
+use crate::modules::structs::{ValidatorParticipation, ValidatorStatsRecord, VoteBucketRecord};
+use crate::AtlasExt;
+use near_sdk::{env, near_bindgen, AccountId};
+
+fn validator_stats_key(chain_id: &str, account_id: &AccountId) -> String {
+    format!("{}{}{}", chain_id, COMMA, account_id)
+}
+
+// Key of the number of records first voted on within a bucket
+fn chain_bucket_key(chain_id: &str, bucket: u64) -> String {
+    format!("{}{}{}", chain_id, COMMA, bucket % VOTE_BUCKET_SLOTS)
+}
+
+// Key of the number of votes a validator cast within a bucket
+fn validator_bucket_key(chain_id: &str, account_id: &AccountId, bucket: u64) -> String {
+    format!("{}{}{}{}{}", chain_id, COMMA, account_id, COMMA, bucket % VOTE_BUCKET_SLOTS)
+}
+
+fn current_timestamp() -> u64 {
+    env::block_timestamp() / 1_000_000_000
+}
+
+#[near_bindgen]
+impl Atlas {
+    pub fn get_validator_stats(&self, account_id: AccountId, chain_id: String) -> Option<ValidatorStatsRecord> {
+        self.validator_stats
+            .get(&validator_stats_key(&chain_id, &account_id))
+            .cloned()
+    }
+
+    // Participation of every validator of a chain over the last window_seconds, rounded to whole hourly buckets
+    // A validator's rate is its votes divided by the number of records any validator of the chain voted on
+    pub fn get_validator_participation(&self, chain_id: String, window_seconds: u64) -> Vec<ValidatorParticipation> {
+        assert!(
+            window_seconds > 0 && window_seconds <= MAX_PARTICIPATION_WINDOW,
+            "Window must be between 1 second and 30 days"
+        );
+
+        let buckets = self.window_buckets(window_seconds);
+        let record_count: u64 = buckets
+            .iter()
+            .map(|bucket| self.vote_bucket_count(&chain_bucket_key(&chain_id, *bucket), *bucket))
+            .sum();
+
+        self.get_chain_validators(&chain_id)
+            .into_iter()
+            .map(|account_id| {
+                let vote_count: u64 = buckets
+                    .iter()
+                    .map(|bucket| {
+                        self.vote_bucket_count(
+                            &validator_bucket_key(&chain_id, &account_id, *bucket),
+                            *bucket,
+                        )
+                    })
+                    .sum();
+                let participation_bps = if record_count == 0 {
+                    0
+                } else {
+                    (vote_count as u128 * BPS_DENOMINATOR / record_count as u128) as u64
+                };
+
+                ValidatorParticipation {
+                    account_id,
+                    vote_count,
+                    record_count,
+                    participation_bps,
+                }
+            })
+            .collect()
+    }
+
+    // Validators of a chain that have not voted within the last inactive_seconds, including those that never voted
+    pub fn get_inactive_validators(&self, chain_id: String, inactive_seconds: u64) -> Vec<AccountId> {
+        let cutoff = current_timestamp().saturating_sub(inactive_seconds);
+
+        self.get_chain_validators(&chain_id)
+            .into_iter()
+            .filter(|account_id| {
+                match self.validator_stats.get(&validator_stats_key(&chain_id, account_id)) {
+                    Some(stats) => stats.last_vote_timestamp < cutoff,
+                    None => true,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Atlas {
+    // Updates the validator's counters and the hourly buckets for a vote on verification_key
+    pub fn record_validator_liveness(&mut self, validator: &AccountId, chain_id: &str, verification_key: &str) {
+        let timestamp = current_timestamp();
+        let bucket = timestamp / VALIDATOR_STATS_BUCKET_SECONDS;
+
+        let stats_key = validator_stats_key(chain_id, validator);
+        let mut stats = self.validator_stats.get(&stats_key).cloned().unwrap_or_default();
+        if stats.vote_count == 0 {
+            stats.first_vote_timestamp = timestamp;
+        }
+        stats.vote_count += 1;
+        stats.last_vote_timestamp = timestamp;
+        self.validator_stats.insert(stats_key, stats);
+
+        self.increment_vote_bucket(validator_bucket_key(chain_id, validator, bucket), bucket);
+
+        // The first vote on a key opens a record the other validators of the chain are expected to vote on
+        if self.get_validators_by_txn_hash(verification_key.to_string()).len() == 1 {
+            self.increment_vote_bucket(chain_bucket_key(chain_id, bucket), bucket);
+        }
+    }
+
+    pub fn record_revoked_vote(&mut self, validator: &AccountId, chain_id: &str) {
+        let stats_key = validator_stats_key(chain_id, validator);
+        if let Some(mut stats) = self.validator_stats.get(&stats_key).cloned() {
+            stats.revoked_vote_count += 1;
+            self.validator_stats.insert(stats_key, stats);
+        }
+    }
+
+    fn get_chain_validators(&self, chain_id: &String) -> Vec<AccountId> {
+        self.validators
+            .iter()
+            .filter(|(account_id, _)| self.is_validator(account_id, chain_id))
+            .map(|(account_id, _)| account_id.clone())
+            .collect()
+    }
+
+    fn window_buckets(&self, window_seconds: u64) -> Vec<u64> {
+        let current_bucket = current_timestamp() / VALIDATOR_STATS_BUCKET_SECONDS;
+        let bucket_count = (window_seconds + VALIDATOR_STATS_BUCKET_SECONDS - 1) / VALIDATOR_STATS_BUCKET_SECONDS;
+        (0..bucket_count)
+            .filter_map(|offset| current_bucket.checked_sub(offset))
+            .collect()
+    }
+
+    // Count of the bucket stored in the slot of key, 0 if the slot holds an expired bucket
+    fn vote_bucket_count(&self, key: &str, bucket: u64) -> u64 {
+        self.vote_buckets
+            .get(key)
+            .filter(|record| record.bucket == bucket)
+            .map_or(0, |record| record.count)
+    }
+
+    // Overwrites the expired bucket in the slot of key when a new bucket starts
+    fn increment_vote_bucket(&mut self, key: String, bucket: u64) {
+        let count = self.vote_bucket_count(&key, bucket) + 1;
+        self.vote_buckets.insert(key, VoteBucketRecord { bucket, count });
+    }
+}
@@ -0,0 +1,212 @@
+use crate::atlas::Atlas;
+use crate::constants::status::*;
+use crate::constants::vote_type::*;
+use crate::modules::events::emit_event;
+use crate::modules::structs::ValidatorVoteRecord;
+use crate::AtlasExt;
+use near_sdk::{log, near_bindgen, AccountId};
+use serde_json::json;
+
+#[near_bindgen]
+impl Atlas {
+    // Getter for the votes of a validator kept in the reverse index
+    pub fn get_validator_votes(&self, account_id: AccountId) -> Vec<ValidatorVoteRecord> {
+        self.validator_votes
+            .get(&account_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    // Drops the votes of a validator on records that are no longer pending from the reverse index
+    // Votes on finished records are never revoked, so keeping them only costs storage
+    // The next vote of the validator prunes them as well, this frees the storage of validators that stopped voting
+    pub fn prune_validator_votes(&mut self, account_id: AccountId) {
+        self.assert_not_paused();
+
+        let votes = self.get_validator_votes(account_id.clone());
+        let outstanding: Vec<ValidatorVoteRecord> = votes
+            .into_iter()
+            .filter(|vote| self.is_vote_outstanding(vote))
+            .collect();
+
+        if outstanding.is_empty() {
+            self.validator_votes.remove(&account_id);
+        } else {
+            self.validator_votes.insert(account_id, outstanding);
+        }
+    }
+}
+
+impl Atlas {
+    // Adds a validator's vote to its accumulated weight and to the validator_votes reverse index
+    // The index only keeps outstanding votes, votes on finished records are dropped as the new vote is added
+    // Starts the record's dispute window if the vote brings it to its threshold
+    pub fn record_validator_vote(
+        &mut self,
+        validator: &AccountId,
+        vote_type: &str,
+        record_key: &str,
+        verification_key: &str,
+        chain_id: &str,
+    ) {
+        let weight = self.record_verified_weight(verification_key, validator, chain_id);
+
+        let mut votes: Vec<ValidatorVoteRecord> = self
+            .get_validator_votes(validator.clone())
+            .into_iter()
+            .filter(|vote| self.is_vote_outstanding(vote))
+            .collect();
+        votes.push(ValidatorVoteRecord {
+            vote_type: vote_type.to_string(),
+            record_key: record_key.to_string(),
+            verification_key: verification_key.to_string(),
+            chain_id: chain_id.to_string(),
+            weight,
+        });
+        self.validator_votes.insert(validator.clone(), votes);
+
+        self.record_validator_liveness(validator, chain_id, verification_key);
+        self.start_dispute_window(vote_type, record_key);
+    }
+
+    // Subtracts the validator's votes for chain_id on records that are still pending and emits a vote_revoked event per vote
+    // Votes of other chains stay in the index, votes on finished records are dropped from it
+    pub fn revoke_outstanding_votes(&mut self, validator: &AccountId, chain_id: &str) {
+        let votes = self.get_validator_votes(validator.clone());
+        let mut remaining = Vec::new();
+
+        for vote in votes {
+            if vote.chain_id != chain_id {
+                remaining.push(vote);
+            } else if self.is_vote_outstanding(&vote) {
+                self.revoke_vote(validator, &vote);
+            }
+        }
+
+        if remaining.is_empty() {
+            self.validator_votes.remove(validator);
+        } else {
+            self.validator_votes.insert(validator.clone(), remaining);
+        }
+    }
+
+    // A vote is outstanding while the record it counts toward has not moved past the step it verifies
+    fn is_vote_outstanding(&self, vote: &ValidatorVoteRecord) -> bool {
+        match vote.vote_type.as_str() {
+            VOTE_DEPOSIT_VERIFIED => self
+                .deposits
+                .get(&vote.record_key)
+                .map_or(false, |deposit| deposit.status == DEP_BTC_DEPOSITED_INTO_ATLAS),
+            VOTE_DEPOSIT_MINTED_TXN_HASH => self
+                .deposits
+                .get(&vote.record_key)
+                .map_or(false, |deposit| deposit.status == DEP_BTC_PENDING_MINTED_INTO_ABTC),
+            VOTE_REDEMPTION_VERIFIED => self
+                .redemptions
+                .get(&vote.record_key)
+                .map_or(false, |redemption| redemption.status == RED_ABTC_BURNT),
+            VOTE_REDEMPTION_BTC_TXN_HASH => self
+                .redemptions
+                .get(&vote.record_key)
+                .map_or(false, |redemption| {
+                    redemption.status == RED_BTC_PENDING_MEMPOOL_CONFIRMATION
+                }),
+            VOTE_BRIDGING_VERIFIED => self
+                .bridgings
+                .get(&vote.record_key)
+                .map_or(false, |bridging| bridging.status == BRG_ABTC_BURNT),
+            VOTE_BRIDGING_DEST_TXN_HASH => self
+                .bridgings
+                .get(&vote.record_key)
+                .map_or(false, |bridging| {
+                    bridging.status == BRG_ABTC_PENDING_BRIDGE_FROM_ORIGIN_TO_DEST
+                }),
+            VOTE_EVM_BLOCK_HEADER => self.is_evm_header_pending(&vote.record_key),
+            _ => false,
+        }
+    }
+
+    fn revoke_vote(&mut self, validator: &AccountId, vote: &ValidatorVoteRecord) {
+        let mut validators_list = self.get_validators_by_txn_hash(vote.verification_key.clone());
+        if !validators_list.contains(validator) {
+            return;
+        }
+        validators_list.retain(|account_id| account_id != validator);
+        if validators_list.is_empty() {
+            self.verifications.remove(&vote.verification_key);
+        } else {
+            self.verifications
+                .insert(vote.verification_key.clone(), validators_list);
+        }
+
+        let verified_weight = self
+            .get_verified_weight(vote.verification_key.clone())
+            .saturating_sub(vote.weight);
+        self.verified_weights
+            .insert(vote.verification_key.clone(), verified_weight);
+
+        match vote.vote_type.as_str() {
+            VOTE_DEPOSIT_VERIFIED | VOTE_DEPOSIT_MINTED_TXN_HASH => {
+                if let Some(mut deposit) = self.deposits.get(&vote.record_key).cloned() {
+                    if vote.vote_type == VOTE_DEPOSIT_VERIFIED {
+                        deposit.verified_count = deposit.verified_count.saturating_sub(1);
+                    } else {
+                        deposit.minted_txn_hash_verified_count =
+                            deposit.minted_txn_hash_verified_count.saturating_sub(1);
+                    }
+                    self.deposits.insert(vote.record_key.clone(), deposit);
+                }
+            }
+            VOTE_REDEMPTION_VERIFIED | VOTE_REDEMPTION_BTC_TXN_HASH => {
+                if let Some(mut redemption) = self.redemptions.get(&vote.record_key).cloned() {
+                    if vote.vote_type == VOTE_REDEMPTION_VERIFIED {
+                        redemption.verified_count = redemption.verified_count.saturating_sub(1);
+                    } else {
+                        redemption.btc_txn_hash_verified_count =
+                            redemption.btc_txn_hash_verified_count.saturating_sub(1);
+                    }
+                    self.redemptions.insert(vote.record_key.clone(), redemption);
+                }
+            }
+            VOTE_BRIDGING_VERIFIED | VOTE_BRIDGING_DEST_TXN_HASH => {
+                if let Some(mut bridging) = self.bridgings.get(&vote.record_key).cloned() {
+                    if vote.vote_type == VOTE_BRIDGING_VERIFIED {
+                        bridging.verified_count = bridging.verified_count.saturating_sub(1);
+                    } else {
+                        bridging.dest_txn_hash_verified_count =
+                            bridging.dest_txn_hash_verified_count.saturating_sub(1);
+                    }
+                    self.bridgings.insert(vote.record_key.clone(), bridging);
+                }
+            }
+            VOTE_EVM_BLOCK_HEADER => {
+                if let Some(mut header) = self.evm_headers.get(&vote.record_key).cloned() {
+                    header.verified_count = header.verified_count.saturating_sub(1);
+                    self.evm_headers.insert(vote.record_key.clone(), header);
+                }
+            }
+            _ => {}
+        }
+
+        self.record_revoked_vote(validator, &vote.chain_id);
+
+        log!(
+            "Revoked {} vote of validator {} on {}",
+            vote.vote_type,
+            validator,
+            vote.verification_key
+        );
+
+        emit_event(
+            "vote_revoked",
+            json!({
+                "validator_id": validator,
+                "vote_type": vote.vote_type,
+                "record_key": vote.record_key,
+                "verification_key": vote.verification_key,
+                "chain_id": vote.chain_id,
+                "weight": vote.weight.to_string(),
+            }),
+        );
+    }
+}
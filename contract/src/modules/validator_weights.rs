@@ -0,0 +1,141 @@
+use crate::atlas::Atlas;
+use crate::constants::delimiter::COMMA;
+use crate::constants::validator_bond::BPS_DENOMINATOR;
+use crate::constants::validator_weight::*;
+use crate::modules::structs::WeightThresholdRecord;
+use crate::AtlasExt;
+use near_sdk::{env, log, near_bindgen, AccountId};
+
+fn validator_weight_key(chain_id: &str, account_id: &AccountId) -> String {
+    format!("{}{}{}", chain_id, COMMA, account_id)
+}
+
+#[near_bindgen]
+impl Atlas {
+    // Sets the voting weight of a validator on a chain, validators without a weight count as 1
+    pub fn set_validator_weight(&mut self, account_id: AccountId, chain_id: String, weight: u64) {
+        self.assert_not_paused();
+        self.assert_owner();
+
+        assert!(!chain_id.is_empty(), "Chain ID cannot be empty");
+        assert!(weight > 0, "Weight must be greater than 0");
+
+        self.validator_weights
+            .insert(validator_weight_key(&chain_id, &account_id), weight);
+    }
+
+    pub fn get_validator_weight(&self, account_id: AccountId, chain_id: String) -> u64 {
+        self.validator_weights
+            .get(&validator_weight_key(&chain_id, &account_id))
+            .copied()
+            .unwrap_or(DEFAULT_VALIDATOR_WEIGHT)
+    }
+
+    // Sets the weight threshold of a chain, which replaces its validators_threshold count
+    // threshold_type absolute: value is the required weight
+    // threshold_type fraction: value is the required share of the chain's total validator weight in basis points
+    pub fn set_chain_weight_threshold(&mut self, chain_id: String, threshold_type: String, value: u64) {
+        self.assert_not_paused();
+        self.assert_owner();
+
+        assert!(
+            self.chain_configs.get_chain_config(chain_id.clone()).is_some(),
+            "Chain ID not found"
+        );
+        assert!(value > 0, "Threshold value must be greater than 0");
+
+        match threshold_type.as_str() {
+            WEIGHT_THRESHOLD_ABSOLUTE => {}
+            WEIGHT_THRESHOLD_FRACTION => {
+                assert!(
+                    value as u128 <= BPS_DENOMINATOR,
+                    "Fraction threshold cannot exceed 10000 basis points"
+                );
+            }
+            _ => env::panic_str("Invalid threshold type"),
+        }
+
+        self.weight_thresholds.insert(
+            chain_id,
+            WeightThresholdRecord {
+                threshold_type,
+                value,
+            },
+        );
+    }
+
+    // Removes the weight threshold of a chain, falling back to its validators_threshold count
+    pub fn remove_chain_weight_threshold(&mut self, chain_id: String) {
+        self.assert_not_paused();
+        self.assert_owner();
+
+        if self.weight_thresholds.remove(&chain_id).is_none() {
+            log!("No weight threshold set for chain ID: {}", chain_id);
+        }
+    }
+
+    pub fn get_chain_weight_threshold(&self, chain_id: String) -> Option<WeightThresholdRecord> {
+        self.weight_thresholds.get(&chain_id).cloned()
+    }
+
+    // Sum of the weights of all authorised validators of a chain
+    pub fn get_total_validator_weight(&self, chain_id: String) -> u64 {
+        self.validators
+            .iter()
+            .filter(|(account_id, _)| self.is_validator(account_id, &chain_id))
+            .map(|(account_id, _)| self.get_validator_weight(account_id.clone(), chain_id.clone()))
+            .sum()
+    }
+
+    // Weight a record needs on a chain, None if the chain has no weight threshold
+    pub fn get_required_verified_weight(&self, chain_id: String) -> Option<u64> {
+        let threshold = self.weight_thresholds.get(&chain_id)?;
+
+        if threshold.threshold_type == WEIGHT_THRESHOLD_FRACTION {
+            let total_weight = self.get_total_validator_weight(chain_id) as u128;
+            let required = (total_weight * threshold.value as u128 + BPS_DENOMINATOR - 1) / BPS_DENOMINATOR;
+            // A chain without validators must not accept unverified records
+            Some((required as u64).max(1))
+        } else {
+            Some(threshold.value)
+        }
+    }
+
+    // Getter for the weight accumulated on a record, keyed like the verifications map:
+    // <btc_txn_hash> for deposits, <btc_txn_hash>,<minted_txn_hash> for minted txn hashes,
+    // <txn_hash> for redemptions, <txn_hash>,<btc_txn_hash> for redemption BTC txn hashes
+    pub fn get_verified_weight(&self, verification_key: String) -> u64 {
+        self.verified_weights
+            .get(&verification_key)
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+impl Atlas {
+    // Adds the validator's weight on chain_id to the weight accumulated under verification_key, returns the weight added
+    pub fn record_verified_weight(&mut self, verification_key: &str, validator: &AccountId, chain_id: &str) -> u64 {
+        let weight = self.get_validator_weight(validator.clone(), chain_id.to_string());
+        let verified_weight = self.get_verified_weight(verification_key.to_string()) + weight;
+        self.verified_weights
+            .insert(verification_key.to_string(), verified_weight);
+        weight
+    }
+
+    // Compares the accumulated weight against the chain's weight threshold if one is set,
+    // otherwise compares the verified count against the validators_threshold count
+    pub fn is_verification_threshold_met(
+        &self,
+        verification_key: &str,
+        chain_id: &str,
+        verified_count: u8,
+        validators_threshold: u8,
+    ) -> bool {
+        match self.get_required_verified_weight(chain_id.to_string()) {
+            Some(required_weight) => {
+                self.get_verified_weight(verification_key.to_string()) >= required_weight
+            }
+            None => verified_count >= validators_threshold,
+        }
+    }
+}
@@ -372,7 +372,7 @@ async fn test_update_contract_with_no_input() {
     context.predecessor_account_id(accounts(0)); // Owner account
     testing_env!(context.build());
 
-    let atlas = Atlas::new(
+    let mut atlas = Atlas::new(
         accounts(0),
         accounts(1),
         accounts(2),
@@ -393,7 +393,7 @@ async fn test_update_contract() {
     ctx.input = vec![0u8; 1024]; // mock code input
     testing_env!(ctx);
 
-    let atlas = Atlas::new(
+    let mut atlas = Atlas::new(
         accounts(0),
         accounts(1),
         accounts(2),
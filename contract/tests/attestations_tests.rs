@@ -0,0 +1,237 @@
+use atlas_protocol::constants::status::*;
+use atlas_protocol::modules::structs::{Atlas, DepositRecord, ValidatorAttestation};
+use ed25519_dalek::{Signer, SigningKey};
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::testing_env;
+use secp256k1::{Message, Secp256k1, SecretKey};
+
+fn set_caller(account_index: usize) {
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(accounts(account_index));
+    testing_env!(context.build());
+}
+
+fn setup_atlas() -> Atlas {
+    set_caller(0);
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    atlas.add_validator(accounts(4), "SIGNET".to_string());
+    atlas.add_validator(accounts(5), "SIGNET".to_string());
+
+    atlas.deposits.insert("btc_txn_hash".to_string(), mempool_deposit());
+
+    atlas
+}
+
+fn mempool_deposit() -> DepositRecord {
+    DepositRecord {
+        btc_txn_hash: "btc_txn_hash".to_string(),
+        btc_sender_address: "tb1qsender".to_string(),
+        receiving_chain_id: "421614".to_string(),
+        receiving_address: "0xf8b5b7c7c8f6a1b1f1f1f1f1f1f1f1f1f1f1f1f1".to_string(),
+        btc_amount: 10000,
+        fee_amount: 0,
+        minted_txn_hash: "".to_string(),
+        timestamp: 1234567890,
+        status: DEP_BTC_DEPOSITED_INTO_ATLAS,
+        remarks: "".to_string(),
+        date_created: 1234567890,
+        verified_count: 0,
+        retry_count: 0,
+        minted_txn_hash_verified_count: 0,
+        custody_txn_id: "".to_string(),
+    }
+}
+
+fn ed25519_key() -> SigningKey {
+    SigningKey::from_bytes(&[7u8; 32])
+}
+
+fn secp256k1_key() -> SecretKey {
+    SecretKey::from_slice(&[9u8; 32]).unwrap()
+}
+
+fn register_ed25519_key(atlas: &mut Atlas, account_index: usize) {
+    set_caller(account_index);
+    atlas.register_attestation_key(
+        "ed25519".to_string(),
+        hex::encode(ed25519_key().verifying_key().to_bytes()),
+    );
+}
+
+fn register_secp256k1_key(atlas: &mut Atlas, account_index: usize) {
+    let secp = Secp256k1::new();
+    let public_key = secp256k1::PublicKey::from_secret_key(&secp, &secp256k1_key());
+
+    set_caller(account_index);
+    atlas.register_attestation_key(
+        "secp256k1".to_string(),
+        hex::encode(public_key.serialize_uncompressed()),
+    );
+}
+
+fn sign_ed25519(message_hash: &str) -> String {
+    hex::encode(
+        ed25519_key()
+            .sign(&hex::decode(message_hash).unwrap())
+            .to_bytes(),
+    )
+}
+
+fn sign_secp256k1(message_hash: &str) -> String {
+    let secp = Secp256k1::new();
+    let message = Message::from_slice(&hex::decode(message_hash).unwrap()).unwrap();
+    let (recovery_id, signature) = secp
+        .sign_ecdsa_recoverable(&message, &secp256k1_key())
+        .serialize_compact();
+
+    let mut bytes = signature.to_vec();
+    bytes.push(recovery_id.to_i32() as u8 + 27);
+    hex::encode(bytes)
+}
+
+#[test]
+fn test_submit_deposit_attestations() {
+    let mut atlas = setup_atlas();
+    register_ed25519_key(&mut atlas, 4);
+    register_secp256k1_key(&mut atlas, 5);
+
+    let message_hash = atlas.get_deposit_attestation_hash(mempool_deposit());
+
+    // Any account can relay the bundle
+    set_caller(3);
+    let results = atlas.submit_deposit_attestations(
+        mempool_deposit(),
+        vec![
+            ValidatorAttestation {
+                validator_id: accounts(4),
+                signature: sign_ed25519(&message_hash),
+            },
+            ValidatorAttestation {
+                validator_id: accounts(5),
+                signature: sign_secp256k1(&message_hash),
+            },
+        ],
+    );
+
+    assert!(results.iter().all(|result| result.verified));
+
+    let deposit = atlas
+        .get_deposit_by_btc_txn_hash("btc_txn_hash".to_string())
+        .unwrap();
+    assert_eq!(deposit.verified_count, 2);
+    assert_eq!(
+        atlas.get_validators_by_txn_hash("btc_txn_hash".to_string()),
+        vec![accounts(4), accounts(5)]
+    );
+
+    // Replaying the same attestation does not count twice
+    let results = atlas.submit_deposit_attestations(
+        mempool_deposit(),
+        vec![ValidatorAttestation {
+            validator_id: accounts(4),
+            signature: sign_ed25519(&message_hash),
+        }],
+    );
+    assert!(!results[0].verified);
+    assert!(results[0].reason.contains("has already verified"));
+}
+
+#[test]
+fn test_submit_deposit_attestations_invalid_signature() {
+    let mut atlas = setup_atlas();
+    register_ed25519_key(&mut atlas, 4);
+
+    // Signature over a different record
+    let mut other_deposit = mempool_deposit();
+    other_deposit.btc_amount = 20000;
+    let other_hash = atlas.get_deposit_attestation_hash(other_deposit);
+
+    set_caller(3);
+    let results = atlas.submit_deposit_attestations(
+        mempool_deposit(),
+        vec![
+            ValidatorAttestation {
+                validator_id: accounts(4),
+                signature: sign_ed25519(&other_hash),
+            },
+            ValidatorAttestation {
+                validator_id: accounts(5),
+                signature: sign_ed25519(&other_hash),
+            },
+        ],
+    );
+
+    assert!(!results[0].verified);
+    assert!(results[0].reason.contains("Invalid attestation signature"));
+    assert!(!results[1].verified);
+    assert!(results[1].reason.contains("No attestation key registered"));
+
+    let deposit = atlas
+        .get_deposit_by_btc_txn_hash("btc_txn_hash".to_string())
+        .unwrap();
+    assert_eq!(deposit.verified_count, 0);
+}
+
+#[test]
+fn test_submit_deposit_attestations_removed_validator() {
+    let mut atlas = setup_atlas();
+    register_ed25519_key(&mut atlas, 4);
+
+    set_caller(0);
+    atlas.remove_validator(accounts(4), "SIGNET".to_string());
+
+    let message_hash = atlas.get_deposit_attestation_hash(mempool_deposit());
+    let results = atlas.submit_deposit_attestations(
+        mempool_deposit(),
+        vec![ValidatorAttestation {
+            validator_id: accounts(4),
+            signature: sign_ed25519(&message_hash),
+        }],
+    );
+
+    assert!(!results[0].verified);
+    assert!(results[0].reason.contains("is not an authorized validator"));
+}
+
+#[test]
+#[should_panic(expected = "Only a validator can register an attestation key")]
+fn test_register_attestation_key_not_validator() {
+    let mut atlas = setup_atlas();
+    register_ed25519_key(&mut atlas, 3);
+}
+
+#[test]
+#[should_panic(expected = "Invalid ed25519 public key length")]
+fn test_register_attestation_key_invalid_length() {
+    let mut atlas = setup_atlas();
+
+    set_caller(4);
+    atlas.register_attestation_key("ed25519".to_string(), "abcd".to_string());
+}
+
+#[test]
+fn test_attestation_hash_keeps_field_boundaries() {
+    let atlas = setup_atlas();
+
+    // Joined with commas both records would read "...,tb1q,sender,SIGNET,..."
+    let mut deposit = mempool_deposit();
+    deposit.btc_sender_address = "tb1q,sender".to_string();
+    deposit.receiving_chain_id = "SIGNET".to_string();
+    let mut shifted_deposit = mempool_deposit();
+    shifted_deposit.btc_sender_address = "tb1q".to_string();
+    shifted_deposit.receiving_chain_id = "sender,SIGNET".to_string();
+
+    assert_ne!(
+        atlas.get_deposit_attestation_hash(deposit),
+        atlas.get_deposit_attestation_hash(shifted_deposit)
+    );
+}
@@ -0,0 +1,186 @@
+use atlas_protocol::constants::status::*;
+use atlas_protocol::modules::structs::{Atlas, DepositRecord, RedemptionRecord};
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::testing_env;
+
+const CHAIN_ID: &str = "421614";
+
+fn set_caller(account_index: usize) {
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(accounts(account_index));
+    testing_env!(context.build());
+}
+
+fn setup_atlas() -> Atlas {
+    set_caller(0);
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    atlas.add_validator(accounts(4), "SIGNET".to_string());
+    atlas.add_validator(accounts(4), CHAIN_ID.to_string());
+
+    atlas
+}
+
+fn deposit_record(btc_txn_hash: &str, status: u8, minted_txn_hash: &str) -> DepositRecord {
+    DepositRecord {
+        btc_txn_hash: btc_txn_hash.to_string(),
+        btc_sender_address: "tb1qsender".to_string(),
+        receiving_chain_id: CHAIN_ID.to_string(),
+        receiving_address: "0xf8b5b7c7c8f6a1b1f1f1f1f1f1f1f1f1f1f1f1f1".to_string(),
+        btc_amount: 10000,
+        fee_amount: 0,
+        minted_txn_hash: minted_txn_hash.to_string(),
+        timestamp: 1234567890,
+        status,
+        remarks: "".to_string(),
+        date_created: 1234567890,
+        verified_count: 0,
+        retry_count: 0,
+        minted_txn_hash_verified_count: 0,
+        custody_txn_id: "".to_string(),
+    }
+}
+
+fn redemption_record(txn_hash: &str, status: u8, btc_txn_hash: &str) -> RedemptionRecord {
+    RedemptionRecord {
+        txn_hash: txn_hash.to_string(),
+        abtc_redemption_address: "0xf8b5b7c7c8f6a1b1f1f1f1f1f1f1f1f1f1f1f1f1".to_string(),
+        abtc_redemption_chain_id: CHAIN_ID.to_string(),
+        btc_receiving_address: "tb1qreceiver".to_string(),
+        abtc_amount: 5000,
+        btc_txn_hash: btc_txn_hash.to_string(),
+        timestamp: 1234567890,
+        status,
+        remarks: "".to_string(),
+        date_created: 1234567890,
+        verified_count: 0,
+        btc_txn_hash_verified_count: 0,
+        custody_txn_id: "".to_string(),
+    }
+}
+
+#[test]
+fn test_batch_increment_deposit_verified_count() {
+    let mut atlas = setup_atlas();
+
+    for btc_txn_hash in ["btc_txn_hash_1", "btc_txn_hash_2"] {
+        atlas.deposits.insert(
+            btc_txn_hash.to_string(),
+            deposit_record(btc_txn_hash, DEP_BTC_DEPOSITED_INTO_ATLAS, ""),
+        );
+    }
+
+    let mut mismatched = deposit_record("btc_txn_hash_2", DEP_BTC_DEPOSITED_INTO_ATLAS, "");
+    mismatched.btc_amount = 9999;
+
+    set_caller(4);
+    let results = atlas.batch_increment_deposit_verified_count(vec![
+        deposit_record("btc_txn_hash_1", DEP_BTC_DEPOSITED_INTO_ATLAS, ""),
+        mismatched,
+        deposit_record("btc_txn_hash_1", DEP_BTC_DEPOSITED_INTO_ATLAS, ""),
+        deposit_record("unknown_btc_txn_hash", DEP_BTC_DEPOSITED_INTO_ATLAS, ""),
+    ]);
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].key, "btc_txn_hash_1");
+    assert!(results[0].verified);
+    assert!(results[0].reason.is_empty());
+    assert!(!results[1].verified);
+    assert!(results[1].reason.contains("Mismatch"));
+    assert!(!results[2].verified);
+    assert!(results[2].reason.contains("has already verified"));
+    assert!(!results[3].verified);
+    assert!(results[3].reason.contains("Deposit record not found"));
+
+    let deposit_1 = atlas
+        .get_deposit_by_btc_txn_hash("btc_txn_hash_1".to_string())
+        .unwrap();
+    assert_eq!(deposit_1.verified_count, 1);
+    let deposit_2 = atlas
+        .get_deposit_by_btc_txn_hash("btc_txn_hash_2".to_string())
+        .unwrap();
+    assert_eq!(deposit_2.verified_count, 0);
+}
+
+#[test]
+fn test_batch_increment_deposit_minted_txn_hash_verified_count() {
+    let mut atlas = setup_atlas();
+
+    atlas.deposits.insert(
+        "btc_txn_hash_1".to_string(),
+        deposit_record("btc_txn_hash_1", DEP_BTC_MINTED_INTO_ABTC, "0xminted_1"),
+    );
+
+    set_caller(4);
+    let results = atlas.batch_increment_deposit_minted_txn_hash_verified_count(vec![
+        ("btc_txn_hash_1".to_string(), "0xminted_1".to_string()),
+        ("btc_txn_hash_1".to_string(), "0xwrong".to_string()),
+    ]);
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].key, "btc_txn_hash_1,0xminted_1");
+    assert!(results[0].verified);
+    assert!(!results[1].verified);
+
+    let deposit = atlas
+        .get_deposit_by_btc_txn_hash("btc_txn_hash_1".to_string())
+        .unwrap();
+    assert_eq!(deposit.minted_txn_hash_verified_count, 1);
+}
+
+#[test]
+fn test_batch_increment_redemption_verified_count() {
+    let mut atlas = setup_atlas();
+
+    let txn_hash = format!("{},0xburn_1", CHAIN_ID);
+    atlas.redemptions.insert(
+        txn_hash.clone(),
+        redemption_record(&txn_hash, RED_ABTC_BURNT, ""),
+    );
+
+    set_caller(4);
+    let results = atlas.batch_increment_redemption_verified_count(vec![
+        redemption_record(&txn_hash, RED_ABTC_BURNT, ""),
+        redemption_record(&txn_hash, RED_ABTC_BURNT, ""),
+    ]);
+
+    assert_eq!(results.len(), 2);
+    assert!(results[0].verified);
+    assert!(!results[1].verified);
+    assert!(results[1].reason.contains("has already verified"));
+
+    let redemption = atlas.get_redemption_by_txn_hash(txn_hash).unwrap();
+    assert_eq!(redemption.verified_count, 1);
+}
+
+#[test]
+fn test_batch_increment_redemption_btc_txn_hash_verified_count_not_validator() {
+    let mut atlas = setup_atlas();
+
+    let txn_hash = format!("{},0xburn_1", CHAIN_ID);
+    atlas.redemptions.insert(
+        txn_hash.clone(),
+        redemption_record(&txn_hash, RED_BTC_PENDING_MEMPOOL_CONFIRMATION, "btc_txn_hash_1"),
+    );
+
+    set_caller(5);
+    let results = atlas.batch_increment_redemption_btc_txn_hash_verified_count(vec![(
+        txn_hash.clone(),
+        "btc_txn_hash_1".to_string(),
+    )]);
+
+    assert_eq!(results.len(), 1);
+    assert!(!results[0].verified);
+    assert!(results[0].reason.contains("is not an authorized validator"));
+
+    let redemption = atlas.get_redemption_by_txn_hash(txn_hash).unwrap();
+    assert_eq!(redemption.btc_txn_hash_verified_count, 0);
+}
@@ -0,0 +1,428 @@
+use atlas_protocol::constants::status::*;
+use atlas_protocol::modules::structs::{Atlas, BridgingRecord};
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::{testing_env, AccountId, PromiseError, PromiseOrValue};
+
+const TXN_HASH: &str = "NEAR_TESTNET,burn_txn_hash";
+const EVM_TXN_HASH: &str = "421614,0xburn_txn_hash";
+
+fn set_caller(account_id: AccountId) {
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(account_id);
+    testing_env!(context.build());
+}
+
+// accounts(4) and accounts(5) are NEAR_TESTNET and 421614 validators that have verified the 421614
+// signer's nonce, bridging charges 1%
+fn setup_atlas() -> Atlas {
+    set_caller(accounts(0));
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    atlas.add_validator(accounts(4), "NEAR_TESTNET".to_string());
+    atlas.add_validator(accounts(5), "NEAR_TESTNET".to_string());
+    atlas.add_validator(accounts(4), "421614".to_string());
+    atlas.add_validator(accounts(5), "421614".to_string());
+
+    for validator in [accounts(4), accounts(5)] {
+        set_caller(validator);
+        atlas.verify_evm_nonce("421614".to_string(), 0);
+    }
+
+    set_caller(accounts(2));
+    atlas.update_fee_bridging_bps(100);
+
+    atlas
+}
+
+fn insert_bridging(atlas: &mut Atlas, dest_chain_id: &str) -> BridgingRecord {
+    set_caller(accounts(1));
+    atlas.insert_bridging_abtc(
+        TXN_HASH.to_string(),
+        "NEAR_TESTNET".to_string(),
+        "sender.testnet".to_string(),
+        dest_chain_id.to_string(),
+        "0x1234567890123456789012345678901234567890".to_string(),
+        10000,
+        1234567890,
+        1234567890,
+    );
+    atlas.get_bridging_by_txn_hash(TXN_HASH.to_string()).unwrap()
+}
+
+fn verify_bridging(atlas: &mut Atlas, bridging: &BridgingRecord) {
+    for validator in [accounts(4), accounts(5)] {
+        set_caller(validator);
+        assert!(atlas.increment_bridging_verified_count(bridging.clone()));
+    }
+}
+
+fn sign_bridging(atlas: &mut Atlas) -> PromiseOrValue<String> {
+    set_caller(accounts(1));
+    atlas.create_bridging_abtc_signed_tx(TXN_HASH.to_string(), 100000, Some(1), Some(1))
+}
+
+#[test]
+fn test_insert_bridging() {
+    let mut atlas = setup_atlas();
+    let bridging = insert_bridging(&mut atlas, "421614");
+
+    assert_eq!(bridging.status, BRG_ABTC_BURNT);
+    assert_eq!(bridging.fee_amount, 100);
+    assert_eq!(atlas.get_bridgings_count(), 1);
+    assert_eq!(atlas.get_reserves().chains[0].burned, 10000);
+}
+
+#[test]
+fn test_sign_verified_bridging() {
+    let mut atlas = setup_atlas();
+    let bridging = insert_bridging(&mut atlas, "421614");
+
+    match sign_bridging(&mut atlas) {
+        PromiseOrValue::Value(result) => assert_eq!(result, "Validators threshold not met."),
+        PromiseOrValue::Promise(_) => panic!("Unverified bridging was signed"),
+    }
+
+    verify_bridging(&mut atlas, &bridging);
+    assert!(matches!(sign_bridging(&mut atlas), PromiseOrValue::Promise(_)));
+    assert_eq!(
+        atlas.get_bridging_by_txn_hash(TXN_HASH.to_string()).unwrap().status,
+        BRG_ABTC_PENDING_BRIDGE_FROM_ORIGIN_TO_DEST
+    );
+    // Not minted until the destination txn hash is verified
+    assert_eq!(atlas.get_reserves().minted, 0);
+
+    // A bridging is only signed once
+    match sign_bridging(&mut atlas) {
+        PromiseOrValue::Value(result) => {
+            assert_eq!(result, "Bridging not found or invalid conditions.")
+        }
+        PromiseOrValue::Promise(_) => panic!("Bridging was signed twice"),
+    }
+}
+
+#[test]
+fn test_speed_up_bridging_mint() {
+    let mut atlas = setup_atlas();
+    let bridging = insert_bridging(&mut atlas, "421614");
+    verify_bridging(&mut atlas, &bridging);
+    sign_bridging(&mut atlas);
+
+    let attempts = atlas.get_bridging_mint_attempts(TXN_HASH.to_string());
+    assert_eq!(attempts.len(), 1);
+    assert_eq!(attempts[0].attempt_type, "mint");
+
+    atlas.speed_up_bridging_abtc_tx(TXN_HASH.to_string(), 100000, Some(10), Some(10));
+
+    let attempts = atlas.get_bridging_mint_attempts(TXN_HASH.to_string());
+    assert_eq!(attempts.len(), 2);
+    assert_eq!(attempts[1].attempt_type, "speed_up");
+    assert_eq!(attempts[1].nonce, attempts[0].nonce);
+}
+
+// Stands in for the MPC callback, records tx_hash and the signer of the latest attempt
+fn record_signed_attempt(atlas: &mut Atlas, attempt_type: &str, tx_hash: &str) {
+    let mut attempts = atlas.get_bridging_mint_attempts(TXN_HASH.to_string());
+    let attempt = attempts.last_mut().unwrap();
+    assert_eq!(attempt.attempt_type, attempt_type);
+    attempt.tx_hash = tx_hash.to_string();
+    attempt.from_address = "0xf8b5b7c7c8f6a1b1f1f1f1f1f1f1f1f1f1f1f1f1".to_string();
+    atlas.mint_attempts.insert(TXN_HASH.to_string(), attempts);
+}
+
+fn signed_bridging(atlas: &mut Atlas) {
+    let bridging = insert_bridging(atlas, "421614");
+    verify_bridging(atlas, &bridging);
+    sign_bridging(atlas);
+    record_signed_attempt(atlas, "mint", "0xdest_txn_hash");
+}
+
+#[test]
+fn test_evm_bridging_minted_after_verification() {
+    let mut atlas = setup_atlas();
+    signed_bridging(&mut atlas);
+
+    set_caller(accounts(1));
+    atlas.update_bridging_dest_txn_hash(TXN_HASH.to_string(), "0xdest_txn_hash".to_string());
+
+    // Not verified yet
+    atlas.update_bridging_minted(TXN_HASH.to_string(), "0xdest_txn_hash".to_string());
+    assert_eq!(
+        atlas.get_bridging_by_txn_hash(TXN_HASH.to_string()).unwrap().status,
+        BRG_ABTC_PENDING_BRIDGE_FROM_ORIGIN_TO_DEST
+    );
+
+    for validator in [accounts(4), accounts(5)] {
+        set_caller(validator);
+        assert!(atlas.increment_bridging_dest_txn_hash_verified_count(
+            TXN_HASH.to_string(),
+            "0xdest_txn_hash".to_string()
+        ));
+    }
+
+    set_caller(accounts(1));
+    atlas.update_bridging_minted(TXN_HASH.to_string(), "0xdest_txn_hash".to_string());
+    let bridging = atlas.get_bridging_by_txn_hash(TXN_HASH.to_string()).unwrap();
+    assert_eq!(bridging.status, BRG_ABTC_MINTED_TO_DEST);
+    assert_eq!(bridging.dest_txn_hash_verified_count, 2);
+    assert_eq!(atlas.get_reserves().minted, 9900);
+}
+
+#[test]
+fn test_unknown_dest_txn_hash_is_rejected() {
+    let mut atlas = setup_atlas();
+    signed_bridging(&mut atlas);
+
+    set_caller(accounts(1));
+    atlas.update_bridging_dest_txn_hash(TXN_HASH.to_string(), "0xother_txn_hash".to_string());
+    assert!(atlas
+        .get_bridging_by_txn_hash(TXN_HASH.to_string())
+        .unwrap()
+        .dest_txn_hash
+        .is_empty());
+}
+
+#[test]
+fn test_rollback_cancelled_bridging() {
+    let mut atlas = setup_atlas();
+    signed_bridging(&mut atlas);
+
+    set_caller(accounts(1));
+    atlas.update_bridging_remarks(TXN_HASH.to_string(), "Mint stuck in the mempool".to_string());
+    atlas.cancel_bridging_abtc_tx(TXN_HASH.to_string(), 21000, Some(10), Some(10));
+    record_signed_attempt(&mut atlas, "cancel", "0xcancel_txn_hash");
+
+    atlas.rollback_bridging_status_by_txn_hash(TXN_HASH.to_string());
+    let bridging = atlas.get_bridging_by_txn_hash(TXN_HASH.to_string()).unwrap();
+    assert_eq!(bridging.status, BRG_ABTC_BURNT);
+    assert_eq!(bridging.retry_count, 1);
+    assert!(bridging.remarks.is_empty());
+
+    // Signed again at a new nonce
+    assert!(matches!(sign_bridging(&mut atlas), PromiseOrValue::Promise(_)));
+}
+
+#[test]
+#[should_panic(expected = "Signed mint has to be cancelled before the bridging is rolled back")]
+fn test_rollback_requires_cancelled_mint() {
+    let mut atlas = setup_atlas();
+    signed_bridging(&mut atlas);
+
+    set_caller(accounts(1));
+    atlas.update_bridging_remarks(TXN_HASH.to_string(), "Mint stuck in the mempool".to_string());
+    atlas.rollback_bridging_status_by_txn_hash(TXN_HASH.to_string());
+}
+
+#[test]
+fn test_bridge_mint_counts_toward_mint_limits() {
+    let mut atlas = setup_atlas();
+    let bridging = insert_bridging(&mut atlas, "421614");
+    verify_bridging(&mut atlas, &bridging);
+
+    set_caller(accounts(3));
+    atlas.set_chain_mint_limits("421614".to_string(), 5000, 0);
+
+    match sign_bridging(&mut atlas) {
+        PromiseOrValue::Value(result) => {
+            assert_eq!(result, "Mint limit exceeded, minting is paused.")
+        }
+        PromiseOrValue::Promise(_) => panic!("Bridge mint over the hourly limit was signed"),
+    }
+    assert!(atlas.is_scope_paused("minting".to_string(), "421614".to_string()));
+    assert_eq!(
+        atlas.get_bridging_by_txn_hash(TXN_HASH.to_string()).unwrap().status,
+        BRG_ABTC_BURNT
+    );
+}
+
+#[test]
+fn test_bridging_vote_by_non_validator() {
+    let mut atlas = setup_atlas();
+    let bridging = insert_bridging(&mut atlas, "421614");
+
+    set_caller(accounts(3));
+    assert!(!atlas.increment_bridging_verified_count(bridging));
+}
+
+#[test]
+#[should_panic(expected = "The bridging flow is paused for chain ID: 421614")]
+fn test_sign_bridging_while_paused() {
+    let mut atlas = setup_atlas();
+    let bridging = insert_bridging(&mut atlas, "421614");
+    verify_bridging(&mut atlas, &bridging);
+
+    set_caller(accounts(0));
+    atlas.pause_scope("bridging".to_string(), Some("421614".to_string()));
+    sign_bridging(&mut atlas);
+}
+
+#[test]
+#[should_panic(expected = "Transaction hash must start with the origin chain ID")]
+fn test_insert_bridging_with_foreign_txn_hash() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(1));
+    atlas.insert_bridging_abtc(
+        "421614,burn_txn_hash".to_string(),
+        "NEAR_TESTNET".to_string(),
+        "sender.testnet".to_string(),
+        "421614".to_string(),
+        "0x1234567890123456789012345678901234567890".to_string(),
+        10000,
+        1234567890,
+        1234567890,
+    );
+}
+
+// A verified bridging from 421614 to NEAR_TESTNET
+fn insert_near_bridging(atlas: &mut Atlas) {
+    set_caller(accounts(1));
+    atlas.insert_bridging_abtc(
+        EVM_TXN_HASH.to_string(),
+        "421614".to_string(),
+        "0x1234567890123456789012345678901234567890".to_string(),
+        "NEAR_TESTNET".to_string(),
+        "receiver.testnet".to_string(),
+        10000,
+        1234567890,
+        1234567890,
+    );
+    let bridging = atlas.get_bridging_by_txn_hash(EVM_TXN_HASH.to_string()).unwrap();
+    verify_bridging(atlas, &bridging);
+}
+
+fn near_mint(atlas: &mut Atlas) -> PromiseOrValue<String> {
+    set_caller(accounts(1));
+    atlas.create_bridging_abtc_near_mint(EVM_TXN_HASH.to_string())
+}
+
+// Callbacks run as the contract itself
+fn settle_near_mint(atlas: &mut Atlas, result: Result<(), PromiseError>) {
+    let mut context = VMContextBuilder::new();
+    context
+        .current_account_id(accounts(0))
+        .predecessor_account_id(accounts(0));
+    testing_env!(context.build());
+    atlas.bridging_mint_callback(EVM_TXN_HASH.to_string(), result);
+}
+
+#[test]
+fn test_near_mint_completes() {
+    let mut atlas = setup_atlas();
+    insert_near_bridging(&mut atlas);
+
+    assert!(matches!(near_mint(&mut atlas), PromiseOrValue::Promise(_)));
+    assert_eq!(
+        atlas.get_bridging_by_txn_hash(EVM_TXN_HASH.to_string()).unwrap().status,
+        BRG_ABTC_PENDING_BRIDGE_FROM_ORIGIN_TO_DEST
+    );
+    assert_eq!(atlas.get_reserves().minted, 0);
+
+    settle_near_mint(&mut atlas, Ok(()));
+    assert_eq!(
+        atlas.get_bridging_by_txn_hash(EVM_TXN_HASH.to_string()).unwrap().status,
+        BRG_ABTC_MINTED_TO_DEST
+    );
+    assert_eq!(atlas.get_reserves().minted, 9900);
+}
+
+#[test]
+fn test_failed_near_mint_is_retried() {
+    let mut atlas = setup_atlas();
+    insert_near_bridging(&mut atlas);
+
+    for attempt in 1..=3 {
+        assert!(matches!(near_mint(&mut atlas), PromiseOrValue::Promise(_)));
+        settle_near_mint(&mut atlas, Err(PromiseError::Failed));
+
+        let bridging = atlas.get_bridging_by_txn_hash(EVM_TXN_HASH.to_string()).unwrap();
+        assert_eq!(bridging.status, BRG_ABTC_BURNT);
+        assert_eq!(bridging.retry_count, attempt);
+    }
+
+    // The default max_retry_count of 3 is used up
+    assert_eq!(
+        atlas.get_bridging_by_txn_hash(EVM_TXN_HASH.to_string()).unwrap().remarks,
+        "NEAR mint_bridge failed 3 times"
+    );
+    match near_mint(&mut atlas) {
+        PromiseOrValue::Value(result) => {
+            assert_eq!(result, "Bridging not found or invalid conditions.")
+        }
+        PromiseOrValue::Promise(_) => panic!("Bridging was minted after its retries were used up"),
+    }
+}
+
+#[test]
+fn test_near_mint_counts_toward_mint_limits() {
+    let mut atlas = setup_atlas();
+    insert_near_bridging(&mut atlas);
+
+    set_caller(accounts(3));
+    atlas.set_chain_mint_limits("NEAR_TESTNET".to_string(), 0, 5000);
+
+    match near_mint(&mut atlas) {
+        PromiseOrValue::Value(result) => {
+            assert_eq!(result, "Mint limit exceeded, minting is paused.")
+        }
+        PromiseOrValue::Promise(_) => panic!("NEAR mint over the daily limit was called"),
+    }
+    assert!(atlas.is_scope_paused("minting".to_string(), "NEAR_TESTNET".to_string()));
+}
+
+#[test]
+#[should_panic(expected = "Destination chain is not a NEAR chain")]
+fn test_near_mint_to_evm_chain() {
+    let mut atlas = setup_atlas();
+    let bridging = insert_bridging(&mut atlas, "421614");
+    verify_bridging(&mut atlas, &bridging);
+
+    set_caller(accounts(1));
+    atlas.create_bridging_abtc_near_mint(TXN_HASH.to_string());
+}
+
+#[test]
+fn test_bridging_mint_blocked_during_dispute_window() {
+    let mut atlas = setup_atlas();
+    set_caller(accounts(0));
+    atlas.set_chain_dispute_window("NEAR_TESTNET".to_string(), 3600);
+
+    let bridging = insert_bridging(&mut atlas, "421614");
+    verify_bridging(&mut atlas, &bridging);
+    assert!(atlas.get_dispute_window(TXN_HASH.to_string()).is_some());
+
+    match sign_bridging(&mut atlas) {
+        PromiseOrValue::Value(reason) => {
+            assert_eq!(reason, "Bridging is disputed or within its dispute window.")
+        }
+        PromiseOrValue::Promise(_) => panic!("Expected the mint to be blocked"),
+    }
+    assert_eq!(atlas.get_bridging_by_txn_hash(TXN_HASH.to_string()).unwrap().status, BRG_ABTC_BURNT);
+}
+
+#[test]
+fn test_upheld_bridging_dispute_blocks_mint() {
+    let mut atlas = setup_atlas();
+    set_caller(accounts(0));
+    atlas.set_chain_dispute_window("NEAR_TESTNET".to_string(), 3600);
+
+    let bridging = insert_bridging(&mut atlas, "421614");
+    verify_bridging(&mut atlas, &bridging);
+
+    set_caller(accounts(4));
+    atlas.flag_disputed_record("bridging".to_string(), TXN_HASH.to_string(), "burn was reverted".to_string());
+    assert_eq!(atlas.get_dispute(TXN_HASH.to_string()).unwrap().record_type, "bridging");
+
+    set_caller(accounts(0));
+    atlas.resolve_dispute(TXN_HASH.to_string(), true);
+
+    assert!(!atlas.get_bridging_by_txn_hash(TXN_HASH.to_string()).unwrap().remarks.is_empty());
+    assert!(matches!(sign_bridging(&mut atlas), PromiseOrValue::Value(_)));
+}
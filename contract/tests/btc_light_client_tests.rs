@@ -0,0 +1,404 @@
+use atlas_protocol::modules::structs::{Atlas, BtcBlockHeaderRecord};
+use bitcoin::blockdata::opcodes::all::OP_RETURN;
+use bitcoin::blockdata::script::{Builder, Script};
+use bitcoin::blockdata::transaction::{OutPoint, Transaction, TxIn, TxOut};
+use bitcoin::consensus::encode::serialize;
+use bitcoin::hashes::{sha256d, Hash};
+use bitcoin::network::constants::Network;
+use bitcoin::util::address::Address;
+use bitcoin::util::key::PublicKey;
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::testing_env;
+use std::str::FromStr;
+
+const SENDER_PUBKEY: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+const DEPOSIT_PUBKEY: &str = "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5";
+const SIGNET_BITS: u32 = 0x1e0377ae;
+
+fn set_caller(account_index: usize) {
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(accounts(account_index));
+    testing_env!(context.build());
+}
+
+fn setup_atlas() -> Atlas {
+    set_caller(0);
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    set_caller(2);
+    atlas.update_btc_atlas_deposit_address(
+        Address::p2wpkh(&PublicKey::from_str(DEPOSIT_PUBKEY).unwrap(), Network::Signet)
+            .unwrap()
+            .to_string(),
+    );
+
+    set_caller(0);
+    atlas
+}
+
+fn build_deposit_tx() -> Transaction {
+    Transaction {
+        version: 2,
+        lock_time: 0,
+        input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: Script::new(),
+            sequence: 0xFFFFFFFF,
+            witness: vec![vec![0x30; 71], hex::decode(SENDER_PUBKEY).unwrap()],
+        }],
+        output: vec![
+            TxOut {
+                value: 25000,
+                script_pubkey: Address::p2wpkh(
+                    &PublicKey::from_str(DEPOSIT_PUBKEY).unwrap(),
+                    Network::Signet,
+                )
+                .unwrap()
+                .script_pubkey(),
+            },
+            TxOut {
+                value: 0,
+                script_pubkey: Builder::new()
+                    .push_opcode(OP_RETURN)
+                    .push_slice("NEAR_TESTNET,user.testnet".as_bytes())
+                    .into_script(),
+            },
+        ],
+    }
+}
+
+fn build_header_hex(prev_block_hash: [u8; 32], merkle_root: [u8; 32], bits: u32) -> String {
+    build_header_hex_at(prev_block_hash, merkle_root, bits, 1700000000)
+}
+
+fn build_header_hex_at(prev_block_hash: [u8; 32], merkle_root: [u8; 32], bits: u32, time: u32) -> String {
+    let mut header = Vec::with_capacity(80);
+    header.extend_from_slice(&2u32.to_le_bytes()); // version
+    header.extend_from_slice(&prev_block_hash);
+    header.extend_from_slice(&merkle_root);
+    header.extend_from_slice(&time.to_le_bytes());
+    header.extend_from_slice(&bits.to_le_bytes());
+    header.extend_from_slice(&0u32.to_le_bytes()); // nonce
+    hex::encode(header)
+}
+
+fn block_hash_of(header_hex: &str) -> [u8; 32] {
+    sha256d::Hash::hash(&hex::decode(header_hex).unwrap()).into_inner()
+}
+
+fn display_hex(hash: &[u8; 32]) -> String {
+    let mut bytes = hash.to_vec();
+    bytes.reverse();
+    hex::encode(bytes)
+}
+
+// Inserts the deposit from its raw transaction and anchors it in a checkpoint block next to one sibling
+fn setup_deposit_in_checkpoint(atlas: &mut Atlas) -> (String, String, String) {
+    let tx = build_deposit_tx();
+    let raw_tx_hex = hex::encode(serialize(&tx));
+    let txid = tx.txid().into_inner();
+    let sibling = [7u8; 32];
+
+    let mut concat = txid.to_vec();
+    concat.extend_from_slice(&sibling);
+    let merkle_root = sha256d::Hash::hash(&concat).into_inner();
+
+    let checkpoint_hex = build_header_hex([0u8; 32], merkle_root, SIGNET_BITS);
+    atlas.init_btc_light_client(checkpoint_hex.clone(), 201600);
+
+    set_caller(1);
+    atlas.insert_deposit_btc_from_raw_tx(raw_tx_hex.clone(), 1234567890, 1234567890);
+    atlas.update_deposit_btc_deposited(tx.txid().to_string(), 1234567890);
+
+    (
+        raw_tx_hex,
+        display_hex(&block_hash_of(&checkpoint_hex)),
+        display_hex(&sibling),
+    )
+}
+
+#[test]
+fn test_init_btc_light_client() {
+    let mut atlas = setup_atlas();
+
+    let checkpoint_hex = build_header_hex([0u8; 32], [1u8; 32], SIGNET_BITS);
+    atlas.init_btc_light_client(checkpoint_hex.clone(), 201600);
+
+    let tip = atlas.get_btc_light_client_tip().unwrap();
+    assert_eq!(tip.block_hash, display_hex(&block_hash_of(&checkpoint_hex)));
+    assert_eq!(tip.height, 201600);
+    assert_eq!(tip.bits, SIGNET_BITS);
+    assert_eq!(atlas.get_btc_light_client_height(), 201600);
+    assert_eq!(
+        atlas.get_btc_block_hash_by_height(201600),
+        Some(tip.block_hash)
+    );
+}
+
+#[test]
+#[should_panic(expected = "BTC light client is already initialized")]
+fn test_init_btc_light_client_twice() {
+    let mut atlas = setup_atlas();
+
+    let checkpoint_hex = build_header_hex([0u8; 32], [1u8; 32], SIGNET_BITS);
+    atlas.init_btc_light_client(checkpoint_hex.clone(), 201600);
+    atlas.init_btc_light_client(checkpoint_hex, 201600);
+}
+
+#[test]
+#[should_panic(expected = "Previous block header not found")]
+fn test_submit_btc_block_header_unknown_parent() {
+    let mut atlas = setup_atlas();
+
+    let checkpoint_hex = build_header_hex([0u8; 32], [1u8; 32], SIGNET_BITS);
+    atlas.init_btc_light_client(checkpoint_hex, 201600);
+
+    set_caller(1);
+    atlas.submit_btc_block_headers(vec![build_header_hex([9u8; 32], [2u8; 32], SIGNET_BITS)]);
+}
+
+#[test]
+#[should_panic(expected = "Invalid difficulty bits")]
+fn test_submit_btc_block_header_invalid_bits() {
+    let mut atlas = setup_atlas();
+
+    let checkpoint_hex = build_header_hex([0u8; 32], [1u8; 32], SIGNET_BITS);
+    atlas.init_btc_light_client(checkpoint_hex.clone(), 201600);
+
+    set_caller(1);
+    atlas.submit_btc_block_headers(vec![build_header_hex(
+        block_hash_of(&checkpoint_hex),
+        [2u8; 32],
+        0x1d00ffff,
+    )]);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient proof of work")]
+fn test_submit_btc_block_header_insufficient_pow() {
+    let mut atlas = setup_atlas();
+
+    let checkpoint_hex = build_header_hex([0u8; 32], [1u8; 32], SIGNET_BITS);
+    atlas.init_btc_light_client(checkpoint_hex.clone(), 201600);
+
+    set_caller(1);
+    atlas.submit_btc_block_headers(vec![build_header_hex_at(
+        block_hash_of(&checkpoint_hex),
+        [2u8; 32],
+        SIGNET_BITS,
+        1700000600,
+    )]);
+}
+
+#[test]
+#[should_panic(expected = "Block time must be later than the median time of the previous blocks")]
+fn test_submit_btc_block_header_time_not_after_median() {
+    let mut atlas = setup_atlas();
+
+    let checkpoint_hex = build_header_hex([0u8; 32], [1u8; 32], SIGNET_BITS);
+    atlas.init_btc_light_client(checkpoint_hex.clone(), 201600);
+
+    set_caller(1);
+    atlas.submit_btc_block_headers(vec![build_header_hex_at(
+        block_hash_of(&checkpoint_hex),
+        [2u8; 32],
+        SIGNET_BITS,
+        1700000000,
+    )]);
+}
+
+#[test]
+#[should_panic(expected = "BTC light client checkpoint must be at a retarget height")]
+fn test_init_btc_light_client_off_retarget_height() {
+    let mut atlas = setup_atlas();
+
+    let checkpoint_hex = build_header_hex([0u8; 32], [1u8; 32], SIGNET_BITS);
+    atlas.init_btc_light_client(checkpoint_hex, 201601);
+}
+
+#[test]
+#[should_panic(expected = "Only a deposit operator or a BTC validator can submit block headers")]
+fn test_submit_btc_block_headers_unauthorized() {
+    let mut atlas = setup_atlas();
+
+    let checkpoint_hex = build_header_hex([0u8; 32], [1u8; 32], SIGNET_BITS);
+    atlas.init_btc_light_client(checkpoint_hex.clone(), 201600);
+
+    set_caller(4);
+    atlas.submit_btc_block_headers(vec![build_header_hex(
+        block_hash_of(&checkpoint_hex),
+        [2u8; 32],
+        SIGNET_BITS,
+    )]);
+}
+
+#[test]
+fn test_verify_deposit_btc_inclusion_proof() {
+    let mut atlas = setup_atlas();
+    atlas.set_btc_confirmation_depth(1);
+
+    let (raw_tx_hex, block_hash, sibling) = setup_deposit_in_checkpoint(&mut atlas);
+    let btc_txn_hash = build_deposit_tx().txid().to_string();
+
+    set_caller(4);
+    assert!(atlas.verify_deposit_btc_inclusion_proof(
+        raw_tx_hex.clone(),
+        block_hash.clone(),
+        vec![sibling.clone()],
+        0
+    ));
+
+    let proof = atlas
+        .get_btc_deposit_inclusion_proof(btc_txn_hash.clone())
+        .unwrap();
+    assert_eq!(proof.block_hash, block_hash);
+    assert_eq!(proof.block_height, 201600);
+
+    // The same proof cannot be recorded twice
+    assert!(!atlas.verify_deposit_btc_inclusion_proof(raw_tx_hex, block_hash, vec![sibling], 0));
+
+    // A proof stands in for the validator threshold when picking deposits to mint
+    let (key, _) = atlas.get_first_valid_deposit_chain_config().unwrap();
+    assert_eq!(key, btc_txn_hash);
+}
+
+#[test]
+fn test_verify_deposit_btc_inclusion_proof_insufficient_confirmations() {
+    let mut atlas = setup_atlas();
+
+    let (raw_tx_hex, block_hash, sibling) = setup_deposit_in_checkpoint(&mut atlas);
+
+    set_caller(4);
+    assert!(!atlas.verify_deposit_btc_inclusion_proof(
+        raw_tx_hex,
+        block_hash,
+        vec![sibling],
+        0
+    ));
+}
+
+#[test]
+fn test_verify_deposit_btc_inclusion_proof_invalid_merkle_proof() {
+    let mut atlas = setup_atlas();
+    atlas.set_btc_confirmation_depth(1);
+
+    let (raw_tx_hex, block_hash, sibling) = setup_deposit_in_checkpoint(&mut atlas);
+
+    set_caller(4);
+    // Wrong position of the transaction in the block
+    assert!(!atlas.verify_deposit_btc_inclusion_proof(
+        raw_tx_hex,
+        block_hash,
+        vec![sibling],
+        1
+    ));
+}
+
+const TARGET_TIMESPAN: u32 = 14 * 24 * 60 * 60;
+
+// Stores a fork from height 201600 up to 203615 next to the main chain checkpoint at 201600, without proof of work
+// The main chain period took twice the target timespan and the fork period exactly the target timespan
+// Returns the block hash of the fork tip as in its headers
+fn setup_fork_across_retarget(atlas: &mut Atlas) -> [u8; 32] {
+    let start_time = 1700000000;
+    let checkpoint_hex = build_header_hex_at([0u8; 32], [1u8; 32], 0x1d00ffff, start_time - TARGET_TIMESPAN);
+    atlas.init_btc_light_client(checkpoint_hex, 201600);
+
+    let mut prev_block_hash = display_hex(&[0xaau8; 32]);
+    let mut tip_hash = [0u8; 32];
+    for height in 201600..=203615u64 {
+        let mut block_hash = [0u8; 32];
+        block_hash[..8].copy_from_slice(&height.to_le_bytes());
+        block_hash[31] = 0xf0;
+        let time = if height == 203615 {
+            start_time + TARGET_TIMESPAN
+        } else {
+            start_time + (height - 201600) as u32 * 600
+        };
+
+        atlas.btc_headers.insert(
+            display_hex(&block_hash),
+            BtcBlockHeaderRecord {
+                block_hash: display_hex(&block_hash),
+                prev_block_hash: prev_block_hash.clone(),
+                merkle_root: display_hex(&[1u8; 32]),
+                height,
+                time,
+                bits: 0x1d00ffff,
+                chain_work: "1".to_string(),
+            },
+        );
+        prev_block_hash = display_hex(&block_hash);
+        tip_hash = block_hash;
+    }
+    tip_hash
+}
+
+// The retarget of the fork is computed from the fork's own period start, so its unchanged difficulty is accepted and
+// the header only fails on its missing proof of work
+#[test]
+#[should_panic(expected = "Insufficient proof of work")]
+fn test_submit_btc_block_header_fork_across_retarget() {
+    let mut atlas = setup_atlas();
+    let fork_tip = setup_fork_across_retarget(&mut atlas);
+
+    set_caller(1);
+    atlas.submit_btc_block_headers(vec![build_header_hex_at(
+        fork_tip,
+        [2u8; 32],
+        0x1d00ffff,
+        1700000000 + TARGET_TIMESPAN + 600,
+    )]);
+}
+
+// The main chain's period start would halve the fork's difficulty
+#[test]
+#[should_panic(expected = "Invalid difficulty bits")]
+fn test_submit_btc_block_header_fork_across_retarget_with_main_chain_bits() {
+    let mut atlas = setup_atlas();
+    let fork_tip = setup_fork_across_retarget(&mut atlas);
+
+    set_caller(1);
+    atlas.submit_btc_block_headers(vec![build_header_hex_at(
+        fork_tip,
+        [2u8; 32],
+        0x1d01fffe,
+        1700000000 + TARGET_TIMESPAN + 600,
+    )]);
+}
+
+// A 64-byte transaction could pass for an inner merkle node of the block
+#[test]
+#[should_panic(expected = "BTC transactions of 64 bytes are not accepted")]
+fn test_verify_deposit_btc_inclusion_proof_64_byte_transaction() {
+    let mut atlas = setup_atlas();
+    atlas.set_btc_confirmation_depth(1);
+    let (_, block_hash, sibling) = setup_deposit_in_checkpoint(&mut atlas);
+
+    let tx = Transaction {
+        version: 2,
+        lock_time: 0,
+        input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: Script::new(),
+            sequence: 0xFFFFFFFF,
+            witness: vec![],
+        }],
+        output: vec![TxOut {
+            value: 0,
+            script_pubkey: Script::from(vec![0x51; 4]),
+        }],
+    };
+    assert_eq!(serialize(&tx).len(), 64);
+
+    set_caller(4);
+    atlas.verify_deposit_btc_inclusion_proof(hex::encode(serialize(&tx)), block_hash, vec![sibling], 0);
+}
@@ -27,6 +27,15 @@ fn setup_atlas() -> Atlas {
     atlas.add_validator(accounts(4), "11155420".to_string());
     atlas.add_validator(accounts(5), "11155420".to_string());
 
+    // Nothing is signed for an EVM chain until its validators have verified the signer's nonce
+    for validator in [accounts(4), accounts(5)] {
+        let mut context = VMContextBuilder::new();
+        context.predecessor_account_id(validator);
+        testing_env!(context.build());
+        atlas.verify_evm_nonce("421614".to_string(), 0);
+        atlas.verify_evm_nonce("11155420".to_string(), 0);
+    }
+
     atlas
 }
 
@@ -44,10 +53,9 @@ fn test_create_mint_abtc_signed_tx_paused() {
     atlas.pause();
     atlas.create_mint_abtc_signed_tx(
         "781eaa989e5e35db6da84cb190e3df49c21cee8931e5301e91e8d9820e8f2c13".to_string(),
-        94,
         5000000,
-        100000000,
-        0,
+        Some(100000000),
+        Some(0),
     );
 }
 
@@ -64,10 +72,9 @@ fn test_create_mint_abtc_signed_tx_not_admin() {
 
     atlas.create_mint_abtc_signed_tx(
         "781eaa989e5e35db6da84cb190e3df49c21cee8931e5301e91e8d9820e8f2c13".to_string(),
-        94,
         5000000,
-        100000000,
-        0,
+        Some(100000000),
+        Some(0),
     );
 }
 
@@ -82,7 +89,7 @@ fn test_create_mint_abtc_signed_tx_empty_btc_txn_hash() {
     context.block_timestamp(1234567890);
     testing_env!(context.build());
 
-    atlas.create_mint_abtc_signed_tx("".to_string(), 94, 5000000, 100000000, 0);
+    atlas.create_mint_abtc_signed_tx("".to_string(), 5000000, Some(100000000), Some(0));
 }
 
 #[test]
@@ -98,10 +105,9 @@ fn test_create_mint_abtc_signed_tx_zero_gas() {
 
     atlas.create_mint_abtc_signed_tx(
         "781eaa989e5e35db6da84cb190e3df49c21cee8931e5301e91e8d9820e8f2c13".to_string(),
-        94,
-        0,
-        100000000,
         0,
+        Some(100000000),
+        Some(0),
     );
 }
 
@@ -117,10 +123,9 @@ fn test_create_mint_abtc_signed_tx_not_found_deposit() {
 
     let result: String = get_value(atlas.create_mint_abtc_signed_tx(
         "this-is-not-found".to_string(),
-        94,
         5000000,
-        100000000,
-        0,
+        Some(100000000),
+        Some(0),
     ));
     assert_eq!(
         result,
@@ -153,10 +158,9 @@ fn test_create_mint_abtc_signed_tx_existing_deposit_status_not_waiting_mint() {
     );
     let result: String = get_value(atlas.create_mint_abtc_signed_tx(
         "123456".to_string(),
-        94,
         5000000,
-        100000000,
-        0,
+        Some(100000000),
+        Some(0),
     ));
     assert_eq!(
         result,
@@ -206,10 +210,9 @@ fn test_create_mint_abtc_signed_tx_no_chain_config() {
     // Attempt to create mint transaction, which should panic due to missing chain config
     let result = get_value(atlas.create_mint_abtc_signed_tx(
         "nonexistent_chain".to_string(),
-        94,
         5000000,
-        100000000,
-        0,
+        Some(100000000),
+        Some(0),
     ));
 
     assert_eq!(result, "Chain config not found.".to_string());
@@ -244,10 +247,9 @@ fn test_create_mint_abtc_signed_tx_not_enough_verified_count() {
     // Attempt to create mint transaction, which should panic due to missing chain config
     let result = get_value(atlas.create_mint_abtc_signed_tx(
         "123456".to_string(),
-        94,
         5000000,
-        100000000,
-        0,
+        Some(100000000),
+        Some(0),
     ));
 
     assert_eq!(result, "Validators threshold not met.".to_string());
@@ -0,0 +1,226 @@
+use atlas_protocol::constants::status::*;
+use atlas_protocol::modules::structs::{Atlas, DepositRecord, RedemptionRecord};
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::{testing_env, PromiseOrValue};
+
+const NANOS: u64 = 1_000_000_000;
+const START: u64 = 1_700_000_000;
+const WINDOW: u64 = 3600;
+const BTC_TXN_HASH: &str = "btc_txn_hash";
+const TXN_HASH: &str = "421614,0xredemption_txn_hash";
+
+fn set_caller_at(account_index: usize, timestamp: u64) {
+    let mut context = VMContextBuilder::new();
+    context
+        .predecessor_account_id(accounts(account_index))
+        .block_timestamp(timestamp * NANOS);
+    testing_env!(context.build());
+}
+
+fn deposit_record() -> DepositRecord {
+    DepositRecord {
+        btc_txn_hash: BTC_TXN_HASH.to_string(),
+        btc_sender_address: "tb1qsender".to_string(),
+        receiving_chain_id: "421614".to_string(),
+        receiving_address: "0xf8b5b7c7c8f6a1b1f1f1f1f1f1f1f1f1f1f1f1f1".to_string(),
+        btc_amount: 10000,
+        fee_amount: 0,
+        minted_txn_hash: "".to_string(),
+        timestamp: 1234567890,
+        status: DEP_BTC_DEPOSITED_INTO_ATLAS,
+        remarks: "".to_string(),
+        date_created: 1234567890,
+        verified_count: 0,
+        retry_count: 0,
+        minted_txn_hash_verified_count: 0,
+        custody_txn_id: "".to_string(),
+    }
+}
+
+fn redemption_record() -> RedemptionRecord {
+    RedemptionRecord {
+        txn_hash: TXN_HASH.to_string(),
+        abtc_redemption_address: "0xf8b5b7c7c8f6a1b1f1f1f1f1f1f1f1f1f1f1f1f1".to_string(),
+        abtc_redemption_chain_id: "421614".to_string(),
+        btc_receiving_address: "tb1qreceiver".to_string(),
+        abtc_amount: 10000,
+        btc_txn_hash: "".to_string(),
+        timestamp: 1234567890,
+        status: RED_ABTC_BURNT,
+        remarks: "".to_string(),
+        date_created: 1234567890,
+        verified_count: 0,
+        btc_txn_hash_verified_count: 0,
+        custody_txn_id: "".to_string(),
+    }
+}
+
+// accounts(4) and accounts(5) verify the deposit on SIGNET and the redemption on 421614, reaching the threshold of 2 at START + 20
+fn setup_atlas() -> Atlas {
+    set_caller_at(0, START);
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    for validator in [4, 5] {
+        atlas.add_validator(accounts(validator), "SIGNET".to_string());
+        atlas.add_validator(accounts(validator), "421614".to_string());
+    }
+    atlas.set_chain_dispute_window("421614".to_string(), WINDOW);
+
+    atlas
+        .deposits
+        .insert(BTC_TXN_HASH.to_string(), deposit_record());
+    atlas
+        .redemptions
+        .insert(TXN_HASH.to_string(), redemption_record());
+
+    set_caller_at(4, START + 10);
+    assert!(atlas.increment_deposit_verified_count(deposit_record()));
+    assert!(atlas.increment_redemption_verified_count(redemption_record()));
+    assert!(atlas.get_dispute_window(BTC_TXN_HASH.to_string()).is_none());
+
+    set_caller_at(5, START + 20);
+    assert!(atlas.increment_deposit_verified_count(deposit_record()));
+    assert!(atlas.increment_redemption_verified_count(redemption_record()));
+
+    atlas
+}
+
+#[test]
+fn test_window_starts_when_threshold_reached() {
+    let atlas = setup_atlas();
+
+    let window = atlas.get_dispute_window(BTC_TXN_HASH.to_string()).unwrap();
+    assert_eq!(window.chain_id, "421614");
+    assert_eq!(window.started_at, START + 20);
+    assert!(atlas.get_dispute_window(TXN_HASH.to_string()).is_some());
+}
+
+#[test]
+fn test_records_blocked_during_window() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(1, START + 20 + WINDOW - 1);
+    assert!(atlas.get_first_valid_deposit_chain_config().is_none());
+    assert!(atlas.get_first_valid_redemption().is_none());
+
+    atlas.update_redemption_start(TXN_HASH.to_string());
+    assert_eq!(
+        atlas.get_redemption_by_txn_hash(TXN_HASH.to_string()).unwrap().status,
+        RED_ABTC_BURNT
+    );
+
+    set_caller_at(1, START + 20 + WINDOW);
+    assert!(atlas.get_first_valid_deposit_chain_config().is_some());
+
+    atlas.update_redemption_start(TXN_HASH.to_string());
+    assert_eq!(
+        atlas.get_redemption_by_txn_hash(TXN_HASH.to_string()).unwrap().status,
+        RED_BTC_PENDING_REDEMPTION_FROM_ATLAS_TO_USER
+    );
+}
+
+#[test]
+fn test_mint_blocked_during_window() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(1, START + 30);
+    match atlas.create_mint_abtc_signed_tx(BTC_TXN_HASH.to_string(), 100000, Some(1), Some(1)) {
+        PromiseOrValue::Value(result) => {
+            assert_eq!(result, "Deposit is disputed or within its dispute window.")
+        }
+        PromiseOrValue::Promise(_) => panic!("Expected the mint to be blocked"),
+    }
+    assert_eq!(
+        atlas.deposits.get(BTC_TXN_HASH).unwrap().status,
+        DEP_BTC_DEPOSITED_INTO_ATLAS
+    );
+}
+
+#[test]
+fn test_flag_freezes_record_until_dismissed() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(4, START + 30);
+    atlas.flag_disputed_record(
+        "deposit".to_string(),
+        BTC_TXN_HASH.to_string(),
+        "BTC transaction was replaced".to_string(),
+    );
+    assert_eq!(atlas.get_open_disputes().len(), 1);
+
+    // Still frozen after the window has passed
+    set_caller_at(0, START + 20 + WINDOW);
+    assert!(atlas.get_first_valid_deposit_chain_config().is_none());
+
+    atlas.resolve_dispute(BTC_TXN_HASH.to_string(), false);
+    let dispute = atlas.get_dispute(BTC_TXN_HASH.to_string()).unwrap();
+    assert_eq!(dispute.status, DISPUTE_DISMISSED);
+    assert!(atlas.get_open_disputes().is_empty());
+    assert!(atlas.get_first_valid_deposit_chain_config().is_some());
+}
+
+#[test]
+fn test_upheld_dispute_blocks_record() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(5, START + 30);
+    atlas.flag_disputed_record(
+        "redemption".to_string(),
+        TXN_HASH.to_string(),
+        "Burn event not found".to_string(),
+    );
+
+    set_caller_at(0, START + 20 + WINDOW);
+    atlas.resolve_dispute(TXN_HASH.to_string(), true);
+
+    let redemption = atlas.get_redemption_by_txn_hash(TXN_HASH.to_string()).unwrap();
+    assert!(!redemption.remarks.is_empty());
+    assert_eq!(
+        atlas.get_dispute(TXN_HASH.to_string()).unwrap().status,
+        DISPUTE_UPHELD
+    );
+    assert!(atlas.get_first_valid_redemption().is_none());
+}
+
+#[test]
+#[should_panic(expected = "Dispute window has passed")]
+fn test_flag_after_window() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(4, START + 20 + WINDOW);
+    atlas.flag_disputed_record(
+        "deposit".to_string(),
+        BTC_TXN_HASH.to_string(),
+        "BTC transaction was replaced".to_string(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Only a validator can flag a record")]
+fn test_flag_by_non_validator() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(3, START + 30);
+    atlas.flag_disputed_record(
+        "deposit".to_string(),
+        BTC_TXN_HASH.to_string(),
+        "BTC transaction was replaced".to_string(),
+    );
+}
+
+#[test]
+fn test_no_window_configured() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(0, START + 30);
+    atlas.set_chain_dispute_window("421614".to_string(), 0);
+    assert!(atlas.get_first_valid_deposit_chain_config().is_some());
+}
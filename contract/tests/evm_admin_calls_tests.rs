@@ -0,0 +1,132 @@
+use atlas_protocol::modules::structs::Atlas;
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::{testing_env, AccountId, PromiseOrValue};
+
+fn set_caller(account_id: AccountId) {
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(account_id);
+    testing_env!(context.build());
+}
+
+fn setup_atlas() -> Atlas {
+    set_caller(accounts(0));
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    atlas.add_evm_admin_function("pause()".to_string());
+    atlas.add_evm_admin_function("setMinter(address,bool)".to_string());
+
+    atlas.add_validator(accounts(4), "421614".to_string());
+    atlas.add_validator(accounts(5), "421614".to_string());
+    for validator in [accounts(4), accounts(5)] {
+        set_caller(validator);
+        atlas.verify_evm_nonce("421614".to_string(), 0);
+    }
+    set_caller(accounts(0));
+
+    atlas
+}
+
+fn admin_call(atlas: &mut Atlas, chain_id: &str, signature: &str, args: Vec<&str>) -> PromiseOrValue<String> {
+    atlas.create_abtc_admin_call_tx(
+        chain_id.to_string(),
+        signature.to_string(),
+        args.into_iter().map(|arg| arg.to_string()).collect(),
+        100000,
+        Some(1),
+        Some(1),
+    )
+}
+
+#[test]
+fn test_add_evm_admin_function() {
+    let atlas = setup_atlas();
+
+    let functions = atlas.get_evm_admin_functions();
+    assert_eq!(functions.len(), 2);
+    assert_eq!(functions[0].selector, "0x8456cb59");
+    assert!(functions[0].param_types.is_empty());
+    assert_eq!(functions[1].param_types, vec!["address".to_string(), "bool".to_string()]);
+    assert_eq!(functions[1].added_by, accounts(0));
+}
+
+#[test]
+fn test_sign_allowlisted_admin_call() {
+    let mut atlas = setup_atlas();
+
+    assert!(matches!(
+        admin_call(
+            &mut atlas,
+            "421614",
+            "setMinter(address,bool)",
+            vec!["0x1234567890123456789012345678901234567890", "true"],
+        ),
+        PromiseOrValue::Promise(_)
+    ));
+    assert!(matches!(
+        admin_call(&mut atlas, "421614", "pause()", vec![]),
+        PromiseOrValue::Promise(_)
+    ));
+}
+
+#[test]
+#[should_panic(expected = "Function is not allowlisted")]
+fn test_removed_function_cannot_be_signed() {
+    let mut atlas = setup_atlas();
+
+    atlas.remove_evm_admin_function("pause()".to_string());
+    admin_call(&mut atlas, "421614", "pause()", vec![]);
+}
+
+#[test]
+#[should_panic(expected = "Function signature is not canonical, expected setCap(uint256)")]
+fn test_non_canonical_signature() {
+    let mut atlas = setup_atlas();
+
+    atlas.add_evm_admin_function("setCap(uint)".to_string());
+}
+
+#[test]
+#[should_panic(expected = "Expected 2 arguments")]
+fn test_wrong_argument_count() {
+    let mut atlas = setup_atlas();
+
+    admin_call(
+        &mut atlas,
+        "421614",
+        "setMinter(address,bool)",
+        vec!["0x1234567890123456789012345678901234567890"],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid address argument: not_an_address")]
+fn test_invalid_argument() {
+    let mut atlas = setup_atlas();
+
+    admin_call(&mut atlas, "421614", "setMinter(address,bool)", vec!["not_an_address", "true"]);
+}
+
+#[test]
+#[should_panic(expected = "Chain ID is not an EVM chain")]
+fn test_admin_call_on_non_evm_chain() {
+    let mut atlas = setup_atlas();
+
+    admin_call(&mut atlas, "NEAR_TESTNET", "pause()", vec![]);
+}
+
+#[test]
+#[should_panic(expected = "Only the owner can call this method")]
+fn test_admin_call_by_non_owner() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(1));
+    admin_call(&mut atlas, "421614", "pause()", vec![]);
+}
@@ -0,0 +1,230 @@
+use atlas_protocol::modules::structs::Atlas;
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::testing_env;
+
+const CHAIN_ID: &str = "421614";
+const GWEI: u64 = 1_000_000_000;
+
+fn set_caller_at(account_index: usize, timestamp: u64) {
+    let mut context = VMContextBuilder::new();
+    context
+        .predecessor_account_id(accounts(account_index))
+        .block_timestamp(timestamp * 1_000_000_000);
+    testing_env!(context.build());
+}
+
+fn set_caller(account_index: usize) {
+    set_caller_at(account_index, 1_000);
+}
+
+fn setup_atlas() -> Atlas {
+    set_caller(0);
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    atlas.add_validator(accounts(4), CHAIN_ID.to_string());
+    atlas.add_validator(accounts(5), CHAIN_ID.to_string());
+    atlas.add_evm_admin_function("pause()".to_string());
+
+    set_caller(3);
+    atlas.set_evm_gas_bounds(CHAIN_ID.to_string(), 10000000, 50 * GWEI, 5 * GWEI, 1_000_000);
+    set_caller(0);
+
+    atlas
+}
+
+fn submit_base_fees(atlas: &mut Atlas, base_fees: [u64; 2]) {
+    for (validator, base_fee) in [4, 5].into_iter().zip(base_fees) {
+        set_caller(validator);
+        assert!(atlas.submit_evm_base_fee(CHAIN_ID.to_string(), 100, base_fee));
+    }
+}
+
+#[test]
+fn test_set_evm_gas_bounds() {
+    let mut atlas = setup_atlas();
+
+    let bounds = atlas.get_evm_gas_bounds(CHAIN_ID.to_string());
+    assert_eq!(bounds.max_gas, 10000000);
+    assert_eq!(bounds.max_fee_per_gas, 50 * GWEI);
+    assert_eq!(bounds.default_priority_fee_per_gas, 1_000_000);
+
+    set_caller(3);
+    atlas.set_evm_gas_bounds(CHAIN_ID.to_string(), 0, 0, 0, 0);
+    assert_eq!(atlas.get_evm_gas_bounds(CHAIN_ID.to_string()).max_gas, 0);
+    assert!(atlas.evm_gas_bounds.get(&CHAIN_ID.to_string()).is_none());
+}
+
+#[test]
+#[should_panic(expected = "Caller does not have the ConfigManager role")]
+fn test_set_evm_gas_bounds_without_role() {
+    let mut atlas = setup_atlas();
+
+    set_caller(4);
+    atlas.set_evm_gas_bounds(CHAIN_ID.to_string(), 10000000, 0, 0, 0);
+}
+
+#[test]
+#[should_panic(expected = "Chain ID is not an EVM chain")]
+fn test_set_evm_gas_bounds_on_non_evm_chain() {
+    let mut atlas = setup_atlas();
+
+    set_caller(3);
+    atlas.set_evm_gas_bounds("SIGNET".to_string(), 10000000, 0, 0, 0);
+}
+
+#[test]
+#[should_panic(expected = "Default priority fee per gas cannot exceed max fee per gas")]
+fn test_set_evm_gas_bounds_default_priority_fee_over_max_fee() {
+    let mut atlas = setup_atlas();
+
+    set_caller(3);
+    atlas.set_evm_gas_bounds(CHAIN_ID.to_string(), 10000000, GWEI, 0, 2 * GWEI);
+}
+
+#[test]
+fn test_fee_estimate_from_median_base_fee() {
+    let mut atlas = setup_atlas();
+    submit_base_fees(&mut atlas, [GWEI, 3 * GWEI]);
+
+    let estimate = atlas.get_evm_fee_estimate(CHAIN_ID.to_string()).unwrap();
+    assert_eq!(estimate.samples, 2);
+    assert_eq!(estimate.median_base_fee, 2 * GWEI);
+    assert_eq!(estimate.max_priority_fee_per_gas, 1_000_000);
+    assert_eq!(estimate.max_fee_per_gas, 4 * GWEI + 1_000_000);
+}
+
+#[test]
+fn test_fee_estimate_is_capped_by_max_fee_per_gas() {
+    let mut atlas = setup_atlas();
+    submit_base_fees(&mut atlas, [40 * GWEI, 40 * GWEI]);
+
+    let estimate = atlas.get_evm_fee_estimate(CHAIN_ID.to_string()).unwrap();
+    assert_eq!(estimate.max_fee_per_gas, 50 * GWEI);
+}
+
+#[test]
+fn test_fee_estimate_needs_threshold_of_fresh_base_fees() {
+    let mut atlas = setup_atlas();
+
+    set_caller(4);
+    assert!(atlas.submit_evm_base_fee(CHAIN_ID.to_string(), 100, GWEI));
+    assert!(atlas.get_evm_fee_estimate(CHAIN_ID.to_string()).is_none());
+
+    set_caller(5);
+    assert!(atlas.submit_evm_base_fee(CHAIN_ID.to_string(), 100, GWEI));
+    assert!(atlas.get_evm_fee_estimate(CHAIN_ID.to_string()).is_some());
+
+    set_caller_at(0, 1_000 + 601);
+    assert!(atlas.get_evm_fee_estimate(CHAIN_ID.to_string()).is_none());
+}
+
+#[test]
+fn test_fee_estimate_ignores_removed_validators() {
+    let mut atlas = setup_atlas();
+    submit_base_fees(&mut atlas, [GWEI, GWEI]);
+
+    set_caller(0);
+    atlas.remove_validator(accounts(5), CHAIN_ID.to_string());
+    assert!(atlas.get_evm_fee_estimate(CHAIN_ID.to_string()).is_none());
+}
+
+#[test]
+fn test_submit_evm_base_fee_replaces_previous_post() {
+    let mut atlas = setup_atlas();
+
+    set_caller(4);
+    assert!(atlas.submit_evm_base_fee(CHAIN_ID.to_string(), 100, GWEI));
+    assert!(!atlas.submit_evm_base_fee(CHAIN_ID.to_string(), 100, 2 * GWEI));
+    assert!(atlas.submit_evm_base_fee(CHAIN_ID.to_string(), 101, 2 * GWEI));
+
+    let base_fees = atlas.get_evm_base_fees(CHAIN_ID.to_string());
+    assert_eq!(base_fees.len(), 1);
+    assert_eq!(base_fees[0].block_number, 101);
+    assert_eq!(base_fees[0].base_fee, 2 * GWEI);
+}
+
+#[test]
+fn test_submit_evm_base_fee_by_non_validator() {
+    let mut atlas = setup_atlas();
+
+    set_caller(1);
+    assert!(!atlas.submit_evm_base_fee(CHAIN_ID.to_string(), 100, GWEI));
+    assert!(atlas.get_evm_base_fees(CHAIN_ID.to_string()).is_empty());
+}
+
+#[test]
+fn test_resolve_evm_fees_fills_in_defaults() {
+    let mut atlas = setup_atlas();
+    submit_base_fees(&mut atlas, [GWEI, GWEI]);
+
+    let chain_config = atlas.get_chain_config(CHAIN_ID.to_string()).unwrap();
+    assert_eq!(
+        atlas.resolve_evm_fees(&chain_config, 100000, None, None),
+        ((2 * GWEI + 1_000_000) as u128, 1_000_000)
+    );
+    assert_eq!(
+        atlas.resolve_evm_fees(&chain_config, 100000, Some(GWEI as u128), None),
+        (GWEI as u128, 1_000_000)
+    );
+}
+
+#[test]
+#[should_panic(expected = "No base fee estimate for the chain, max_fee_per_gas is required")]
+fn test_signing_without_fees_or_estimate() {
+    let mut atlas = setup_atlas();
+
+    atlas.create_abtc_admin_call_tx(CHAIN_ID.to_string(), "pause()".to_string(), vec![], 100000, None, None);
+}
+
+#[test]
+#[should_panic(expected = "Gas exceeds the chain's max_gas of 10000000")]
+fn test_signing_with_gas_over_bound() {
+    let mut atlas = setup_atlas();
+
+    atlas.create_abtc_admin_call_tx(
+        CHAIN_ID.to_string(),
+        "pause()".to_string(),
+        vec![],
+        20000000,
+        Some(1),
+        Some(1),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Max fee per gas exceeds the chain's max_fee_per_gas of 50000000000")]
+fn test_signing_with_max_fee_over_bound() {
+    let mut atlas = setup_atlas();
+
+    atlas.create_abtc_admin_call_tx(
+        CHAIN_ID.to_string(),
+        "pause()".to_string(),
+        vec![],
+        100000,
+        Some(100 * GWEI as u128),
+        Some(1),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Max priority fee per gas cannot exceed max fee per gas")]
+fn test_signing_with_priority_fee_over_max_fee() {
+    let mut atlas = setup_atlas();
+
+    atlas.create_abtc_admin_call_tx(
+        CHAIN_ID.to_string(),
+        "pause()".to_string(),
+        vec![],
+        100000,
+        Some(1),
+        Some(2),
+    );
+}
@@ -0,0 +1,193 @@
+use atlas_protocol::modules::structs::Atlas;
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::testing_env;
+
+const CHAIN_ID: &str = "421614";
+
+fn set_caller(account_index: usize) {
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(accounts(account_index));
+    testing_env!(context.build());
+}
+
+fn setup_atlas() -> Atlas {
+    set_caller(0);
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    atlas.add_validator(accounts(4), CHAIN_ID.to_string());
+    atlas.add_validator(accounts(5), CHAIN_ID.to_string());
+    atlas.add_evm_admin_function("pause()".to_string());
+
+    atlas
+}
+
+fn sign_admin_call(atlas: &mut Atlas) {
+    set_caller(0);
+    atlas.create_abtc_admin_call_tx(CHAIN_ID.to_string(), "pause()".to_string(), vec![], 100000, Some(1), Some(1));
+}
+
+fn verify_nonce(atlas: &mut Atlas, nonce: u64) {
+    for validator in [4, 5] {
+        set_caller(validator);
+        assert!(atlas.verify_evm_nonce(CHAIN_ID.to_string(), nonce));
+    }
+}
+
+#[test]
+#[should_panic(expected = "No verified nonce for the chain, validators have to verify the signer's nonce first")]
+fn test_signing_without_verified_nonce() {
+    let mut atlas = setup_atlas();
+
+    sign_admin_call(&mut atlas);
+}
+
+#[test]
+fn test_signing_hands_out_sequential_nonces() {
+    let mut atlas = setup_atlas();
+    assert!(atlas.get_evm_nonce(CHAIN_ID.to_string()).is_none());
+    verify_nonce(&mut atlas, 0);
+
+    sign_admin_call(&mut atlas);
+    sign_admin_call(&mut atlas);
+
+    let record = atlas.get_evm_nonce(CHAIN_ID.to_string()).unwrap();
+    assert_eq!(record.path, "EVM");
+    assert_eq!(record.next_nonce, 2);
+
+    let pending = atlas.get_pending_evm_nonces(CHAIN_ID.to_string());
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending[0].nonce, 0);
+    assert_eq!(pending[1].nonce, 1);
+    assert_eq!(pending[1].record_key, "pause()");
+
+    // Nonces are tracked per chain
+    assert!(atlas.get_pending_evm_nonces("11155420".to_string()).is_empty());
+}
+
+#[test]
+fn test_verify_evm_nonce_reaches_threshold() {
+    let mut atlas = setup_atlas();
+
+    set_caller(4);
+    assert!(atlas.verify_evm_nonce(CHAIN_ID.to_string(), 5));
+    assert_eq!(atlas.get_evm_nonce(CHAIN_ID.to_string()).unwrap().verified_nonce, None);
+    assert!(!atlas.verify_evm_nonce(CHAIN_ID.to_string(), 5));
+
+    set_caller(5);
+    assert!(atlas.verify_evm_nonce(CHAIN_ID.to_string(), 5));
+
+    // Signing starts at the verified nonce
+    let record = atlas.get_evm_nonce(CHAIN_ID.to_string()).unwrap();
+    assert_eq!(record.verified_nonce, Some(5));
+    assert_eq!(record.next_nonce, 5);
+    assert_eq!(record.votes.len(), 2);
+}
+
+#[test]
+fn test_verify_evm_nonce_ignores_outlier_vote() {
+    let mut atlas = setup_atlas();
+    verify_nonce(&mut atlas, 2);
+
+    // A single validator voting far ahead does not move the verified nonce
+    set_caller(4);
+    assert!(atlas.verify_evm_nonce(CHAIN_ID.to_string(), 1000));
+    assert_eq!(atlas.get_evm_nonce(CHAIN_ID.to_string()).unwrap().verified_nonce, Some(2));
+
+    // The other validator moves it as far as both votes agree
+    set_caller(5);
+    assert!(atlas.verify_evm_nonce(CHAIN_ID.to_string(), 4));
+
+    let record = atlas.get_evm_nonce(CHAIN_ID.to_string()).unwrap();
+    assert_eq!(record.verified_nonce, Some(4));
+    assert_eq!(record.next_nonce, 4);
+}
+
+#[test]
+fn test_verify_evm_nonce_prunes_mined_pending_nonces() {
+    let mut atlas = setup_atlas();
+    verify_nonce(&mut atlas, 0);
+    for _ in 0..3 {
+        sign_admin_call(&mut atlas);
+    }
+
+    verify_nonce(&mut atlas, 2);
+
+    let record = atlas.get_evm_nonce(CHAIN_ID.to_string()).unwrap();
+    assert_eq!(record.next_nonce, 3);
+    assert_eq!(record.pending.len(), 1);
+    assert_eq!(record.pending[0].nonce, 2);
+}
+
+#[test]
+fn test_verify_evm_nonce_rejects_stale_nonce() {
+    let mut atlas = setup_atlas();
+    verify_nonce(&mut atlas, 5);
+
+    set_caller(4);
+    assert!(!atlas.verify_evm_nonce(CHAIN_ID.to_string(), 5));
+    assert!(!atlas.verify_evm_nonce(CHAIN_ID.to_string(), 3));
+}
+
+#[test]
+fn test_verify_evm_nonce_by_non_validator() {
+    let mut atlas = setup_atlas();
+
+    set_caller(1);
+    assert!(!atlas.verify_evm_nonce(CHAIN_ID.to_string(), 5));
+}
+
+#[test]
+fn test_resync_evm_nonce() {
+    let mut atlas = setup_atlas();
+    verify_nonce(&mut atlas, 0);
+    for _ in 0..3 {
+        sign_admin_call(&mut atlas);
+    }
+    verify_nonce(&mut atlas, 1);
+    assert_eq!(atlas.get_pending_evm_nonces(CHAIN_ID.to_string()).len(), 2);
+
+    set_caller(1);
+    atlas.resync_evm_nonce(CHAIN_ID.to_string());
+
+    let record = atlas.get_evm_nonce(CHAIN_ID.to_string()).unwrap();
+    assert_eq!(record.next_nonce, 1);
+    assert!(record.pending.is_empty());
+
+    sign_admin_call(&mut atlas);
+    assert_eq!(atlas.get_pending_evm_nonces(CHAIN_ID.to_string())[0].nonce, 1);
+}
+
+#[test]
+#[should_panic(expected = "No verified nonce for the chain")]
+fn test_resync_evm_nonce_without_verified_nonce() {
+    let mut atlas = setup_atlas();
+
+    set_caller(1);
+    atlas.resync_evm_nonce(CHAIN_ID.to_string());
+}
+
+#[test]
+#[should_panic(expected = "Caller does not have the DepositOperator role")]
+fn test_resync_evm_nonce_without_role() {
+    let mut atlas = setup_atlas();
+    verify_nonce(&mut atlas, 1);
+
+    set_caller(3);
+    atlas.resync_evm_nonce(CHAIN_ID.to_string());
+}
+
+#[test]
+#[should_panic(expected = "Chain ID is not an EVM chain")]
+fn test_get_evm_nonce_of_non_evm_chain() {
+    let atlas = setup_atlas();
+
+    atlas.get_evm_nonce("NEAR_TESTNET".to_string());
+}
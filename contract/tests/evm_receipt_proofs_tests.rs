@@ -0,0 +1,326 @@
+use atlas_protocol::constants::status::*;
+use atlas_protocol::modules::structs::{Atlas, DepositRecord};
+use ethers_core::utils::rlp::RlpStream;
+use near_sdk::env::keccak256;
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::testing_env;
+
+const CHAIN_ID: &str = "421614";
+const ABTC_ADDRESS: &str = "163b2778a4807e4321e8aa01a2dc09f541a98003";
+const WALLET: &str = "f8b5b7c7c8f6a1b1f1f1f1f1f1f1f1f1f1f1f1f1";
+const BTC_TXN_HASH: &str = "781eaa989e5e35db6da84cb190e3df49c21cee8931e5301e91e8d9820e8f2c13";
+
+fn set_caller(account_index: usize) {
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(accounts(account_index));
+    testing_env!(context.build());
+}
+
+fn setup_atlas() -> Atlas {
+    set_caller(0);
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    atlas.add_validator(accounts(4), CHAIN_ID.to_string());
+    atlas.add_validator(accounts(5), CHAIN_ID.to_string());
+
+    atlas
+}
+
+// Leaf node of a trie holding a single entry under key rlp(0) = 0x80
+fn single_leaf_node(value: &[u8]) -> Vec<u8> {
+    let mut stream = RlpStream::new_list(2);
+    stream.append(&vec![0x20u8, 0x80]);
+    stream.append(&value.to_vec());
+    stream.out().to_vec()
+}
+
+fn build_raw_tx() -> Vec<u8> {
+    let mut stream = RlpStream::new_list(2);
+    stream.append(&42u64);
+    stream.append(&hex::decode(ABTC_ADDRESS).unwrap());
+    let mut raw_tx = vec![0x02];
+    raw_tx.extend_from_slice(&stream.out());
+    raw_tx
+}
+
+fn build_receipt(event_signature: &str, text: &str, amount: u64) -> Vec<u8> {
+    let mut wallet_topic = vec![0u8; 12];
+    wallet_topic.extend_from_slice(&hex::decode(WALLET).unwrap());
+    let data = ethabi::encode(&[
+        ethabi::Token::String(text.to_string()),
+        ethabi::Token::Uint(ethabi::Uint::from(amount)),
+    ]);
+
+    let mut stream = RlpStream::new_list(4);
+    stream.append(&1u64);
+    stream.append(&21000u64);
+    stream.append(&vec![0u8; 256]);
+    stream.begin_list(1);
+    stream.begin_list(3);
+    stream.append(&hex::decode(ABTC_ADDRESS).unwrap());
+    stream.begin_list(2);
+    stream.append(&keccak256(event_signature.as_bytes()));
+    stream.append(&wallet_topic);
+    stream.append(&data);
+
+    let mut receipt = vec![0x02];
+    receipt.extend_from_slice(&stream.out());
+    receipt
+}
+
+fn build_header(transactions_root: &[u8], receipts_root: &[u8]) -> Vec<u8> {
+    let mut stream = RlpStream::new_list(13);
+    stream.append(&vec![0u8; 32]); // parent hash
+    stream.append(&vec![0u8; 32]); // uncles hash
+    stream.append(&vec![0u8; 20]); // coinbase
+    stream.append(&vec![0u8; 32]); // state root
+    stream.append(&transactions_root.to_vec());
+    stream.append(&receipts_root.to_vec());
+    stream.append(&vec![0u8; 256]); // logs bloom
+    stream.append(&0u64); // difficulty
+    stream.append(&1000u64); // number
+    stream.append(&30000000u64); // gas limit
+    stream.append(&21000u64); // gas used
+    stream.append(&1700000000u64); // timestamp
+    stream.append(&Vec::<u8>::new()); // extra data
+    stream.out().to_vec()
+}
+
+// Co-signs a header committing to the transaction and receipt and returns (block_hash, txn_hash, tx_proof, receipt_proof)
+fn submit_block(
+    atlas: &mut Atlas,
+    receipt: &[u8],
+    signers: &[usize],
+) -> (String, String, Vec<String>, Vec<String>) {
+    let raw_tx = build_raw_tx();
+    let tx_node = single_leaf_node(&raw_tx);
+    let receipt_node = single_leaf_node(receipt);
+    let header = build_header(&keccak256(&tx_node), &keccak256(&receipt_node));
+
+    for signer in signers {
+        set_caller(*signer);
+        assert!(atlas.submit_evm_block_header(CHAIN_ID.to_string(), hex::encode(&header)));
+    }
+
+    (
+        format!("0x{}", hex::encode(keccak256(&header))),
+        format!("0x{}", hex::encode(keccak256(&raw_tx))),
+        vec![hex::encode(tx_node)],
+        vec![hex::encode(receipt_node)],
+    )
+}
+
+fn insert_pending_mint_deposit(atlas: &mut Atlas, minted_txn_hash: &str) {
+    atlas.deposits.insert(
+        BTC_TXN_HASH.to_string(),
+        DepositRecord {
+            btc_txn_hash: BTC_TXN_HASH.to_string(),
+            btc_sender_address: "tb1qsender".to_string(),
+            receiving_chain_id: CHAIN_ID.to_string(),
+            receiving_address: format!("0x{}", WALLET),
+            btc_amount: 10000,
+            fee_amount: 0,
+            minted_txn_hash: minted_txn_hash.to_string(),
+            timestamp: 1234567890,
+            status: DEP_BTC_PENDING_MINTED_INTO_ABTC,
+            remarks: "".to_string(),
+            date_created: 1234567890,
+            verified_count: 2,
+            retry_count: 0,
+            minted_txn_hash_verified_count: 0,
+            custody_txn_id: "".to_string(),
+        },
+    );
+}
+
+#[test]
+fn test_submit_evm_block_header() {
+    let mut atlas = setup_atlas();
+    let receipt = build_receipt("MintDeposit(address,string,uint256)", BTC_TXN_HASH, 10000);
+
+    let (block_hash, _, _, _) = submit_block(&mut atlas, &receipt, &[4, 5]);
+
+    let header = atlas
+        .get_evm_block_header(CHAIN_ID.to_string(), block_hash.clone())
+        .unwrap();
+    assert_eq!(header.block_number, 1000);
+    assert_eq!(header.verified_count, 2);
+
+    // Same validator cannot co-sign twice
+    set_caller(4);
+    let header_rlp = build_header(
+        &keccak256(&single_leaf_node(&build_raw_tx())),
+        &keccak256(&single_leaf_node(&receipt)),
+    );
+    assert!(!atlas.submit_evm_block_header(CHAIN_ID.to_string(), hex::encode(header_rlp)));
+}
+
+#[test]
+fn test_cosigned_header_votes_are_pruned() {
+    let mut atlas = setup_atlas();
+    let receipt = build_receipt("MintDeposit(address,string,uint256)", BTC_TXN_HASH, 10000);
+
+    // A competing header of the same block number that never reaches the threshold
+    set_caller(4);
+    assert!(atlas.submit_evm_block_header(
+        CHAIN_ID.to_string(),
+        hex::encode(build_header(&[1u8; 32], &[2u8; 32]))
+    ));
+    submit_block(&mut atlas, &receipt, &[4]);
+    assert_eq!(atlas.get_validator_votes(accounts(4)).len(), 2);
+
+    submit_block(&mut atlas, &receipt, &[5]);
+
+    // Both the co-signed header and the superseded one are finished
+    atlas.prune_validator_votes(accounts(4));
+    atlas.prune_validator_votes(accounts(5));
+    assert!(atlas.get_validator_votes(accounts(4)).is_empty());
+    assert!(atlas.get_validator_votes(accounts(5)).is_empty());
+}
+
+#[test]
+fn test_submit_evm_block_header_not_validator() {
+    let mut atlas = setup_atlas();
+    let receipt = build_receipt("MintDeposit(address,string,uint256)", BTC_TXN_HASH, 10000);
+    let header_rlp = build_header(
+        &keccak256(&single_leaf_node(&build_raw_tx())),
+        &keccak256(&single_leaf_node(&receipt)),
+    );
+
+    set_caller(3);
+    assert!(!atlas.submit_evm_block_header(CHAIN_ID.to_string(), hex::encode(header_rlp)));
+}
+
+#[test]
+fn test_verify_deposit_minted_by_receipt_proof() {
+    let mut atlas = setup_atlas();
+    let receipt = build_receipt("MintDeposit(address,string,uint256)", BTC_TXN_HASH, 10000);
+    let (block_hash, txn_hash, tx_proof, receipt_proof) =
+        submit_block(&mut atlas, &receipt, &[4, 5]);
+
+    insert_pending_mint_deposit(&mut atlas, &txn_hash);
+
+    set_caller(3);
+    assert!(atlas.verify_deposit_minted_by_receipt_proof(
+        BTC_TXN_HASH.to_string(),
+        block_hash.clone(),
+        0,
+        tx_proof,
+        receipt_proof
+    ));
+
+    let proof = atlas.get_evm_receipt_proof(BTC_TXN_HASH.to_string()).unwrap();
+    assert_eq!(proof.block_hash, block_hash);
+    assert_eq!(proof.block_number, 1000);
+
+    // The receipt proof stands in for the minted txn hash validator threshold
+    set_caller(1);
+    atlas.update_deposit_minted(BTC_TXN_HASH.to_string(), txn_hash);
+    let deposit = atlas
+        .get_deposit_by_btc_txn_hash(BTC_TXN_HASH.to_string())
+        .unwrap();
+    assert_eq!(deposit.status, DEP_BTC_MINTED_INTO_ABTC);
+}
+
+#[test]
+fn test_verify_deposit_minted_by_receipt_proof_header_below_threshold() {
+    let mut atlas = setup_atlas();
+    let receipt = build_receipt("MintDeposit(address,string,uint256)", BTC_TXN_HASH, 10000);
+    let (block_hash, txn_hash, tx_proof, receipt_proof) = submit_block(&mut atlas, &receipt, &[4]);
+
+    insert_pending_mint_deposit(&mut atlas, &txn_hash);
+
+    assert!(!atlas.verify_deposit_minted_by_receipt_proof(
+        BTC_TXN_HASH.to_string(),
+        block_hash,
+        0,
+        tx_proof,
+        receipt_proof
+    ));
+}
+
+#[test]
+fn test_verify_deposit_minted_by_receipt_proof_wrong_amount() {
+    let mut atlas = setup_atlas();
+    let receipt = build_receipt("MintDeposit(address,string,uint256)", BTC_TXN_HASH, 9999);
+    let (block_hash, txn_hash, tx_proof, receipt_proof) =
+        submit_block(&mut atlas, &receipt, &[4, 5]);
+
+    insert_pending_mint_deposit(&mut atlas, &txn_hash);
+
+    assert!(!atlas.verify_deposit_minted_by_receipt_proof(
+        BTC_TXN_HASH.to_string(),
+        block_hash,
+        0,
+        tx_proof,
+        receipt_proof
+    ));
+}
+
+#[test]
+fn test_verify_deposit_minted_by_receipt_proof_wrong_txn_hash() {
+    let mut atlas = setup_atlas();
+    let receipt = build_receipt("MintDeposit(address,string,uint256)", BTC_TXN_HASH, 10000);
+    let (block_hash, _, tx_proof, receipt_proof) = submit_block(&mut atlas, &receipt, &[4, 5]);
+
+    insert_pending_mint_deposit(&mut atlas, "0x1234");
+
+    assert!(!atlas.verify_deposit_minted_by_receipt_proof(
+        BTC_TXN_HASH.to_string(),
+        block_hash,
+        0,
+        tx_proof,
+        receipt_proof
+    ));
+}
+
+#[test]
+fn test_verify_redemption_burnt_by_receipt_proof() {
+    let mut atlas = setup_atlas();
+    let btc_receiving_address = "tb1qreceiver";
+    let receipt = build_receipt(
+        "BurnRedeem(address,string,uint256)",
+        btc_receiving_address,
+        5000,
+    );
+    let (block_hash, txn_hash, tx_proof, receipt_proof) =
+        submit_block(&mut atlas, &receipt, &[4, 5]);
+
+    let redemption_txn_hash = format!("{},{}", CHAIN_ID, txn_hash);
+    set_caller(1);
+    atlas.insert_redemption_abtc(
+        redemption_txn_hash.clone(),
+        format!("0x{}", WALLET),
+        CHAIN_ID.to_string(),
+        btc_receiving_address.to_string(),
+        5000,
+        1234567890,
+        1234567890,
+    );
+
+    assert!(atlas.verify_redemption_burnt_by_receipt_proof(
+        redemption_txn_hash.clone(),
+        block_hash,
+        0,
+        tx_proof,
+        receipt_proof
+    ));
+
+    // The receipt proof stands in for the redemption validator threshold
+    atlas.update_redemption_start(redemption_txn_hash.clone());
+    let redemption = atlas
+        .get_redemption_by_txn_hash(redemption_txn_hash)
+        .unwrap();
+    assert_eq!(
+        redemption.status,
+        RED_BTC_PENDING_REDEMPTION_FROM_ATLAS_TO_USER
+    );
+}
@@ -0,0 +1,216 @@
+use atlas_protocol::constants::status::*;
+use atlas_protocol::modules::structs::{Atlas, GovernanceParams};
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::{testing_env, AccountId};
+
+const NANOS: u64 = 1_000_000_000;
+const START: u64 = 1_700_000_000;
+const VOTING_PERIOD: u64 = 86400;
+const TIMELOCK: u64 = 3600;
+
+fn set_caller_at(account_id: AccountId, timestamp: u64) {
+    let mut context = VMContextBuilder::new();
+    context
+        .predecessor_account_id(account_id)
+        .block_timestamp(timestamp * NANOS);
+    testing_env!(context.build());
+}
+
+fn grace() -> AccountId {
+    "grace".parse().unwrap()
+}
+
+// Council of accounts(4), accounts(5) and grace, two of three have to approve
+fn setup_atlas() -> Atlas {
+    set_caller_at(accounts(0), START);
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    atlas.set_governance_params(GovernanceParams {
+        council_type: "members".to_string(),
+        council_members: vec![accounts(4), accounts(5), grace()],
+        quorum_bps: 6667,
+        voting_period: VOTING_PERIOD,
+        timelock: TIMELOCK,
+    });
+
+    atlas
+}
+
+#[test]
+fn test_proposal_executes_after_quorum_and_timelock() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(4), START + 10);
+    let proposal_id =
+        atlas.submit_proposal("update_fee_redemption_bps".to_string(), "100".to_string());
+    assert_eq!(
+        atlas.get_proposal(proposal_id).unwrap().status,
+        PROPOSAL_PENDING
+    );
+
+    set_caller_at(accounts(5), START + 20);
+    atlas.vote_on_proposal(proposal_id, true);
+
+    let proposal = atlas.get_proposal(proposal_id).unwrap();
+    assert_eq!(proposal.status, PROPOSAL_APPROVED);
+    assert_eq!(proposal.approved_at, START + 20);
+    assert_eq!(proposal.votes.len(), 2);
+    assert_eq!(proposal.votes[0].account_id, accounts(4));
+    assert!(proposal.votes[1].approve);
+
+    set_caller_at(accounts(3), START + 20 + TIMELOCK);
+    atlas.execute_proposal(proposal_id);
+
+    assert_eq!(atlas.get_all_global_params().get_fee_redemption_bps(), 100);
+    assert_eq!(
+        atlas.get_proposal(proposal_id).unwrap().status,
+        PROPOSAL_EXECUTED
+    );
+}
+
+#[test]
+#[should_panic(expected = "Proposal timelock has not passed")]
+fn test_execute_before_timelock() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(4), START + 10);
+    let proposal_id =
+        atlas.submit_proposal("update_fee_redemption_bps".to_string(), "100".to_string());
+
+    set_caller_at(accounts(5), START + 20);
+    atlas.vote_on_proposal(proposal_id, true);
+
+    set_caller_at(accounts(3), START + 20 + TIMELOCK - 1);
+    atlas.execute_proposal(proposal_id);
+}
+
+#[test]
+fn test_proposal_rejected() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(4), START + 10);
+    let proposal_id = atlas.submit_proposal(
+        "set_mpc_contract".to_string(),
+        "new-signer.testnet".to_string(),
+    );
+
+    set_caller_at(accounts(5), START + 20);
+    atlas.vote_on_proposal(proposal_id, false);
+    set_caller_at(grace(), START + 30);
+    atlas.vote_on_proposal(proposal_id, false);
+
+    assert_eq!(
+        atlas.get_proposal(proposal_id).unwrap().status,
+        PROPOSAL_REJECTED
+    );
+}
+
+#[test]
+#[should_panic(expected = "Voting period has ended")]
+fn test_vote_after_voting_period() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(4), START + 10);
+    let proposal_id =
+        atlas.submit_proposal("update_btc_staking_cap".to_string(), "100000".to_string());
+
+    set_caller_at(accounts(5), START + 10 + VOTING_PERIOD);
+    atlas.vote_on_proposal(proposal_id, true);
+}
+
+#[test]
+#[should_panic(expected = "Invalid fee: must be between 0 and 300 basis points")]
+fn test_invalid_proposal_value() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(4), START + 10);
+    atlas.submit_proposal("update_fee_deposit_bps".to_string(), "500".to_string());
+}
+
+#[test]
+#[should_panic(expected = "Only a council member can submit a proposal")]
+fn test_submit_by_non_member() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(2), START + 10);
+    atlas.submit_proposal("update_fee_deposit_bps".to_string(), "100".to_string());
+}
+
+#[test]
+#[should_panic(expected = "This change requires a governance proposal")]
+fn test_direct_change_blocked() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(2), START + 10);
+    atlas.update_fee_deposit_bps(100);
+}
+
+#[test]
+fn test_validator_council() {
+    let mut atlas = setup_atlas();
+
+    // Switch the council to the validator set through a proposal
+    set_caller_at(accounts(0), START);
+    atlas.add_validator(accounts(4), "SIGNET".to_string());
+    atlas.add_validator(accounts(5), "421614".to_string());
+
+    set_caller_at(accounts(4), START + 10);
+    let proposal_id = atlas.submit_proposal(
+        "set_governance_params".to_string(),
+        r#"{"council_type":"validators","council_members":[],"quorum_bps":5000,"voting_period":86400,"timelock":0}"#
+            .to_string(),
+    );
+    set_caller_at(grace(), START + 20);
+    atlas.vote_on_proposal(proposal_id, true);
+    set_caller_at(grace(), START + 20 + TIMELOCK);
+    atlas.execute_proposal(proposal_id);
+
+    assert_eq!(atlas.get_council_size(), 2);
+    assert!(!atlas.is_council_member(grace()));
+
+    // One of two validators is quorum at 50%
+    set_caller_at(accounts(5), START + 30 + TIMELOCK);
+    let proposal_id =
+        atlas.submit_proposal("update_fee_bridging_bps".to_string(), "50".to_string());
+    assert_eq!(
+        atlas.get_proposal(proposal_id).unwrap().status,
+        PROPOSAL_APPROVED
+    );
+}
+
+#[test]
+fn test_treasury_address_proposal() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(4), START + 10);
+    let proposal_id = atlas.submit_proposal(
+        "update_treasury_address".to_string(),
+        "new_treasury_address".to_string(),
+    );
+    set_caller_at(accounts(5), START + 20);
+    atlas.vote_on_proposal(proposal_id, true);
+
+    set_caller_at(accounts(3), START + 20 + TIMELOCK);
+    atlas.execute_proposal(proposal_id);
+    assert_eq!(
+        atlas.get_all_global_params().get_treasury_address(),
+        "new_treasury_address"
+    );
+}
+
+#[test]
+#[should_panic(expected = "This change requires a governance proposal")]
+fn test_direct_deposit_address_change_blocked() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(3), START + 10);
+    atlas.update_btc_atlas_deposit_address("tb1qnewdepositaddress".to_string());
+}
@@ -0,0 +1,207 @@
+use atlas_protocol::constants::status::*;
+use atlas_protocol::modules::structs::Atlas;
+use bitcoin::blockdata::opcodes::all::OP_RETURN;
+use bitcoin::blockdata::script::{Builder, Script};
+use bitcoin::blockdata::transaction::{OutPoint, Transaction, TxIn, TxOut};
+use bitcoin::consensus::encode::serialize;
+use bitcoin::network::constants::Network;
+use bitcoin::util::address::Address;
+use bitcoin::util::key::PublicKey;
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::testing_env;
+use std::str::FromStr;
+
+const SENDER_PUBKEY: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+const DEPOSIT_PUBKEY: &str = "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5";
+
+fn p2wpkh_address(pubkey: &str) -> Address {
+    Address::p2wpkh(&PublicKey::from_str(pubkey).unwrap(), Network::Signet).unwrap()
+}
+
+fn setup_atlas() -> Atlas {
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(accounts(0));
+    testing_env!(context.build());
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    // Global params owner configures the atlas deposit address
+    context.predecessor_account_id(accounts(2));
+    testing_env!(context.build());
+    atlas.update_btc_atlas_deposit_address(p2wpkh_address(DEPOSIT_PUBKEY).to_string());
+
+    // Switch to the admin for deposit inserts
+    context.predecessor_account_id(accounts(1));
+    testing_env!(context.build());
+
+    atlas
+}
+
+fn build_raw_tx(deposit_amount: u64, memo: Option<&str>) -> (String, String) {
+    build_raw_tx_with_witness(
+        deposit_amount,
+        memo,
+        vec![vec![0x30; 71], hex::decode(SENDER_PUBKEY).unwrap()],
+    )
+}
+
+fn build_raw_tx_with_witness(
+    deposit_amount: u64,
+    memo: Option<&str>,
+    witness: Vec<Vec<u8>>,
+) -> (String, String) {
+    let mut output = vec![TxOut {
+        value: deposit_amount,
+        script_pubkey: p2wpkh_address(DEPOSIT_PUBKEY).script_pubkey(),
+    }];
+
+    if let Some(memo) = memo {
+        output.push(TxOut {
+            value: 0,
+            script_pubkey: Builder::new()
+                .push_opcode(OP_RETURN)
+                .push_slice(memo.as_bytes())
+                .into_script(),
+        });
+    }
+
+    let tx = Transaction {
+        version: 2,
+        lock_time: 0,
+        input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: Script::new(),
+            sequence: 0xFFFFFFFF,
+            witness,
+        }],
+        output,
+    };
+
+    (hex::encode(serialize(&tx)), tx.txid().to_string())
+}
+
+#[test]
+fn test_parse_deposit_btc_raw_tx() {
+    let atlas = setup_atlas();
+
+    let (raw_tx_hex, txid) = build_raw_tx(10000, Some("421614,0xf8b5b7c7c8f6a1b1f1f1f1f1f1f1f1f1f1f1f1f1"));
+
+    let details = atlas.parse_deposit_btc_raw_tx(raw_tx_hex);
+
+    assert_eq!(details.btc_txn_hash, txid);
+    assert_eq!(
+        details.btc_sender_address,
+        p2wpkh_address(SENDER_PUBKEY).to_string()
+    );
+    assert_eq!(details.receiving_chain_id, "421614");
+    assert_eq!(
+        details.receiving_address,
+        "0xf8b5b7c7c8f6a1b1f1f1f1f1f1f1f1f1f1f1f1f1"
+    );
+    assert_eq!(details.btc_amount, 10000);
+    assert_eq!(details.fee_amount, 0);
+}
+
+#[test]
+fn test_insert_deposit_btc_from_raw_tx() {
+    let mut atlas = setup_atlas();
+
+    let (raw_tx_hex, txid) = build_raw_tx(25000, Some("NEAR_TESTNET,user.testnet"));
+
+    atlas.insert_deposit_btc_from_raw_tx(raw_tx_hex, 1234567890, 1234567890);
+
+    let deposit = atlas.get_deposit_by_btc_txn_hash(txid).unwrap();
+    assert_eq!(
+        deposit.btc_sender_address,
+        p2wpkh_address(SENDER_PUBKEY).to_string()
+    );
+    assert_eq!(deposit.receiving_chain_id, "NEAR_TESTNET");
+    assert_eq!(deposit.receiving_address, "user.testnet");
+    assert_eq!(deposit.btc_amount, 25000);
+    assert_eq!(deposit.status, DEP_BTC_PENDING_MEMPOOL);
+    assert!(deposit.minted_txn_hash.is_empty());
+    assert!(deposit.remarks.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Deposit with this transaction hash already exists")]
+fn test_insert_deposit_btc_from_raw_tx_duplicate() {
+    let mut atlas = setup_atlas();
+
+    let (raw_tx_hex, _) = build_raw_tx(25000, Some("NEAR_TESTNET,user.testnet"));
+
+    atlas.insert_deposit_btc_from_raw_tx(raw_tx_hex.clone(), 1234567890, 1234567890);
+    atlas.insert_deposit_btc_from_raw_tx(raw_tx_hex, 1234567890, 1234567890);
+}
+
+#[test]
+#[should_panic(expected = "OP_RETURN memo not found")]
+fn test_insert_deposit_btc_from_raw_tx_missing_memo() {
+    let mut atlas = setup_atlas();
+
+    let (raw_tx_hex, _) = build_raw_tx(25000, None);
+
+    atlas.insert_deposit_btc_from_raw_tx(raw_tx_hex, 1234567890, 1234567890);
+}
+
+#[test]
+#[should_panic(expected = "No output paying the BTC atlas deposit address")]
+fn test_insert_deposit_btc_from_raw_tx_no_deposit_output() {
+    let mut atlas = setup_atlas();
+
+    let (raw_tx_hex, _) = build_raw_tx(0, Some("NEAR_TESTNET,user.testnet"));
+
+    atlas.insert_deposit_btc_from_raw_tx(raw_tx_hex, 1234567890, 1234567890);
+}
+
+#[test]
+#[should_panic(expected = "Caller does not have the DepositOperator role")]
+fn test_insert_deposit_btc_from_raw_tx_not_admin() {
+    let mut atlas = setup_atlas();
+
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(accounts(4));
+    testing_env!(context.build());
+
+    let (raw_tx_hex, _) = build_raw_tx(25000, Some("NEAR_TESTNET,user.testnet"));
+
+    atlas.insert_deposit_btc_from_raw_tx(raw_tx_hex, 1234567890, 1234567890);
+}
+
+#[test]
+fn test_insert_deposit_btc_from_raw_tx_taproot_sender_left_empty() {
+    let mut atlas = setup_atlas();
+
+    // A taproot key path spend only carries the schnorr signature
+    let (raw_tx_hex, txid) =
+        build_raw_tx_with_witness(25000, Some("NEAR_TESTNET,user.testnet"), vec![vec![0x01; 64]]);
+
+    atlas.insert_deposit_btc_from_raw_tx(raw_tx_hex, 1234567890, 1234567890);
+
+    let deposit = atlas.get_deposit_by_btc_txn_hash(txid).unwrap();
+    assert!(deposit.btc_sender_address.is_empty());
+}
+
+#[test]
+fn test_insert_deposit_btc_from_raw_tx_p2wsh_sender_left_empty() {
+    let mut atlas = setup_atlas();
+
+    // A 2-of-2 multisig P2WSH spend carries an empty element, two signatures and the witness script
+    let (raw_tx_hex, txid) = build_raw_tx_with_witness(
+        25000,
+        Some("NEAR_TESTNET,user.testnet"),
+        vec![vec![], vec![0x30; 71], vec![0x30; 71], vec![0x52; 71]],
+    );
+
+    atlas.insert_deposit_btc_from_raw_tx(raw_tx_hex, 1234567890, 1234567890);
+
+    let deposit = atlas.get_deposit_by_btc_txn_hash(txid).unwrap();
+    assert!(deposit.btc_sender_address.is_empty());
+}
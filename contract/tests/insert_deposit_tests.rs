@@ -828,7 +828,7 @@ async fn test_insert_deposit_with_very_large_timestamp() {
 }
 
 #[tokio::test]
-#[should_panic(expected = "Only the admin can call this method")]
+#[should_panic(expected = "Caller does not have the DepositOperator role")]
 async fn test_insert_deposit_by_non_admin() {
     let mut context = VMContextBuilder::new();
     context.predecessor_account_id(accounts(2)); // Not the admin
@@ -397,7 +397,7 @@ async fn test_insert_redemption_with_very_large_timestamp() {
 }
 
 #[tokio::test]
-#[should_panic(expected = "Only the admin can call this method")]
+#[should_panic(expected = "Caller does not have the RedemptionOperator role")]
 async fn test_insert_redemption_by_non_admin() {
     let mut context = VMContextBuilder::new();
 
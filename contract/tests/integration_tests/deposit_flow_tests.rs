@@ -1,4 +1,4 @@
-use crate::setup::setup_atlas;
+use crate::setup::{record_signed_mint, setup_atlas};
 use atlas_protocol::constants::status::*;
 use near_sdk::test_utils::{accounts, VMContextBuilder};
 use near_sdk::{testing_env, Promise};
@@ -55,10 +55,11 @@ async fn test_deposit_flow_evm() {
         .predecessor_account_id(accounts(1))
         .block_timestamp(1234567890)
         .build());
-    atlas.create_mint_abtc_signed_tx(deposit.btc_txn_hash.clone(), 94, 5000000, 100000000, 0);
+    atlas.create_mint_abtc_signed_tx(deposit.btc_txn_hash.clone(), 5000000, Some(100000000), Some(0));
     
     // 5. Update minted txn hash
     let minted_txn_hash = "0x511d02e4a7dc5319a339050a405f40a6ff17ad68dce7f9cb0e3d0cf549c6acbf".to_string();
+    record_signed_mint(&mut atlas, &btc_txn_hash, &minted_txn_hash);
     testing_env!(VMContextBuilder::new()
         .predecessor_account_id(accounts(1))
         .build());
@@ -150,7 +151,7 @@ async fn test_deposit_flow_near() {
         .predecessor_account_id(accounts(1))
         .block_timestamp(1234567890)
         .build());
-    atlas.create_mint_abtc_signed_tx(deposit.btc_txn_hash.clone(), 94, 5000000, 100000000, 0);
+    atlas.create_mint_abtc_signed_tx(deposit.btc_txn_hash.clone(), 5000000, Some(100000000), Some(0));
 
     // 5. Update minted txn hash
     let minted_txn_hash = "25YpMdT51NiUzHGpnpJMJtwdboAe91YBMFEGo3GCwyRq".to_string();
@@ -88,5 +88,21 @@ pub fn setup_atlas() -> Atlas {
     atlas.add_validator(accounts(1), "NEAR_TESTNET".to_string());
     atlas.add_validator(accounts(2), "NEAR_TESTNET".to_string());
 
+    // Nothing is signed for 421614 until its validators have verified the signer's nonce
+    for validator in [accounts(1), accounts(2)] {
+        testing_env!(VMContextBuilder::new()
+            .predecessor_account_id(validator)
+            .build());
+        atlas.verify_evm_nonce("421614".to_string(), 0);
+    }
+
     atlas
 }
+
+// The MPC callback does not run in unit tests, record the hash of the signed mint attempt it would have kept
+pub fn record_signed_mint(atlas: &mut Atlas, btc_txn_hash: &str, tx_hash: &str) {
+    let mut attempts = atlas.get_mint_attempts(btc_txn_hash.to_string());
+    let attempt = attempts.last_mut().unwrap();
+    attempt.tx_hash = tx_hash.to_string();
+    atlas.mint_attempts.insert(btc_txn_hash.to_string(), attempts);
+}
@@ -0,0 +1,309 @@
+use atlas_protocol::constants::status::*;
+use atlas_protocol::modules::signer::{AffinePoint, Scalar, SignResult};
+use atlas_protocol::modules::structs::{Atlas, DepositRecord};
+use near_sdk::env::keccak256;
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::{testing_env, AccountId};
+use omni_transaction::evm::evm_transaction::EVMTransaction;
+use secp256k1::{Message, Secp256k1, SecretKey};
+
+const NANOS: u64 = 1_000_000_000;
+const START: u64 = 1_700_000_000;
+const GWEI: u128 = 1_000_000_000;
+
+fn set_caller(account_id: AccountId) {
+    let mut context = VMContextBuilder::new();
+    context
+        .predecessor_account_id(account_id)
+        .block_timestamp(START * NANOS);
+    testing_env!(context.build());
+}
+
+// Callbacks run as the contract itself
+fn set_contract_caller() {
+    let mut context = VMContextBuilder::new();
+    context
+        .current_account_id(accounts(5))
+        .predecessor_account_id(accounts(5))
+        .block_timestamp(START * NANOS);
+    testing_env!(context.build());
+}
+
+fn verified_deposit(btc_txn_hash: &str) -> DepositRecord {
+    DepositRecord {
+        btc_txn_hash: btc_txn_hash.to_string(),
+        btc_sender_address: "tb1qsender".to_string(),
+        receiving_chain_id: "421614".to_string(),
+        receiving_address: "0xf8b5b7c7c8f6a1b1f1f1f1f1f1f1f1f1f1f1f1f1".to_string(),
+        btc_amount: 10000,
+        fee_amount: 0,
+        minted_txn_hash: "".to_string(),
+        timestamp: START,
+        status: DEP_BTC_DEPOSITED_INTO_ATLAS,
+        remarks: "".to_string(),
+        date_created: START,
+        verified_count: 2,
+        retry_count: 0,
+        minted_txn_hash_verified_count: 0,
+        custody_txn_id: "".to_string(),
+    }
+}
+
+// Signs deposit_1 and deposit_2 at nonces 0 and 1 with 1 gwei max fee and 0.1 gwei tip, once the
+// 421614 validators accounts(2) and accounts(4) have verified the signer's nonce
+fn setup_atlas() -> Atlas {
+    set_caller(accounts(0));
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    atlas.add_validator(accounts(2), "421614".to_string());
+    atlas.add_validator(accounts(4), "421614".to_string());
+    for validator in [accounts(2), accounts(4)] {
+        set_caller(validator);
+        atlas.verify_evm_nonce("421614".to_string(), 0);
+    }
+
+    set_caller(accounts(1));
+    for btc_txn_hash in ["deposit_1", "deposit_2"] {
+        atlas
+            .deposits
+            .insert(btc_txn_hash.to_string(), verified_deposit(btc_txn_hash));
+        atlas.create_mint_abtc_signed_tx(
+            btc_txn_hash.to_string(),
+            100000,
+            Some(GWEI),
+            Some(GWEI / 10),
+        );
+    }
+
+    atlas
+}
+
+fn signer_key() -> SecretKey {
+    SecretKey::from_slice(&[9u8; 32]).unwrap()
+}
+
+fn signer_address() -> String {
+    let public_key = secp256k1::PublicKey::from_secret_key(&Secp256k1::new(), &signer_key());
+    format!(
+        "0x{}",
+        hex::encode(&keccak256(&public_key.serialize_uncompressed()[1..])[12..])
+    )
+}
+
+// Returns the MPC callback of the last attempt of the record, signed with signer_key
+fn sign_last_attempt(atlas: &Atlas, record_key: &str) -> (EVMTransaction, SignResult) {
+    let attempt = atlas.get_mint_attempts(record_key.to_string()).pop().unwrap();
+    let evm_tx: EVMTransaction = serde_json::from_str(&attempt.evm_tx).unwrap();
+    let message = Message::from_slice(&keccak256(&evm_tx.build_for_signing())).unwrap();
+    let (recovery_id, signature) = Secp256k1::new()
+        .sign_ecdsa_recoverable(&message, &signer_key())
+        .serialize_compact();
+
+    let sign_result = SignResult {
+        big_r: AffinePoint {
+            affine_point: format!("02{}", hex::encode(&signature[..32])),
+        },
+        s: Scalar {
+            scalar: hex::encode(&signature[32..]),
+        },
+        recovery_id: recovery_id.to_i32() as u8,
+    };
+    (evm_tx, sign_result)
+}
+
+// Records the signature of deposit_2's mint and returns its hash
+fn sign_deposit_2(atlas: &mut Atlas) -> String {
+    let (evm_tx, sign_result) = sign_last_attempt(atlas, "deposit_2");
+
+    set_contract_caller();
+    let signed_tx =
+        atlas.sign_mint_attempt_callback("deposit_2".to_string(), 0, evm_tx, Ok(sign_result));
+    set_caller(accounts(1));
+
+    format!("0x{}", hex::encode(keccak256(&signed_tx)))
+}
+
+#[test]
+fn test_mint_records_first_attempt() {
+    let atlas = setup_atlas();
+
+    let attempts = atlas.get_mint_attempts("deposit_2".to_string());
+    assert_eq!(attempts.len(), 1);
+    assert_eq!(attempts[0].attempt_type, "mint");
+    assert_eq!(attempts[0].nonce, 1);
+    assert_eq!(attempts[0].max_fee_per_gas.0, GWEI);
+    assert!(attempts[0].tx_hash.is_empty());
+    assert!(!attempts[0].evm_tx.is_empty());
+    assert!(atlas.last_evm_tx.is_none());
+}
+
+#[test]
+fn test_speed_up_reuses_nonce_with_bumped_fees() {
+    let mut atlas = setup_atlas();
+
+    atlas.speed_up_mint_abtc_tx("deposit_1".to_string(), 100000, None, None);
+
+    let attempts = atlas.get_mint_attempts("deposit_1".to_string());
+    assert_eq!(attempts.len(), 2);
+    assert_eq!(attempts[1].attempt_type, "speed_up");
+    assert_eq!(attempts[1].nonce, 0);
+    assert_eq!(attempts[1].max_fee_per_gas.0, GWEI * 11 / 10);
+    assert_eq!(attempts[1].max_priority_fee_per_gas.0, GWEI / 10 * 11 / 10);
+    assert_eq!(atlas.get_evm_nonce("421614".to_string()).unwrap().next_nonce, 2);
+}
+
+#[test]
+#[should_panic(expected = "Replacement fees have to be at least")]
+fn test_speed_up_below_fee_bump() {
+    let mut atlas = setup_atlas();
+
+    atlas.speed_up_mint_abtc_tx("deposit_1".to_string(), 100000, Some(GWEI + 1), None);
+}
+
+#[test]
+#[should_panic(expected = "Caller does not have the DepositOperator role")]
+fn test_speed_up_without_role() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(3));
+    atlas.speed_up_mint_abtc_tx("deposit_1".to_string(), 100000, None, None);
+}
+
+#[test]
+fn test_callback_records_hash_and_signer() {
+    let mut atlas = setup_atlas();
+
+    let tx_hash = sign_deposit_2(&mut atlas);
+
+    let attempt = &atlas.get_mint_attempts("deposit_2".to_string())[0];
+    assert_eq!(attempt.tx_hash, tx_hash);
+    assert_eq!(attempt.from_address, signer_address());
+}
+
+#[test]
+fn test_cancel_signs_self_transfer_at_same_nonce() {
+    let mut atlas = setup_atlas();
+    sign_deposit_2(&mut atlas);
+
+    atlas.cancel_mint_abtc_tx("deposit_2".to_string(), 100000, None, None);
+
+    let attempts = atlas.get_mint_attempts("deposit_2".to_string());
+    assert_eq!(attempts[1].attempt_type, "cancel");
+    assert_eq!(attempts[1].nonce, 1);
+
+    let evm_tx: EVMTransaction = serde_json::from_str(&attempts[1].evm_tx).unwrap();
+    let signer = hex::decode(&signer_address()[2..]).unwrap();
+    assert!(evm_tx
+        .build_for_signing()
+        .windows(signer.len())
+        .any(|bytes| bytes == signer.as_slice()));
+}
+
+#[test]
+#[should_panic(expected = "Signer address of the mint is not known yet")]
+fn test_cancel_before_mint_is_signed() {
+    let mut atlas = setup_atlas();
+
+    atlas.cancel_mint_abtc_tx("deposit_1".to_string(), 100000, None, None);
+}
+
+#[test]
+#[should_panic(expected = "Mint transaction is already cancelled")]
+fn test_speed_up_after_cancel() {
+    let mut atlas = setup_atlas();
+    sign_deposit_2(&mut atlas);
+
+    atlas.cancel_mint_abtc_tx("deposit_2".to_string(), 100000, None, None);
+    atlas.speed_up_mint_abtc_tx("deposit_2".to_string(), 100000, Some(2 * GWEI), Some(GWEI));
+}
+
+#[test]
+fn test_minted_txn_hash_has_to_match_an_attempt() {
+    let mut atlas = setup_atlas();
+    let tx_hash = sign_deposit_2(&mut atlas);
+
+    atlas.update_deposit_minted_txn_hash("deposit_2".to_string(), "0xunknown".to_string());
+    assert!(atlas
+        .get_deposit_by_btc_txn_hash("deposit_2".to_string())
+        .unwrap()
+        .minted_txn_hash
+        .is_empty());
+
+    atlas.update_deposit_minted_txn_hash(
+        "deposit_2".to_string(),
+        tx_hash.to_uppercase().replace("0X", "0x"),
+    );
+    assert_eq!(
+        atlas
+            .get_deposit_by_btc_txn_hash("deposit_2".to_string())
+            .unwrap()
+            .minted_txn_hash
+            .to_lowercase(),
+        tx_hash
+    );
+}
+
+#[test]
+fn test_minted_txn_hash_before_attempt_is_signed() {
+    let mut atlas = setup_atlas();
+
+    // deposit_1 has a mint attempt whose signature has not come back, no reported hash can match it
+    set_caller(accounts(1));
+    atlas.update_deposit_minted_txn_hash("deposit_1".to_string(), "0xunknown".to_string());
+    assert!(atlas
+        .get_deposit_by_btc_txn_hash("deposit_1".to_string())
+        .unwrap()
+        .minted_txn_hash
+        .is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Signed mint has to be cancelled before the deposit is rolled back")]
+fn test_rollback_refused_while_mint_attempt_is_outstanding() {
+    let mut atlas = setup_atlas();
+    sign_deposit_2(&mut atlas);
+
+    atlas.update_deposit_remarks("deposit_2".to_string(), "mint is stuck".to_string());
+    atlas.rollback_deposit_status_by_btc_txn_hash("deposit_2".to_string());
+}
+
+#[test]
+fn test_rollback_all_skips_outstanding_mint_attempt() {
+    let mut atlas = setup_atlas();
+    sign_deposit_2(&mut atlas);
+
+    atlas.update_deposit_remarks("deposit_2".to_string(), "mint is stuck".to_string());
+    atlas.rollback_all_deposit_status();
+
+    let deposit = atlas.get_deposit_by_btc_txn_hash("deposit_2".to_string()).unwrap();
+    assert_eq!(deposit.status, DEP_BTC_PENDING_MINTED_INTO_ABTC);
+    assert_eq!(deposit.retry_count, 0);
+}
+
+#[test]
+fn test_rollback_allowed_after_cancel_attempt() {
+    let mut atlas = setup_atlas();
+    sign_deposit_2(&mut atlas);
+
+    atlas.cancel_mint_abtc_tx("deposit_2".to_string(), 100000, None, None);
+    let (evm_tx, sign_result) = sign_last_attempt(&atlas, "deposit_2");
+    set_contract_caller();
+    atlas.sign_mint_attempt_callback("deposit_2".to_string(), 1, evm_tx, Ok(sign_result));
+    set_caller(accounts(1));
+
+    atlas.update_deposit_remarks("deposit_2".to_string(), "mint is cancelled".to_string());
+    atlas.rollback_deposit_status_by_btc_txn_hash("deposit_2".to_string());
+
+    let deposit = atlas.get_deposit_by_btc_txn_hash("deposit_2".to_string()).unwrap();
+    assert_eq!(deposit.status, DEP_BTC_DEPOSITED_INTO_ATLAS);
+    assert_eq!(deposit.retry_count, 1);
+    assert!(deposit.remarks.is_empty());
+}
@@ -0,0 +1,251 @@
+use atlas_protocol::constants::status::*;
+use atlas_protocol::modules::structs::{Atlas, DepositRecord};
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::{testing_env, AccountId, PromiseOrValue};
+
+const NANOS: u64 = 1_000_000_000;
+const START: u64 = 1_700_000_000;
+
+fn set_caller_at(account_id: AccountId, timestamp: u64) {
+    let mut context = VMContextBuilder::new();
+    context
+        .predecessor_account_id(account_id)
+        .block_timestamp(timestamp * NANOS);
+    testing_env!(context.build());
+}
+
+// A deposit to 421614 that has reached the validators threshold and is ready to mint
+fn verified_deposit(btc_txn_hash: &str, btc_amount: u64) -> DepositRecord {
+    DepositRecord {
+        btc_txn_hash: btc_txn_hash.to_string(),
+        btc_sender_address: "tb1qsender".to_string(),
+        receiving_chain_id: "421614".to_string(),
+        receiving_address: "0xf8b5b7c7c8f6a1b1f1f1f1f1f1f1f1f1f1f1f1f1".to_string(),
+        btc_amount,
+        fee_amount: 0,
+        minted_txn_hash: "".to_string(),
+        timestamp: START,
+        status: DEP_BTC_DEPOSITED_INTO_ATLAS,
+        remarks: "".to_string(),
+        date_created: START,
+        verified_count: 2,
+        retry_count: 0,
+        minted_txn_hash_verified_count: 0,
+        custody_txn_id: "".to_string(),
+    }
+}
+
+// 421614 may mint 15000 sats per hour and 25000 sats per day, its signer's nonce is verified by
+// accounts(4) and accounts(5)
+fn setup_atlas() -> Atlas {
+    set_caller_at(accounts(0), START);
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    atlas.add_validator(accounts(4), "421614".to_string());
+    atlas.add_validator(accounts(5), "421614".to_string());
+    for validator in [accounts(4), accounts(5)] {
+        set_caller_at(validator, START);
+        atlas.verify_evm_nonce("421614".to_string(), 0);
+    }
+
+    set_caller_at(accounts(3), START);
+    atlas.set_chain_mint_limits("421614".to_string(), 15000, 25000);
+
+    for (btc_txn_hash, btc_amount) in [("deposit_1", 10000), ("deposit_2", 10000), ("deposit_3", 10000)] {
+        atlas
+            .deposits
+            .insert(btc_txn_hash.to_string(), verified_deposit(btc_txn_hash, btc_amount));
+    }
+
+    atlas
+}
+
+fn mint(atlas: &mut Atlas, btc_txn_hash: &str) -> PromiseOrValue<String> {
+    atlas.create_mint_abtc_signed_tx(btc_txn_hash.to_string(), 100000, Some(1), Some(1))
+}
+
+#[test]
+fn test_mint_within_limits() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(1), START + 10);
+    assert!(matches!(mint(&mut atlas, "deposit_1"), PromiseOrValue::Promise(_)));
+
+    let capacity = atlas.get_remaining_mint_capacity("421614".to_string());
+    assert_eq!(capacity.hourly_remaining, Some(5000));
+    assert_eq!(capacity.daily_remaining, Some(15000));
+    assert_eq!(
+        atlas
+            .get_remaining_mint_capacity("11155420".to_string())
+            .hourly_remaining,
+        None
+    );
+}
+
+#[test]
+fn test_hourly_limit_trips_circuit_breaker() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(1), START + 10);
+    mint(&mut atlas, "deposit_1");
+
+    match mint(&mut atlas, "deposit_2") {
+        PromiseOrValue::Value(result) => {
+            assert_eq!(result, "Mint limit exceeded, minting is paused.")
+        }
+        PromiseOrValue::Promise(_) => panic!("Mint over the hourly limit was signed"),
+    }
+
+    assert!(atlas.is_scope_paused("minting".to_string(), "421614".to_string()));
+    assert!(!atlas.is_scope_paused("minting".to_string(), "11155420".to_string()));
+    assert_eq!(
+        atlas
+            .get_deposit_by_btc_txn_hash("deposit_2".to_string())
+            .unwrap()
+            .status,
+        DEP_BTC_DEPOSITED_INTO_ATLAS
+    );
+}
+
+#[test]
+fn test_hourly_window_rolls_over() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(1), START + 10);
+    mint(&mut atlas, "deposit_1");
+
+    set_caller_at(accounts(1), START + 10 + 3600);
+    assert!(matches!(mint(&mut atlas, "deposit_2"), PromiseOrValue::Promise(_)));
+
+    // The daily limit still counts both mints
+    assert_eq!(
+        atlas
+            .get_remaining_mint_capacity("421614".to_string())
+            .daily_remaining,
+        Some(5000)
+    );
+}
+
+#[test]
+fn test_global_limit_pauses_every_chain() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(2), START);
+    atlas.set_global_mint_limits(0, 15000);
+
+    set_caller_at(accounts(1), START + 10);
+    mint(&mut atlas, "deposit_1");
+    set_caller_at(accounts(1), START + 10 + 3600);
+    mint(&mut atlas, "deposit_2");
+
+    assert!(atlas.is_scope_paused("minting".to_string(), "11155420".to_string()));
+    assert_eq!(atlas.get_paused_scopes()[0].chain_id, "");
+}
+
+#[test]
+fn test_guardian_resumes_circuit_breaker() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(1), START + 10);
+    mint(&mut atlas, "deposit_1");
+    mint(&mut atlas, "deposit_2");
+
+    // The reviewed 10000 sats are allowed on top of the limits, the mints still count
+    set_caller_at(accounts(0), START + 20);
+    atlas.resume_mint_circuit_breaker(Some("421614".to_string()), 10000);
+    assert!(atlas.get_paused_scopes().is_empty());
+    assert_eq!(
+        atlas
+            .get_remaining_mint_capacity("421614".to_string())
+            .hourly_remaining,
+        Some(15000)
+    );
+
+    set_caller_at(accounts(1), START + 30);
+    assert!(matches!(mint(&mut atlas, "deposit_2"), PromiseOrValue::Promise(_)));
+}
+
+#[test]
+#[should_panic(expected = "Minting was not paused by the circuit breaker")]
+fn test_resume_guardian_pause() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(0), START + 10);
+    atlas.pause_scope("minting".to_string(), Some("421614".to_string()));
+    atlas.resume_mint_circuit_breaker(Some("421614".to_string()), 0);
+}
+
+#[test]
+fn test_resume_keeps_mint_volumes() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(1), START + 10);
+    mint(&mut atlas, "deposit_1");
+    mint(&mut atlas, "deposit_2");
+
+    set_caller_at(accounts(0), START + 20);
+    atlas.resume_mint_circuit_breaker(Some("421614".to_string()), 0);
+
+    // Without an allowance the next mint over the limit trips the breaker again
+    set_caller_at(accounts(1), START + 30);
+    assert!(matches!(mint(&mut atlas, "deposit_2"), PromiseOrValue::Value(_)));
+    assert!(atlas.is_scope_paused("minting".to_string(), "421614".to_string()));
+}
+
+#[test]
+fn test_mint_allowance_expires() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(1), START + 10);
+    mint(&mut atlas, "deposit_1");
+    mint(&mut atlas, "deposit_2");
+
+    set_caller_at(accounts(0), START + 20);
+    atlas.resume_mint_circuit_breaker(Some("421614".to_string()), 10000);
+    let allowance = atlas.get_mint_allowance(Some("421614".to_string())).unwrap();
+    assert_eq!(allowance.amount, 10000);
+    assert_eq!(allowance.expires_at, START + 20 + 86400);
+
+    set_caller_at(accounts(1), START + 20 + 86400);
+    assert_eq!(
+        atlas
+            .get_remaining_mint_capacity("421614".to_string())
+            .hourly_remaining,
+        Some(15000)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Caller does not have the ConfigManager role")]
+fn test_set_mint_limits_without_role() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(1), START + 10);
+    atlas.set_chain_mint_limits("421614".to_string(), 0, 0);
+}
+
+#[test]
+fn test_retry_after_signing_failure_counts_once() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(1), START + 10);
+    assert!(matches!(mint(&mut atlas, "deposit_1"), PromiseOrValue::Promise(_)));
+
+    // The MPC signature never came back, the operator rolls the deposit back and signs it again
+    atlas.update_deposit_remarks("deposit_1".to_string(), "signing failed".to_string());
+    atlas.rollback_deposit_status_by_btc_txn_hash("deposit_1".to_string());
+    assert!(matches!(mint(&mut atlas, "deposit_1"), PromiseOrValue::Promise(_)));
+
+    let capacity = atlas.get_remaining_mint_capacity("421614".to_string());
+    assert_eq!(capacity.hourly_remaining, Some(5000));
+    assert_eq!(capacity.daily_remaining, Some(15000));
+    assert!(!atlas.is_scope_paused("minting".to_string(), "421614".to_string()));
+}
@@ -0,0 +1,162 @@
+use atlas_protocol::constants::status::*;
+use atlas_protocol::modules::structs::{Atlas, DepositRecord};
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::{testing_env, AccountId};
+
+const BTC_TXN_HASH: &str = "btc_txn_hash";
+
+fn set_caller(account_id: AccountId) {
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(account_id);
+    testing_env!(context.build());
+}
+
+// accounts(4) is a pause guardian and accounts(5) a SIGNET validator
+fn setup_atlas() -> Atlas {
+    set_caller(accounts(0));
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    atlas.grant_role(accounts(4), "PauseGuardian".to_string());
+    atlas.add_validator(accounts(5), "SIGNET".to_string());
+
+    atlas
+}
+
+fn insert_deposit(atlas: &mut Atlas) {
+    set_caller(accounts(1));
+    atlas.insert_deposit_btc(
+        BTC_TXN_HASH.to_string(),
+        "btc_sender_address".to_string(),
+        "421614".to_string(),
+        "0x1234567890123456789012345678901234567890".to_string(),
+        1000,
+        0,
+        "".to_string(),
+        1234567890,
+        "".to_string(),
+        1234567890,
+    );
+}
+
+fn insert_redemption(atlas: &mut Atlas) {
+    set_caller(accounts(1));
+    atlas.insert_redemption_abtc(
+        "421614,0x1234567890abcdef".to_string(),
+        "0x1234567890123456789012345678901234567890".to_string(),
+        "421614".to_string(),
+        "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx".to_string(),
+        1000,
+        1625097600,
+        1625097600,
+    );
+}
+
+#[test]
+#[should_panic(expected = "The deposits flow is paused for chain ID: 421614")]
+fn test_pause_scope_on_one_chain() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(4));
+    atlas.pause_scope("deposits".to_string(), Some("421614".to_string()));
+
+    assert!(atlas.is_scope_paused("deposits".to_string(), "421614".to_string()));
+    assert!(!atlas.is_scope_paused("deposits".to_string(), "SIGNET".to_string()));
+    assert_eq!(atlas.get_paused_scopes()[0].paused_by, accounts(4));
+
+    insert_deposit(&mut atlas);
+}
+
+#[test]
+fn test_other_flows_keep_running() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(4));
+    atlas.pause_scope("minting".to_string(), Some("421614".to_string()));
+
+    insert_deposit(&mut atlas);
+    insert_redemption(&mut atlas);
+    assert_eq!(atlas.get_redemptions_count(), 1);
+}
+
+#[test]
+#[should_panic(expected = "The redemptions flow is paused for chain ID: 421614")]
+fn test_pause_scope_on_every_chain() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(4));
+    atlas.pause_scope("redemptions".to_string(), None);
+    assert!(atlas.is_scope_paused("redemptions".to_string(), "421614".to_string()));
+
+    insert_redemption(&mut atlas);
+}
+
+#[test]
+fn test_validator_voting_paused() {
+    let mut atlas = setup_atlas();
+    insert_deposit(&mut atlas);
+    atlas.update_deposit_btc_deposited(BTC_TXN_HASH.to_string(), 1234567890);
+
+    set_caller(accounts(4));
+    atlas.pause_scope("validator_voting".to_string(), Some("SIGNET".to_string()));
+
+    let deposit: DepositRecord = atlas
+        .get_deposit_by_btc_txn_hash(BTC_TXN_HASH.to_string())
+        .unwrap();
+    assert_eq!(deposit.status, DEP_BTC_DEPOSITED_INTO_ATLAS);
+
+    set_caller(accounts(5));
+    assert!(!atlas.increment_deposit_verified_count(deposit.clone()));
+
+    set_caller(accounts(0));
+    atlas.unpause_scope("validator_voting".to_string(), Some("SIGNET".to_string()));
+    assert!(atlas.get_paused_scopes().is_empty());
+
+    set_caller(accounts(5));
+    assert!(atlas.increment_deposit_verified_count(deposit));
+}
+
+#[test]
+#[should_panic(expected = "Only the owner can call this method")]
+fn test_guardian_cannot_unpause_scope() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(4));
+    atlas.pause_scope("refunds".to_string(), None);
+    atlas.unpause_scope("refunds".to_string(), None);
+}
+
+#[test]
+#[should_panic(expected = "Only the owner can call this method")]
+fn test_guardian_cannot_unpause_contract() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(4));
+    atlas.pause();
+    atlas.unpause();
+}
+
+#[test]
+#[should_panic(expected = "Caller does not have the PauseGuardian role")]
+fn test_pause_scope_without_role() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(1));
+    atlas.pause_scope("minting".to_string(), None);
+}
+
+#[test]
+#[should_panic(expected = "Invalid pause scope")]
+fn test_invalid_pause_scope() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(4));
+    atlas.pause_scope("withdrawals".to_string(), None);
+}
@@ -0,0 +1,290 @@
+use atlas_protocol::constants::status::*;
+use atlas_protocol::modules::signer::{AffinePoint, Scalar, SignResult};
+use atlas_protocol::modules::structs::{
+    Atlas, DepositRecord, RedemptionRecord, ReserveAttestationRecord,
+};
+use near_sdk::json_types::U128;
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::{testing_env, AccountId};
+
+fn set_caller(account_id: AccountId) {
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(account_id);
+    testing_env!(context.build());
+}
+
+fn setup_atlas() -> Atlas {
+    set_caller(accounts(0));
+
+    Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    )
+}
+
+fn deposit_record(btc_txn_hash: &str, btc_amount: u64, status: u8) -> DepositRecord {
+    DepositRecord {
+        btc_txn_hash: btc_txn_hash.to_string(),
+        btc_sender_address: "tb1qsender".to_string(),
+        receiving_chain_id: "421614".to_string(),
+        receiving_address: "0x1234567890123456789012345678901234567890".to_string(),
+        btc_amount,
+        fee_amount: 0,
+        minted_txn_hash: "".to_string(),
+        timestamp: 1234567890,
+        status,
+        remarks: "".to_string(),
+        date_created: 1234567890,
+        verified_count: 2,
+        retry_count: 0,
+        minted_txn_hash_verified_count: 0,
+        custody_txn_id: "custody_txn_id".to_string(),
+    }
+}
+
+fn redemption_record(txn_hash: &str, abtc_amount: u64, status: u8) -> RedemptionRecord {
+    RedemptionRecord {
+        txn_hash: txn_hash.to_string(),
+        abtc_redemption_address: "0x1234567890123456789012345678901234567890".to_string(),
+        abtc_redemption_chain_id: "421614".to_string(),
+        btc_receiving_address: "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx".to_string(),
+        abtc_amount,
+        btc_txn_hash: "".to_string(),
+        timestamp: 1234567890,
+        status,
+        remarks: "".to_string(),
+        date_created: 1234567890,
+        verified_count: 2,
+        btc_txn_hash_verified_count: 0,
+        custody_txn_id: "".to_string(),
+    }
+}
+
+#[test]
+fn test_totals_follow_status_transitions() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(1));
+    atlas.insert_deposit_btc(
+        "btc_txn_hash".to_string(),
+        "btc_sender_address".to_string(),
+        "421614".to_string(),
+        "0x1234567890123456789012345678901234567890".to_string(),
+        1000,
+        0,
+        "".to_string(),
+        1234567890,
+        "".to_string(),
+        1234567890,
+    );
+    assert_eq!(atlas.get_reserves().deposited, 0);
+
+    atlas.update_deposit_btc_deposited("btc_txn_hash".to_string(), 1234567890);
+    atlas.insert_redemption_abtc(
+        "421614,0x1234567890abcdef".to_string(),
+        "0x1234567890123456789012345678901234567890".to_string(),
+        "421614".to_string(),
+        "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx".to_string(),
+        400,
+        1625097600,
+        1625097600,
+    );
+
+    let reserves = atlas.get_reserves();
+    assert_eq!(reserves.deposited, 1000);
+    assert_eq!(reserves.btc_reserve, 1000);
+    assert_eq!(reserves.burned, 400);
+    assert_eq!(reserves.chains[0].chain_id, "421614");
+    assert_eq!(reserves.chains[0].circulating_supply, -400);
+}
+
+#[test]
+fn test_refund_reduces_btc_reserve() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(1));
+    atlas.deposits.insert(
+        "btc_txn_hash".to_string(),
+        deposit_record("btc_txn_hash", 1000, DEP_BTC_REFUNDING),
+    );
+    atlas.reserve_totals.deposited = 1000;
+
+    atlas.update_withdraw_fail_deposit_status("btc_txn_hash".to_string(), 1234567890);
+
+    let reserves = atlas.get_reserves();
+    assert_eq!(reserves.refunded, 1000);
+    assert_eq!(reserves.btc_reserve, 0);
+}
+
+#[test]
+fn test_rebuild_reserve_totals() {
+    let mut atlas = setup_atlas();
+
+    for (btc_txn_hash, status) in [
+        ("pending", DEP_BTC_PENDING_MEMPOOL),
+        ("deposited", DEP_BTC_DEPOSITED_INTO_ATLAS),
+        ("minted", DEP_BTC_MINTED_INTO_ABTC),
+        ("refunded", DEP_BTC_REFUNDED),
+    ] {
+        atlas
+            .deposits
+            .insert(btc_txn_hash.to_string(), deposit_record(btc_txn_hash, 1000, status));
+    }
+    for (txn_hash, status) in [
+        ("421614,burnt", RED_ABTC_BURNT),
+        ("421614,redeemed", RED_BTC_REDEEMED_BACK_TO_USER),
+    ] {
+        atlas
+            .redemptions
+            .insert(txn_hash.to_string(), redemption_record(txn_hash, 300, status));
+    }
+
+    atlas.rebuild_reserve_totals();
+
+    let reserves = atlas.get_reserves();
+    assert_eq!(reserves.deposited, 3000);
+    assert_eq!(reserves.redeemed, 300);
+    assert_eq!(reserves.refunded, 1000);
+    assert_eq!(reserves.btc_reserve, 1700);
+    assert_eq!(reserves.minted, 1000);
+    assert_eq!(reserves.burned, 600);
+    assert_eq!(reserves.expected_circulating_supply, 400);
+}
+
+#[test]
+#[should_panic(expected = "Only the owner can call this method")]
+fn test_rebuild_reserve_totals_by_non_owner() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(1));
+    atlas.rebuild_reserve_totals();
+}
+
+// Callbacks run as the contract itself
+fn set_contract_caller() {
+    let mut context = VMContextBuilder::new();
+    context
+        .current_account_id(accounts(5))
+        .predecessor_account_id(accounts(5));
+    testing_env!(context.build());
+}
+
+#[test]
+fn test_reconcile_near_supply_within_threshold() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(3));
+    atlas.set_supply_drift_threshold(100);
+    atlas.update_chain_reserve("NEAR_TESTNET", |reserve| reserve.minted = 5000);
+
+    set_contract_caller();
+    let drift = atlas.reconcile_near_supply_callback("NEAR_TESTNET".to_string(), Ok(U128(5050)));
+
+    assert_eq!(drift, Some(50));
+    assert!(atlas.get_paused_scopes().is_empty());
+}
+
+#[test]
+fn test_reconcile_near_supply_drift_pauses_minting() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(3));
+    atlas.set_supply_drift_threshold(100);
+    atlas.update_chain_reserve("NEAR_TESTNET", |reserve| reserve.minted = 5000);
+
+    set_contract_caller();
+    let drift = atlas.reconcile_near_supply_callback("NEAR_TESTNET".to_string(), Ok(U128(4800)));
+
+    assert_eq!(drift, Some(-200));
+    assert!(atlas.is_scope_paused("minting".to_string(), "NEAR_TESTNET".to_string()));
+    assert!(!atlas.is_scope_paused("minting".to_string(), "421614".to_string()));
+    assert_eq!(atlas.get_paused_scopes()[0].paused_by, accounts(5));
+}
+
+#[test]
+fn test_reconcile_near_supply_without_threshold() {
+    let mut atlas = setup_atlas();
+
+    set_contract_caller();
+    let drift = atlas.reconcile_near_supply_callback("NEAR_TESTNET".to_string(), Ok(U128(1000)));
+
+    assert_eq!(drift, Some(1000));
+    assert!(atlas.get_paused_scopes().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Caller does not have the PauseGuardian role")]
+fn test_reconcile_near_supply_without_role() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(1));
+    atlas.reconcile_near_supply();
+}
+
+fn attestation(block_height: u64) -> ReserveAttestationRecord {
+    ReserveAttestationRecord {
+        deposited: 3000,
+        redeemed: 300,
+        refunded: 1000,
+        minted: 1000,
+        burned: 600,
+        block_height,
+        timestamp: 1234567890,
+        digest: "0xdigest".to_string(),
+        signature: "".to_string(),
+    }
+}
+
+fn sign_result() -> SignResult {
+    SignResult {
+        big_r: AffinePoint {
+            affine_point: format!("02{}", "AB".repeat(32)),
+        },
+        s: Scalar {
+            scalar: "CD".repeat(32),
+        },
+        recovery_id: 1,
+    }
+}
+
+#[test]
+fn test_reserve_attestation_callback_stores_signature() {
+    let mut atlas = setup_atlas();
+    assert!(atlas.get_reserve_attestation().is_none());
+
+    set_contract_caller();
+    atlas.reserve_attestation_callback(attestation(100), Ok(sign_result()));
+
+    let stored = atlas.get_reserve_attestation().unwrap();
+    assert_eq!(stored.block_height, 100);
+    assert_eq!(
+        stored.signature,
+        format!("0x{}{}1c", "ab".repeat(32), "cd".repeat(32))
+    );
+}
+
+#[test]
+fn test_stale_reserve_attestation_is_ignored() {
+    let mut atlas = setup_atlas();
+
+    set_contract_caller();
+    atlas.reserve_attestation_callback(attestation(200), Ok(sign_result()));
+    assert!(atlas
+        .reserve_attestation_callback(attestation(100), Ok(sign_result()))
+        .is_none());
+
+    assert_eq!(atlas.get_reserve_attestation().unwrap().block_height, 200);
+}
+
+#[test]
+#[should_panic(expected = "Caller does not have the DepositOperator role")]
+fn test_create_reserve_attestation_without_role() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(3));
+    atlas.create_reserve_attestation();
+}
@@ -0,0 +1,184 @@
+use atlas_protocol::chain_configs::ChainConfigs;
+use atlas_protocol::global_params::OldGlobalParams;
+use atlas_protocol::modules::structs::{Atlas, OldAtlas};
+use near_sdk::store::IterableMap;
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::{env, testing_env, AccountId};
+
+fn set_caller(account_id: AccountId) {
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(account_id);
+    testing_env!(context.build());
+}
+
+fn setup_atlas() -> Atlas {
+    set_caller(accounts(0));
+
+    Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    )
+}
+
+#[test]
+fn test_default_roles() {
+    let atlas = setup_atlas();
+
+    assert_eq!(
+        atlas.get_roles(accounts(0)),
+        vec!["PauseGuardian".to_string(), "Upgrader".to_string()]
+    );
+    assert_eq!(
+        atlas.get_roles(accounts(1)),
+        vec![
+            "DepositOperator".to_string(),
+            "RedemptionOperator".to_string(),
+            "RefundOperator".to_string()
+        ]
+    );
+    assert!(atlas.has_role(accounts(2), "FeeManager".to_string()));
+    assert!(atlas.has_role(accounts(3), "ConfigManager".to_string()));
+    assert!(!atlas.has_role(accounts(3), "FeeManager".to_string()));
+    assert_eq!(
+        atlas.get_role_members("ConfigManager".to_string()),
+        vec![accounts(2), accounts(3)]
+    );
+}
+
+#[test]
+fn test_grant_role_allows_method() {
+    let mut atlas = setup_atlas();
+
+    atlas.grant_role(accounts(4), "PauseGuardian".to_string());
+    assert!(atlas.has_role(accounts(4), "PauseGuardian".to_string()));
+
+    set_caller(accounts(4));
+    atlas.pause();
+    assert!(atlas.is_paused());
+}
+
+#[test]
+#[should_panic(expected = "Caller does not have the PauseGuardian role")]
+fn test_revoke_role_blocks_method() {
+    let mut atlas = setup_atlas();
+
+    atlas.grant_role(accounts(4), "PauseGuardian".to_string());
+    atlas.revoke_role(accounts(4), "PauseGuardian".to_string());
+    assert!(atlas.get_roles(accounts(4)).is_empty());
+
+    set_caller(accounts(4));
+    atlas.pause();
+}
+
+#[test]
+fn test_fee_manager_role() {
+    let mut atlas = setup_atlas();
+
+    atlas.grant_role(accounts(5), "FeeManager".to_string());
+
+    set_caller(accounts(5));
+    atlas.update_fee_deposit_bps(100);
+    assert_eq!(
+        serde_json::to_value(atlas.get_all_global_params()).unwrap()["fee_deposit_bps"],
+        100
+    );
+}
+
+#[test]
+fn test_accepting_admin_transfers_roles() {
+    let mut atlas = setup_atlas();
+
+    atlas.propose_new_atlas_admin(accounts(4));
+    set_caller(accounts(4));
+    atlas.accept_atlas_admin();
+
+    assert!(atlas.get_roles(accounts(1)).is_empty());
+    assert!(atlas.has_role(accounts(4), "DepositOperator".to_string()));
+    assert!(atlas.has_role(accounts(4), "RefundOperator".to_string()));
+}
+
+#[test]
+#[should_panic(expected = "Only the owner can call this method")]
+fn test_grant_role_by_non_owner() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(1));
+    atlas.grant_role(accounts(4), "PauseGuardian".to_string());
+}
+
+#[test]
+#[should_panic(expected = "Invalid role")]
+fn test_grant_invalid_role() {
+    let mut atlas = setup_atlas();
+
+    atlas.grant_role(accounts(4), "Superuser".to_string());
+}
+
+#[test]
+#[should_panic(expected = "Account already has the role")]
+fn test_grant_role_twice() {
+    let mut atlas = setup_atlas();
+
+    atlas.grant_role(accounts(1), "DepositOperator".to_string());
+}
+
+#[test]
+fn test_migrate_from_old_layout_grants_default_roles() {
+    set_caller(accounts(0));
+
+    let mut old_state = OldAtlas {
+        deposits: IterableMap::new(b"d"),
+        redemptions: IterableMap::new(b"r"),
+        validators: IterableMap::new(b"v"),
+        verifications: IterableMap::new(b"f"),
+        owner_id: accounts(0),
+        proposed_owner_id: None,
+        admin_id: accounts(1),
+        proposed_admin_id: None,
+        global_params: OldGlobalParams {
+            mpc_contract: "v1.signer-prod.testnet".parse().unwrap(),
+            fee_deposit_bps: 25,
+            fee_redemption_bps: 30,
+            fee_bridging_bps: 0,
+            fee_babylon_rewards_bps: 0,
+            btc_staking_cap: 50000000000,
+            btc_max_staking_amount: 5000000,
+            btc_min_staking_amount: 2000,
+            treasury_address: "treasury_address".to_string(),
+            owner_id: accounts(2),
+            proposed_owner_id: None,
+            max_retry_count: 3,
+        },
+        chain_configs: ChainConfigs::init_chain_configs(accounts(3)),
+        last_evm_tx: None,
+        paused: false,
+        production_mode: false,
+    };
+    old_state
+        .validators
+        .insert(accounts(4), vec!["SIGNET".to_string()]);
+    env::state_write(&old_state);
+    drop(old_state);
+
+    let atlas = Atlas::migrate();
+
+    assert_eq!(atlas.get_atlas_owner_id(), accounts(0));
+    assert_eq!(atlas.validators.get(&accounts(4)), Some(&vec!["SIGNET".to_string()]));
+    assert!(atlas.get_chain_config("SIGNET".to_string()).is_some());
+    let global_params = atlas.get_all_global_params();
+    assert_eq!(global_params.get_fee_redemption_bps(), 30);
+    assert_eq!(global_params.get_fee_validator_rewards_bps(), 0);
+    assert_eq!(global_params.get_btc_atlas_deposit_address(), "");
+
+    assert_eq!(
+        atlas.get_roles(accounts(0)),
+        vec!["PauseGuardian".to_string(), "Upgrader".to_string()]
+    );
+    assert!(atlas.has_role(accounts(1), "DepositOperator".to_string()));
+    assert!(atlas.has_role(accounts(2), "FeeManager".to_string()));
+    assert!(atlas.has_role(accounts(3), "ConfigManager".to_string()));
+}
@@ -0,0 +1,224 @@
+use atlas_protocol::constants::status::*;
+use atlas_protocol::modules::structs::Atlas;
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::{env, testing_env, AccountId};
+
+const NANOS: u64 = 1_000_000_000;
+const START: u64 = 1_700_000_000;
+const DELAY: u64 = 3600;
+
+fn set_caller_at(account_id: AccountId, timestamp: u64) {
+    let mut context = VMContextBuilder::new();
+    context
+        .predecessor_account_id(account_id)
+        .block_timestamp(timestamp * NANOS);
+    testing_env!(context.build());
+}
+
+fn setup_atlas() -> Atlas {
+    set_caller_at(accounts(0), START);
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    atlas.set_operation_delay("update_fee_redemption_bps".to_string(), DELAY);
+
+    atlas
+}
+
+#[test]
+fn test_schedule_and_execute() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(2), START + 10);
+    let operation_id =
+        atlas.schedule_operation("update_fee_redemption_bps".to_string(), "100".to_string());
+
+    let pending = atlas.get_pending_operations();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].eta, START + 10 + DELAY);
+
+    set_caller_at(accounts(2), START + 10 + DELAY);
+    atlas.execute_operation(operation_id);
+
+    assert_eq!(atlas.get_all_global_params().get_fee_redemption_bps(), 100);
+    assert_eq!(
+        atlas.get_scheduled_operation(operation_id).unwrap().status,
+        OPERATION_EXECUTED
+    );
+    assert!(atlas.get_pending_operations().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Operation delay has not passed")]
+fn test_execute_before_eta() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(2), START + 10);
+    let operation_id =
+        atlas.schedule_operation("update_fee_redemption_bps".to_string(), "100".to_string());
+
+    set_caller_at(accounts(2), START + 10 + DELAY - 1);
+    atlas.execute_operation(operation_id);
+}
+
+#[test]
+#[should_panic(expected = "Operation is not scheduled")]
+fn test_cancel_operation() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(2), START + 10);
+    let operation_id =
+        atlas.schedule_operation("update_fee_redemption_bps".to_string(), "100".to_string());
+    atlas.cancel_operation(operation_id);
+
+    assert_eq!(
+        atlas.get_scheduled_operation(operation_id).unwrap().status,
+        OPERATION_CANCELLED
+    );
+
+    set_caller_at(accounts(2), START + 10 + DELAY);
+    atlas.execute_operation(operation_id);
+}
+
+#[test]
+#[should_panic(expected = "Operation is timelocked and has to be scheduled")]
+fn test_direct_call_blocked() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(2), START + 10);
+    atlas.update_fee_redemption_bps(100);
+}
+
+#[test]
+fn test_direct_call_without_delay() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(2), START + 10);
+    atlas.update_fee_deposit_bps(100);
+}
+
+#[test]
+#[should_panic(expected = "Caller does not have the ConfigManager role")]
+fn test_schedule_without_role() {
+    let mut atlas = setup_atlas();
+
+    // Chain configs need the ConfigManager role, the admin only holds operator roles
+    set_caller_at(accounts(1), START + 10);
+    atlas.schedule_operation(
+        "set_chain_configs".to_string(),
+        r#"{"chains":[]}"#.to_string(),
+    );
+}
+
+#[test]
+fn test_lowering_delay_is_scheduled() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(0), START + 10);
+    let operation_id = atlas.schedule_operation(
+        "set_operation_delay".to_string(),
+        "update_fee_redemption_bps,0".to_string(),
+    );
+    assert_eq!(
+        atlas.get_scheduled_operation(operation_id).unwrap().eta,
+        START + 10 + DELAY
+    );
+
+    set_caller_at(accounts(0), START + 10 + DELAY);
+    atlas.execute_operation(operation_id);
+    assert_eq!(
+        atlas.get_operation_delay("update_fee_redemption_bps".to_string()),
+        0
+    );
+}
+
+#[test]
+#[should_panic(expected = "Lowering an operation delay has to be scheduled")]
+fn test_lowering_delay_directly() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(0), START + 10);
+    atlas.set_operation_delay("update_fee_redemption_bps".to_string(), 0);
+}
+
+#[test]
+fn test_scheduled_contract_update() {
+    let mut atlas = setup_atlas();
+    let code = vec![0u8; 1024];
+
+    set_caller_at(accounts(0), START + 10);
+    atlas.set_operation_delay("update_contract".to_string(), DELAY);
+    let operation_id =
+        atlas.schedule_operation("update_contract".to_string(), hex::encode(env::sha256(&code)));
+
+    let mut context = VMContextBuilder::new();
+    context
+        .predecessor_account_id(accounts(0))
+        .block_timestamp((START + 10 + DELAY) * NANOS);
+    let mut ctx = context.build();
+    ctx.input = code;
+    testing_env!(ctx);
+
+    atlas.update_contract();
+    assert_eq!(
+        atlas.get_scheduled_operation(operation_id).unwrap().status,
+        OPERATION_EXECUTED
+    );
+}
+
+#[test]
+#[should_panic(expected = "Contract update has not been scheduled")]
+fn test_unscheduled_contract_update() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(0), START + 10);
+    atlas.set_operation_delay("update_contract".to_string(), DELAY);
+
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(accounts(0));
+    let mut ctx = context.build();
+    ctx.input = vec![0u8; 1024];
+    testing_env!(ctx);
+
+    atlas.update_contract();
+}
+
+#[test]
+fn test_scheduled_deposit_address_update() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(0), START);
+    atlas.set_operation_delay("update_btc_atlas_deposit_address".to_string(), DELAY);
+
+    set_caller_at(accounts(3), START + 10);
+    let operation_id = atlas.schedule_operation(
+        "update_btc_atlas_deposit_address".to_string(),
+        "tb1qnewdepositaddress".to_string(),
+    );
+
+    set_caller_at(accounts(3), START + 10 + DELAY);
+    atlas.execute_operation(operation_id);
+    assert_eq!(
+        atlas.get_all_global_params().get_btc_atlas_deposit_address(),
+        "tb1qnewdepositaddress"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Operation is timelocked and has to be scheduled")]
+fn test_direct_treasury_address_update_blocked() {
+    let mut atlas = setup_atlas();
+
+    set_caller_at(accounts(0), START);
+    atlas.set_operation_delay("update_treasury_address".to_string(), DELAY);
+
+    set_caller_at(accounts(3), START + 10);
+    atlas.update_treasury_address("new_treasury_address".to_string());
+}
@@ -93,12 +93,25 @@ fn setup_atlas() -> Atlas {
     atlas.add_validator(accounts(2), "421614".to_string());
     atlas.add_validator(accounts(1), "NEAR_TESTNET".to_string());
     atlas.add_validator(accounts(2), "NEAR_TESTNET".to_string());
+    testing_env!(VMContextBuilder::new()
+        .predecessor_account_id(accounts(2))
+        .build());
+    atlas.verify_evm_nonce("421614".to_string(), 0);
     testing_env!(VMContextBuilder::new()
         .predecessor_account_id(accounts(1))
         .build());
+    atlas.verify_evm_nonce("421614".to_string(), 0);
     atlas
 }
 
+// The MPC callback does not run in unit tests, record the hash of the signed mint attempt it would have kept
+fn record_signed_mint(atlas: &mut Atlas, btc_txn_hash: &str, tx_hash: &str) {
+    let mut attempts = atlas.get_mint_attempts(btc_txn_hash.to_string());
+    let attempt = attempts.last_mut().unwrap();
+    attempt.tx_hash = tx_hash.to_string();
+    atlas.mint_attempts.insert(btc_txn_hash.to_string(), attempts);
+}
+
 #[tokio::test]
 async fn test_update_deposit_btc_deposited() {
     let mut atlas = setup_atlas();
@@ -178,10 +191,11 @@ async fn test_update_deposit_minted() {
         .predecessor_account_id(accounts(1))
         .block_timestamp(1234567001 * 1_000_000_000)
         .build());
-    atlas.create_mint_abtc_signed_tx(deposit.btc_txn_hash.clone(), 94, 5000000, 100000000, 0);
+    atlas.create_mint_abtc_signed_tx(deposit.btc_txn_hash.clone(), 5000000, Some(100000000), Some(0));
 
     // 5. Update minted txn hash
     let minted_txn_hash = "0x511d02e4a7dc5319a339050a405f40a6ff17ad68dce7f9cb0e3d0cf549c6acbf".to_string();
+    record_signed_mint(&mut atlas, &btc_txn_hash, &minted_txn_hash);
     testing_env!(VMContextBuilder::new()
         .predecessor_account_id(accounts(1))
         .build());
@@ -272,10 +286,11 @@ async fn test_update_deposit_multiple_times() {
         .predecessor_account_id(accounts(1))
         .block_timestamp(1234567001 * 1_000_000_000)
         .build());
-    atlas.create_mint_abtc_signed_tx(deposit.btc_txn_hash.clone(), 94, 5000000, 100000000, 0);
+    atlas.create_mint_abtc_signed_tx(deposit.btc_txn_hash.clone(), 5000000, Some(100000000), Some(0));
 
     // 5. Update minted txn hash
     let minted_txn_hash = "0x511d02e4a7dc5319a339050a405f40a6ff17ad68dce7f9cb0e3d0cf549c6acbf".to_string();
+    record_signed_mint(&mut atlas, &btc_txn_hash, &minted_txn_hash);
     testing_env!(VMContextBuilder::new()
         .predecessor_account_id(accounts(1))
         .build());
@@ -320,7 +335,7 @@ async fn test_update_deposit_multiple_times() {
 }
 
 #[tokio::test]
-#[should_panic(expected = "Only the admin can call this method")]
+#[should_panic(expected = "Caller does not have the DepositOperator role")]
 async fn test_unauthorized_update_deposit() {
     let mut atlas = setup_atlas();
     let btc_txn_hash = "btc_txn_hash".to_string();
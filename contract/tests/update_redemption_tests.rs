@@ -82,7 +82,7 @@ async fn test_update_redemption_start() {
 // For panic tests, update them like this:
 
 #[tokio::test]
-#[should_panic(expected = "Only the admin can call this method")]
+#[should_panic(expected = "Caller does not have the RedemptionOperator role")]
 async fn test_unauthorized_update_redemption() {
     let mut atlas = setup_atlas();
     let txn_hash = insert_test_redemption(&mut atlas);
@@ -172,7 +172,7 @@ async fn test_update_redemption_remarks() {
 }
 
 #[tokio::test]
-#[should_panic(expected = "Only the admin can call this method")]
+#[should_panic(expected = "Caller does not have the RedemptionOperator role")]
 async fn test_unauthorized_update_redemption_start() {
     let mut atlas = setup_atlas();
     let txn_hash = insert_test_redemption(&mut atlas);
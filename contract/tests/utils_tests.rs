@@ -44,7 +44,7 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "Only the owner can call this method")]
+    #[should_panic(expected = "Caller does not have the FeeManager role")]
     async fn test_update_fee_deposit_bps_non_owner() {
         let (mut atlas, _) = setup_atlas();
         let new_fee = 200; // 2%
@@ -150,7 +150,7 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "Only the owner can call this method")]
+    #[should_panic(expected = "Caller does not have the FeeManager role")]
     async fn test_update_fee_bridging_bps_non_owner() {
         let (mut atlas, _) = setup_atlas();
         let new_fee = 150; // 1.5%
@@ -182,7 +182,7 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "Only the owner can call this method")]
+    #[should_panic(expected = "Caller does not have the FeeManager role")]
     async fn test_update_fee_babylon_rewards_bps_non_owner() {
         let (mut atlas, _) = setup_atlas();
         let new_fee = 50; // 0.5%
@@ -212,7 +212,7 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "Only the owner can call this method")]
+    #[should_panic(expected = "Caller does not have the ConfigManager role")]
     async fn test_update_btc_staking_cap_non_owner() {
         let (mut atlas, _) = setup_atlas();
         let new_cap = 1000000; // 10 BTC
@@ -244,7 +244,7 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "Only the owner can call this method")]
+    #[should_panic(expected = "Caller does not have the ConfigManager role")]
     async fn test_update_btc_max_staking_amount_non_owner() {
         let (mut atlas, _) = setup_atlas();
         let new_max = 500000; // 5 BTC
@@ -276,7 +276,7 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "Only the owner can call this method")]
+    #[should_panic(expected = "Caller does not have the ConfigManager role")]
     async fn test_update_btc_min_staking_amount_non_owner() {
         let (mut atlas, _) = setup_atlas();
         let new_min = 10000; // 0.1 BTC
@@ -306,7 +306,7 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "Only the owner can call this method")]
+    #[should_panic(expected = "Caller does not have the ConfigManager role")]
     async fn test_update_treasury_address_non_owner() {
         let (mut atlas, _) = setup_atlas();
         let new_address = "new_treasury_address".to_string();
@@ -334,7 +334,7 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "Only the owner can call this method")]
+    #[should_panic(expected = "Caller does not have the ConfigManager role")]
     async fn test_update_max_retry_count_non_owner() {
         let (mut atlas, _) = setup_atlas();
 
@@ -468,7 +468,7 @@ mod tests {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "Only the owner can call this method")]
+    #[should_panic(expected = "Caller does not have the ConfigManager role")]
     async fn test_set_mpc_contract_unauthorized() {
         let (mut atlas, _) = setup_atlas();
         let new_mpc_contract = AccountId::new_unvalidated("new_mpc.testnet".to_string());
@@ -0,0 +1,290 @@
+use atlas_protocol::modules::structs::Atlas;
+use bitcoin::blockdata::opcodes::all::OP_RETURN;
+use bitcoin::blockdata::script::{Builder, Script};
+use bitcoin::blockdata::transaction::{OutPoint, Transaction, TxIn, TxOut};
+use bitcoin::consensus::encode::serialize;
+use bitcoin::hashes::{sha256d, Hash};
+use bitcoin::network::constants::Network;
+use bitcoin::util::address::Address;
+use bitcoin::util::key::PublicKey;
+use near_sdk::json_types::U128;
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::{testing_env, AccountId, NearToken, PromiseError, PromiseOrValue};
+use std::str::FromStr;
+
+const ONE_NEAR: u128 = 1_000_000_000_000_000_000_000_000;
+const SENDER_PUBKEY: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+const DEPOSIT_PUBKEY: &str = "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5";
+const SIGNET_BITS: u32 = 0x1e0377ae;
+const NEAR_ABTC: &str = "atbtc_audit_v2.velar.testnet";
+
+fn set_caller(account_index: usize) {
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(accounts(account_index));
+    testing_env!(context.build());
+}
+
+fn bond_near(atlas: &mut Atlas, account_index: usize, amount: u128) {
+    let mut context = VMContextBuilder::new();
+    context
+        .predecessor_account_id(accounts(account_index))
+        .attached_deposit(NearToken::from_yoctonear(amount));
+    testing_env!(context.build());
+    atlas.bond_validator_near();
+}
+
+fn setup_atlas() -> Atlas {
+    set_caller(0);
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    atlas.add_validator(accounts(4), "SIGNET".to_string());
+    atlas.set_validator_bond_params(U128(10 * ONE_NEAR), U128(0), 3600, 5000);
+
+    atlas
+}
+
+#[test]
+fn test_validator_requires_bond() {
+    let mut atlas = setup_atlas();
+
+    assert!(!atlas.is_validator(&accounts(4), &"SIGNET".to_string()));
+
+    bond_near(&mut atlas, 4, 10 * ONE_NEAR);
+
+    assert!(atlas.is_validator(&accounts(4), &"SIGNET".to_string()));
+    assert_eq!(
+        atlas.get_validator_bond(accounts(4)).unwrap().near_amount,
+        U128(10 * ONE_NEAR)
+    );
+
+    // Unbonding below the minimum removes eligibility right away
+    set_caller(4);
+    atlas.request_validator_unbond(U128(ONE_NEAR), U128(0));
+    assert!(!atlas.is_validator(&accounts(4), &"SIGNET".to_string()));
+
+    let bond = atlas.get_validator_bond(accounts(4)).unwrap();
+    assert_eq!(bond.near_amount, U128(9 * ONE_NEAR));
+    assert_eq!(bond.unbonding_near_amount, U128(ONE_NEAR));
+    assert_eq!(bond.unbonding_available_at, 3600);
+}
+
+#[test]
+#[should_panic(expected = "Unbonding period has not ended")]
+fn test_withdraw_validator_unbonded_too_early() {
+    let mut atlas = setup_atlas();
+    bond_near(&mut atlas, 4, 10 * ONE_NEAR);
+
+    set_caller(4);
+    atlas.request_validator_unbond(U128(ONE_NEAR), U128(0));
+    atlas.withdraw_validator_unbonded();
+}
+
+#[test]
+#[should_panic(expected = "Only a validator can bond")]
+fn test_bond_validator_near_not_validator() {
+    let mut atlas = setup_atlas();
+    bond_near(&mut atlas, 5, 10 * ONE_NEAR);
+}
+
+fn deposit_address() -> Address {
+    Address::p2wpkh(&PublicKey::from_str(DEPOSIT_PUBKEY).unwrap(), Network::Signet).unwrap()
+}
+
+fn build_deposit_tx() -> Transaction {
+    Transaction {
+        version: 2,
+        lock_time: 0,
+        input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: Script::new(),
+            sequence: 0xFFFFFFFF,
+            witness: vec![vec![0x30; 71], hex::decode(SENDER_PUBKEY).unwrap()],
+        }],
+        output: vec![
+            TxOut {
+                value: 25000,
+                script_pubkey: deposit_address().script_pubkey(),
+            },
+            TxOut {
+                value: 0,
+                script_pubkey: Builder::new()
+                    .push_opcode(OP_RETURN)
+                    .push_slice("NEAR_TESTNET,user.testnet".as_bytes())
+                    .into_script(),
+            },
+        ],
+    }
+}
+
+// Anchors the deposit transaction in a one-confirmation checkpoint next to one sibling
+// Returns (raw_tx_hex, block_hash, sibling)
+fn setup_deposit_in_checkpoint(atlas: &mut Atlas) -> (String, String, String) {
+    let tx = build_deposit_tx();
+    let txid = tx.txid().into_inner();
+    let sibling = [7u8; 32];
+
+    let mut concat = txid.to_vec();
+    concat.extend_from_slice(&sibling);
+    let merkle_root = sha256d::Hash::hash(&concat).into_inner();
+
+    let mut header = Vec::with_capacity(80);
+    header.extend_from_slice(&2u32.to_le_bytes());
+    header.extend_from_slice(&[0u8; 32]);
+    header.extend_from_slice(&merkle_root);
+    header.extend_from_slice(&1700000000u32.to_le_bytes());
+    header.extend_from_slice(&SIGNET_BITS.to_le_bytes());
+    header.extend_from_slice(&0u32.to_le_bytes());
+    let mut block_hash = sha256d::Hash::hash(&header).into_inner().to_vec();
+    block_hash.reverse();
+    let mut sibling_hex = sibling.to_vec();
+    sibling_hex.reverse();
+
+    set_caller(2);
+    atlas.update_btc_atlas_deposit_address(deposit_address().to_string());
+    set_caller(0);
+    atlas.init_btc_light_client(hex::encode(header), 201600);
+    atlas.set_btc_confirmation_depth(1);
+
+    (
+        hex::encode(serialize(&tx)),
+        hex::encode(block_hash),
+        hex::encode(sibling_hex),
+    )
+}
+
+fn insert_deposit(atlas: &mut Atlas, btc_amount: u64) -> String {
+    let btc_txn_hash = build_deposit_tx().txid().to_string();
+
+    set_caller(1);
+    atlas.insert_deposit_btc(
+        btc_txn_hash.clone(),
+        Address::p2wpkh(&PublicKey::from_str(SENDER_PUBKEY).unwrap(), Network::Signet)
+            .unwrap()
+            .to_string(),
+        "NEAR_TESTNET".to_string(),
+        "user.testnet".to_string(),
+        btc_amount,
+        0,
+        "".to_string(),
+        1234567890,
+        "".to_string(),
+        1234567890,
+    );
+    atlas.update_deposit_btc_deposited(btc_txn_hash.clone(), 1234567890);
+
+    // The bonded validator verifies the record through the public vote path
+    let deposit = atlas.get_deposit_by_btc_txn_hash(btc_txn_hash.clone()).unwrap();
+    set_caller(4);
+    assert!(atlas.increment_deposit_verified_count(deposit));
+
+    btc_txn_hash
+}
+
+#[test]
+fn test_challenge_disproven_deposit_vote() {
+    let mut atlas = setup_atlas();
+    bond_near(&mut atlas, 4, 10 * ONE_NEAR);
+    let (raw_tx_hex, block_hash, sibling) = setup_deposit_in_checkpoint(&mut atlas);
+
+    // The validator verified a record claiming more than the transaction pays
+    insert_deposit(&mut atlas, 50000);
+
+    set_caller(5);
+    atlas.challenge_disproven_deposit_vote(accounts(4), raw_tx_hex, block_hash, vec![sibling], 0);
+
+    assert!(atlas.get_validator_bond(accounts(4)).is_none());
+    assert!(!atlas.is_validator(&accounts(4), &"SIGNET".to_string()));
+}
+
+#[test]
+#[should_panic(expected = "Deposit record matches the proven transaction")]
+fn test_challenge_disproven_deposit_vote_on_valid_record() {
+    let mut atlas = setup_atlas();
+    bond_near(&mut atlas, 4, 10 * ONE_NEAR);
+    let (raw_tx_hex, block_hash, sibling) = setup_deposit_in_checkpoint(&mut atlas);
+
+    insert_deposit(&mut atlas, 25000);
+
+    set_caller(5);
+    atlas.challenge_disproven_deposit_vote(accounts(4), raw_tx_hex, block_hash, vec![sibling], 0);
+}
+
+#[test]
+#[should_panic(expected = "Merkle proof does not match the block's merkle root")]
+fn test_challenge_disproven_deposit_vote_invalid_proof() {
+    let mut atlas = setup_atlas();
+    bond_near(&mut atlas, 4, 10 * ONE_NEAR);
+    let (raw_tx_hex, block_hash, sibling) = setup_deposit_in_checkpoint(&mut atlas);
+
+    insert_deposit(&mut atlas, 50000);
+
+    set_caller(5);
+    atlas.challenge_disproven_deposit_vote(accounts(4), raw_tx_hex, block_hash, vec![sibling], 1);
+}
+
+#[test]
+#[should_panic(expected = "Validator cannot challenge its own votes")]
+fn test_challenge_own_vote() {
+    let mut atlas = setup_atlas();
+    bond_near(&mut atlas, 4, 10 * ONE_NEAR);
+    let (raw_tx_hex, block_hash, sibling) = setup_deposit_in_checkpoint(&mut atlas);
+
+    insert_deposit(&mut atlas, 50000);
+
+    set_caller(4);
+    atlas.challenge_disproven_deposit_vote(accounts(4), raw_tx_hex, block_hash, vec![sibling], 0);
+}
+
+fn abtc_transfer(atlas: &mut Atlas, msg: &str) -> PromiseOrValue<U128> {
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(NEAR_ABTC.parse::<AccountId>().unwrap());
+    testing_env!(context.build());
+
+    atlas.ft_on_transfer(accounts(4), U128(1000), msg.to_string())
+}
+
+#[test]
+fn test_ft_on_transfer_requires_bond_msg() {
+    let mut atlas = setup_atlas();
+
+    match abtc_transfer(&mut atlas, "") {
+        PromiseOrValue::Value(refund) => assert_eq!(refund, U128(1000)),
+        PromiseOrValue::Promise(_) => panic!("Expected a refund"),
+    }
+    assert!(atlas.get_validator_bond(accounts(4)).is_none());
+
+    match abtc_transfer(&mut atlas, "bond") {
+        PromiseOrValue::Value(refund) => assert_eq!(refund, U128(0)),
+        PromiseOrValue::Promise(_) => panic!("Expected the transfer to be kept"),
+    }
+    assert_eq!(
+        atlas.get_validator_bond(accounts(4)).unwrap().abtc_amount,
+        U128(1000)
+    );
+}
+
+#[test]
+fn test_resolve_bond_transfer_restores_failed_transfer() {
+    let mut atlas = setup_atlas();
+
+    set_caller(0);
+    atlas.resolve_bond_transfer(accounts(5), U128(1000), Err(PromiseError::Failed));
+
+    let bond = atlas.get_validator_bond(accounts(5)).unwrap();
+    assert_eq!(bond.unbonding_abtc_amount, U128(1000));
+    assert_eq!(bond.abtc_amount, U128(0));
+
+    atlas.resolve_bond_transfer(accounts(5), U128(1000), Ok(()));
+    assert_eq!(
+        atlas.get_validator_bond(accounts(5)).unwrap().unbonding_abtc_amount,
+        U128(1000)
+    );
+}
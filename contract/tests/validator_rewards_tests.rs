@@ -0,0 +1,286 @@
+use atlas_protocol::constants::status::*;
+use atlas_protocol::modules::structs::{Atlas, DepositRecord};
+use near_sdk::json_types::U128;
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::{testing_env, AccountId, PromiseError, PromiseOrValue};
+
+const BTC_TXN_HASH: &str = "btc_txn_hash";
+const MINTED_TXN_HASH: &str = "0xminted_txn_hash";
+const NEAR_ABTC: &str = "atbtc_audit_v2.velar.testnet";
+
+fn set_caller(account_id: AccountId) {
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(account_id);
+    testing_env!(context.build());
+}
+
+fn validator(name: &str) -> AccountId {
+    name.parse().unwrap()
+}
+
+fn deposit_record() -> DepositRecord {
+    DepositRecord {
+        btc_txn_hash: BTC_TXN_HASH.to_string(),
+        btc_sender_address: "tb1qsender".to_string(),
+        receiving_chain_id: "421614".to_string(),
+        receiving_address: "0xf8b5b7c7c8f6a1b1f1f1f1f1f1f1f1f1f1f1f1f1".to_string(),
+        btc_amount: 100000,
+        fee_amount: 1000,
+        minted_txn_hash: MINTED_TXN_HASH.to_string(),
+        timestamp: 1234567890,
+        status: DEP_BTC_PENDING_MINTED_INTO_ABTC,
+        remarks: "".to_string(),
+        date_created: 1234567890,
+        verified_count: 3,
+        retry_count: 0,
+        minted_txn_hash_verified_count: 2,
+        custody_txn_id: "".to_string(),
+    }
+}
+
+// Three SIGNET validators confirmed the deposit and two 421614 validators its minted txn hash, both thresholds are 2
+fn setup_atlas() -> Atlas {
+    set_caller(accounts(0));
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    atlas.deposits.insert(BTC_TXN_HASH.to_string(), deposit_record());
+    atlas.verifications.insert(
+        BTC_TXN_HASH.to_string(),
+        vec![accounts(4), accounts(5), validator("grace")],
+    );
+    atlas.verifications.insert(
+        format!("{},{}", BTC_TXN_HASH, MINTED_TXN_HASH),
+        vec![validator("henry"), validator("ivan")],
+    );
+
+    set_caller(accounts(2));
+    atlas.update_fee_validator_rewards_bps(5000);
+
+    atlas
+}
+
+#[test]
+fn test_rewards_credited_to_useful_votes() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(1));
+    atlas.update_deposit_minted(BTC_TXN_HASH.to_string(), MINTED_TXN_HASH.to_string());
+
+    // 50% of the 1000 fee split across the two votes needed on each chain
+    for account_id in [accounts(4), accounts(5), validator("henry"), validator("ivan")] {
+        assert_eq!(atlas.get_validator_rewards(account_id), 125);
+    }
+    // The third SIGNET vote was not needed to reach the threshold
+    assert_eq!(atlas.get_validator_rewards(validator("grace")), 0);
+
+    let pool = atlas.get_reward_pool();
+    assert_eq!(pool.total_accrued, 500);
+    assert_eq!(pool.total_credited, 500);
+    assert_eq!(pool.total_claimed, 0);
+}
+
+#[test]
+fn test_no_rewards_without_fee_share() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(2));
+    atlas.update_fee_validator_rewards_bps(0);
+
+    set_caller(accounts(1));
+    atlas.update_deposit_minted(BTC_TXN_HASH.to_string(), MINTED_TXN_HASH.to_string());
+
+    assert_eq!(atlas.get_validator_rewards(accounts(4)), 0);
+    assert_eq!(atlas.get_reward_pool().total_accrued, 0);
+}
+
+fn fund_reward_pool(atlas: &mut Atlas, amount: u128) {
+    set_caller(NEAR_ABTC.parse().unwrap());
+    match atlas.ft_on_transfer(accounts(2), U128(amount), "rewards".to_string()) {
+        PromiseOrValue::Value(refund) => assert_eq!(refund, U128(0)),
+        PromiseOrValue::Promise(_) => panic!("Expected the funding to be kept"),
+    }
+}
+
+#[test]
+fn test_fund_reward_pool() {
+    let mut atlas = setup_atlas();
+
+    fund_reward_pool(&mut atlas, 1000);
+    fund_reward_pool(&mut atlas, 500);
+
+    assert_eq!(atlas.get_reward_pool().total_funded, 1500);
+    // Funding is not a bond
+    assert!(atlas.get_validator_bond(accounts(2)).is_none());
+}
+
+#[test]
+fn test_claim_validator_rewards_near() {
+    let mut atlas = setup_atlas();
+    fund_reward_pool(&mut atlas, 1000);
+
+    set_caller(accounts(1));
+    atlas.update_deposit_minted(BTC_TXN_HASH.to_string(), MINTED_TXN_HASH.to_string());
+
+    let minted = atlas.get_reserves().minted;
+    set_caller(accounts(4));
+    atlas.claim_validator_rewards_near(accounts(4));
+
+    let claim = atlas.get_reward_claim("REWARD,1".to_string()).unwrap();
+    assert_eq!(claim.account_id, accounts(4));
+    assert_eq!(claim.amount, 125);
+    assert_eq!(claim.status, REWARD_CLAIM_PENDING);
+    assert_eq!(atlas.get_validator_rewards(accounts(4)), 0);
+    assert_eq!(atlas.get_reward_pool().total_claimed, 125);
+
+    set_caller(accounts(0));
+    atlas.reward_claim_callback("REWARD,1".to_string(), Ok(()));
+    assert_eq!(
+        atlas.get_reward_claim("REWARD,1".to_string()).unwrap().status,
+        REWARD_CLAIM_COMPLETED
+    );
+    // Paying a claim moves funded atBTC, it does not change the supply
+    assert_eq!(atlas.get_reserves().minted, minted);
+}
+
+#[test]
+fn test_failed_reward_claim_is_credited_back() {
+    let mut atlas = setup_atlas();
+    fund_reward_pool(&mut atlas, 1000);
+
+    set_caller(accounts(1));
+    atlas.update_deposit_minted(BTC_TXN_HASH.to_string(), MINTED_TXN_HASH.to_string());
+
+    set_caller(accounts(4));
+    atlas.claim_validator_rewards_near(accounts(4));
+
+    set_caller(accounts(0));
+    atlas.reward_claim_callback("REWARD,1".to_string(), Err(PromiseError::Failed));
+
+    assert_eq!(
+        atlas.get_reward_claim("REWARD,1".to_string()).unwrap().status,
+        REWARD_CLAIM_FAILED
+    );
+    assert_eq!(atlas.get_validator_rewards(accounts(4)), 125);
+    assert_eq!(atlas.get_reward_pool().total_claimed, 0);
+}
+
+// 421614 validators verify the signer's nonce so EVM claims can be signed
+fn verify_evm_nonce(atlas: &mut Atlas) {
+    set_caller(accounts(0));
+    atlas.add_validator(validator("judy"), "421614".to_string());
+    atlas.add_validator(validator("kate"), "421614".to_string());
+    for account_id in [validator("judy"), validator("kate")] {
+        set_caller(account_id);
+        atlas.verify_evm_nonce("421614".to_string(), 0);
+    }
+}
+
+#[test]
+fn test_claim_validator_rewards_evm() {
+    let mut atlas = setup_atlas();
+    fund_reward_pool(&mut atlas, 1000);
+    verify_evm_nonce(&mut atlas);
+
+    set_caller(accounts(1));
+    atlas.update_deposit_minted(BTC_TXN_HASH.to_string(), MINTED_TXN_HASH.to_string());
+
+    set_caller(validator("henry"));
+    let claim_id = atlas.claim_validator_rewards_evm(
+        "421614".to_string(),
+        "0x2564186c643B292d6A4215f5C33Aa69b213414dd".to_string(),
+    );
+
+    let claim = atlas.get_reward_claim(claim_id.clone()).unwrap();
+    assert_eq!(claim.amount, 125);
+    assert_eq!(claim.status, REWARD_CLAIM_PENDING);
+    assert_eq!(atlas.get_validator_rewards(validator("henry")), 0);
+    assert_eq!(atlas.get_reward_pool().total_claimed, 125);
+
+    let minted = atlas.get_reserves().minted;
+    set_caller(accounts(1));
+    assert!(matches!(
+        atlas.create_reward_claim_signed_tx(claim_id.clone(), 100000, Some(1), Some(1)),
+        PromiseOrValue::Promise(_)
+    ));
+
+    assert_eq!(atlas.get_reward_claim(claim_id.clone()).unwrap().status, REWARD_CLAIM_SIGNED);
+    // The transfer takes the nonce of the signer, and pays out funded atBTC without minting any
+    let pending = atlas.get_pending_evm_nonces("421614".to_string());
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].record_key, claim_id);
+    assert_eq!(atlas.get_reserves().minted, minted);
+}
+
+#[test]
+#[should_panic(expected = "Reward claim is not pending")]
+fn test_reward_claim_signed_twice() {
+    let mut atlas = setup_atlas();
+    fund_reward_pool(&mut atlas, 1000);
+    verify_evm_nonce(&mut atlas);
+
+    set_caller(accounts(1));
+    atlas.update_deposit_minted(BTC_TXN_HASH.to_string(), MINTED_TXN_HASH.to_string());
+
+    set_caller(validator("henry"));
+    let claim_id = atlas.claim_validator_rewards_evm(
+        "421614".to_string(),
+        "0x2564186c643B292d6A4215f5C33Aa69b213414dd".to_string(),
+    );
+
+    set_caller(accounts(1));
+    atlas.create_reward_claim_signed_tx(claim_id.clone(), 100000, Some(1), Some(1));
+    atlas.create_reward_claim_signed_tx(claim_id, 100000, Some(1), Some(1));
+}
+
+#[test]
+#[should_panic(expected = "Invalid EVM receiving address")]
+fn test_claim_validator_rewards_evm_invalid_address() {
+    let mut atlas = setup_atlas();
+
+    set_caller(validator("henry"));
+    atlas.claim_validator_rewards_evm("421614".to_string(), "not_an_address".to_string());
+}
+
+#[test]
+#[should_panic(expected = "Reward pool is not funded enough to pay the claim")]
+fn test_claim_exceeding_funded_rewards() {
+    let mut atlas = setup_atlas();
+    fund_reward_pool(&mut atlas, 200);
+
+    set_caller(accounts(1));
+    atlas.update_deposit_minted(BTC_TXN_HASH.to_string(), MINTED_TXN_HASH.to_string());
+
+    set_caller(accounts(4));
+    atlas.claim_validator_rewards_near(accounts(4));
+
+    // 125 of the 200 funded is claimed, the next 125 is not covered
+    set_caller(accounts(5));
+    atlas.claim_validator_rewards_near(accounts(5));
+}
+
+#[test]
+#[should_panic(expected = "No rewards to claim")]
+fn test_claim_without_rewards() {
+    let mut atlas = setup_atlas();
+    fund_reward_pool(&mut atlas, 1000);
+
+    set_caller(accounts(4));
+    atlas.claim_validator_rewards_near(accounts(4));
+}
+
+#[test]
+#[should_panic(expected = "Caller does not have the FeeManager role")]
+fn test_update_fee_validator_rewards_bps_unauthorized() {
+    let mut atlas = setup_atlas();
+
+    set_caller(accounts(4));
+    atlas.update_fee_validator_rewards_bps(100);
+}
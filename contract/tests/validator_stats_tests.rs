@@ -0,0 +1,141 @@
+use atlas_protocol::constants::status::*;
+use atlas_protocol::modules::structs::{Atlas, DepositRecord};
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::testing_env;
+
+const NANOS: u64 = 1_000_000_000;
+const START: u64 = 1_700_000_000;
+
+fn set_caller_at(account_index: usize, timestamp: u64) {
+    let mut context = VMContextBuilder::new();
+    context
+        .predecessor_account_id(accounts(account_index))
+        .block_timestamp(timestamp * NANOS);
+    testing_env!(context.build());
+}
+
+fn deposit_record(btc_txn_hash: &str) -> DepositRecord {
+    DepositRecord {
+        btc_txn_hash: btc_txn_hash.to_string(),
+        btc_sender_address: "tb1qsender".to_string(),
+        receiving_chain_id: "421614".to_string(),
+        receiving_address: "0xf8b5b7c7c8f6a1b1f1f1f1f1f1f1f1f1f1f1f1f1".to_string(),
+        btc_amount: 10000,
+        fee_amount: 0,
+        minted_txn_hash: "".to_string(),
+        timestamp: 1234567890,
+        status: DEP_BTC_DEPOSITED_INTO_ATLAS,
+        remarks: "".to_string(),
+        date_created: 1234567890,
+        verified_count: 0,
+        retry_count: 0,
+        minted_txn_hash_verified_count: 0,
+        custody_txn_id: "".to_string(),
+    }
+}
+
+// accounts(4) votes on both deposits, accounts(5) on the first one only, accounts(6) never votes
+fn setup_atlas() -> Atlas {
+    set_caller_at(0, START);
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    for validator in [4, 5] {
+        atlas.add_validator(accounts(validator), "SIGNET".to_string());
+    }
+    atlas.add_validator("frank".parse().unwrap(), "SIGNET".to_string());
+
+    for btc_txn_hash in ["btc_txn_hash_1", "btc_txn_hash_2"] {
+        atlas
+            .deposits
+            .insert(btc_txn_hash.to_string(), deposit_record(btc_txn_hash));
+    }
+
+    set_caller_at(4, START + 10);
+    assert!(atlas.increment_deposit_verified_count(deposit_record("btc_txn_hash_1")));
+    assert!(atlas.increment_deposit_verified_count(deposit_record("btc_txn_hash_2")));
+
+    set_caller_at(5, START + 20);
+    assert!(atlas.increment_deposit_verified_count(deposit_record("btc_txn_hash_1")));
+
+    atlas
+}
+
+#[test]
+fn test_validator_stats() {
+    let atlas = setup_atlas();
+
+    let stats = atlas
+        .get_validator_stats(accounts(4), "SIGNET".to_string())
+        .unwrap();
+    assert_eq!(stats.vote_count, 2);
+    assert_eq!(stats.first_vote_timestamp, START + 10);
+    assert_eq!(stats.last_vote_timestamp, START + 10);
+
+    assert!(atlas
+        .get_validator_stats("frank".parse().unwrap(), "SIGNET".to_string())
+        .is_none());
+}
+
+#[test]
+fn test_validator_participation() {
+    let atlas = setup_atlas();
+
+    let participation = atlas.get_validator_participation("SIGNET".to_string(), 3600);
+    let rate_of = |account_id: &str| {
+        participation
+            .iter()
+            .find(|entry| entry.account_id.as_str() == account_id)
+            .unwrap()
+            .participation_bps
+    };
+
+    assert!(participation.iter().all(|entry| entry.record_count == 2));
+    assert_eq!(rate_of(accounts(4).as_str()), 10000);
+    assert_eq!(rate_of(accounts(5).as_str()), 5000);
+    assert_eq!(rate_of("frank"), 0);
+}
+
+#[test]
+fn test_get_inactive_validators() {
+    let atlas = setup_atlas();
+
+    set_caller_at(0, START + 100);
+    let mut inactive = atlas.get_inactive_validators("SIGNET".to_string(), 85);
+    inactive.sort();
+    assert_eq!(inactive, vec![accounts(4), "frank".parse().unwrap()]);
+}
+
+#[test]
+fn test_vote_buckets_are_reused_after_retention() {
+    let mut atlas = setup_atlas();
+    let bucket_count = atlas.vote_buckets.len();
+
+    // 30 days later the votes land in the slots of the expired buckets
+    atlas
+        .deposits
+        .insert("btc_txn_hash_3".to_string(), deposit_record("btc_txn_hash_3"));
+    set_caller_at(4, START + 30 * 24 * 60 * 60 + 10);
+    assert!(atlas.increment_deposit_verified_count(deposit_record("btc_txn_hash_3")));
+
+    assert_eq!(atlas.vote_buckets.len(), bucket_count);
+
+    let participation = atlas.get_validator_participation("SIGNET".to_string(), 3600);
+    assert!(participation.iter().all(|entry| entry.record_count == 1));
+    let vote_count_of = |account_id: &str| {
+        participation
+            .iter()
+            .find(|entry| entry.account_id.as_str() == account_id)
+            .unwrap()
+            .vote_count
+    };
+    assert_eq!(vote_count_of(accounts(4).as_str()), 1);
+    assert_eq!(vote_count_of(accounts(5).as_str()), 0);
+}
@@ -0,0 +1,153 @@
+use atlas_protocol::constants::status::*;
+use atlas_protocol::modules::structs::{Atlas, DepositRecord};
+use near_sdk::test_utils::{accounts, get_logs, VMContextBuilder};
+use near_sdk::testing_env;
+
+fn set_caller(account_index: usize) {
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(accounts(account_index));
+    testing_env!(context.build());
+}
+
+fn mempool_deposit() -> DepositRecord {
+    DepositRecord {
+        btc_txn_hash: "btc_txn_hash".to_string(),
+        btc_sender_address: "tb1qsender".to_string(),
+        receiving_chain_id: "421614".to_string(),
+        receiving_address: "0xf8b5b7c7c8f6a1b1f1f1f1f1f1f1f1f1f1f1f1f1".to_string(),
+        btc_amount: 10000,
+        fee_amount: 0,
+        minted_txn_hash: "".to_string(),
+        timestamp: 1234567890,
+        status: DEP_BTC_DEPOSITED_INTO_ATLAS,
+        remarks: "".to_string(),
+        date_created: 1234567890,
+        verified_count: 0,
+        retry_count: 0,
+        minted_txn_hash_verified_count: 0,
+        custody_txn_id: "".to_string(),
+    }
+}
+
+// Deposit verified by validators accounts(4) and accounts(5)
+fn setup_atlas() -> Atlas {
+    set_caller(0);
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    atlas.add_validator(accounts(4), "SIGNET".to_string());
+    atlas.add_validator(accounts(5), "SIGNET".to_string());
+    atlas
+        .deposits
+        .insert("btc_txn_hash".to_string(), mempool_deposit());
+
+    for validator in [4, 5] {
+        set_caller(validator);
+        assert!(atlas.increment_deposit_verified_count(mempool_deposit()));
+    }
+
+    atlas
+}
+
+#[test]
+fn test_validator_votes_are_indexed() {
+    let atlas = setup_atlas();
+
+    let votes = atlas.get_validator_votes(accounts(4));
+    assert_eq!(votes.len(), 1);
+    assert_eq!(votes[0].vote_type, "deposit_verified");
+    assert_eq!(votes[0].verification_key, "btc_txn_hash");
+    assert_eq!(votes[0].chain_id, "SIGNET");
+    assert_eq!(votes[0].weight, 1);
+}
+
+#[test]
+fn test_remove_validator_revokes_pending_votes() {
+    let mut atlas = setup_atlas();
+
+    set_caller(0);
+    atlas.remove_validator(accounts(4), "SIGNET".to_string());
+
+    let deposit = atlas
+        .get_deposit_by_btc_txn_hash("btc_txn_hash".to_string())
+        .unwrap();
+    assert_eq!(deposit.verified_count, 1);
+    assert_eq!(
+        atlas.get_validators_by_txn_hash("btc_txn_hash".to_string()),
+        vec![accounts(5)]
+    );
+    assert_eq!(atlas.get_verified_weight("btc_txn_hash".to_string()), 1);
+    assert!(atlas.get_validator_votes(accounts(4)).is_empty());
+
+    assert!(get_logs()
+        .iter()
+        .any(|log| log.starts_with("EVENT_JSON:") && log.contains("\"event\":\"vote_revoked\"")));
+}
+
+#[test]
+fn test_remove_validator_keeps_votes_on_finished_records() {
+    let mut atlas = setup_atlas();
+
+    let mut deposit = atlas
+        .get_deposit_by_btc_txn_hash("btc_txn_hash".to_string())
+        .unwrap();
+    deposit.status = DEP_BTC_MINTED_INTO_ABTC;
+    atlas.deposits.insert("btc_txn_hash".to_string(), deposit);
+
+    set_caller(0);
+    atlas.remove_validator(accounts(4), "SIGNET".to_string());
+
+    let deposit = atlas
+        .get_deposit_by_btc_txn_hash("btc_txn_hash".to_string())
+        .unwrap();
+    assert_eq!(deposit.verified_count, 2);
+    assert_eq!(
+        atlas.get_validators_by_txn_hash("btc_txn_hash".to_string()),
+        vec![accounts(4), accounts(5)]
+    );
+}
+
+#[test]
+fn test_prune_validator_votes() {
+    let mut atlas = setup_atlas();
+
+    let mut deposit = atlas
+        .get_deposit_by_btc_txn_hash("btc_txn_hash".to_string())
+        .unwrap();
+    deposit.status = DEP_BTC_MINTED_INTO_ABTC;
+    atlas.deposits.insert("btc_txn_hash".to_string(), deposit);
+
+    atlas.prune_validator_votes(accounts(4));
+    assert!(atlas.get_validator_votes(accounts(4)).is_empty());
+}
+
+#[test]
+fn test_new_vote_prunes_votes_on_finished_records() {
+    let mut atlas = setup_atlas();
+
+    let mut deposit = atlas
+        .get_deposit_by_btc_txn_hash("btc_txn_hash".to_string())
+        .unwrap();
+    deposit.status = DEP_BTC_MINTED_INTO_ABTC;
+    atlas.deposits.insert("btc_txn_hash".to_string(), deposit);
+
+    let mut other_deposit = mempool_deposit();
+    other_deposit.btc_txn_hash = "other_btc_txn_hash".to_string();
+    atlas
+        .deposits
+        .insert("other_btc_txn_hash".to_string(), other_deposit.clone());
+
+    set_caller(4);
+    assert!(atlas.increment_deposit_verified_count(other_deposit));
+
+    let votes = atlas.get_validator_votes(accounts(4));
+    assert_eq!(votes.len(), 1);
+    assert_eq!(votes[0].record_key, "other_btc_txn_hash");
+}
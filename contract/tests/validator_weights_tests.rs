@@ -0,0 +1,108 @@
+use atlas_protocol::constants::status::*;
+use atlas_protocol::modules::structs::{Atlas, RedemptionRecord};
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::testing_env;
+
+const CHAIN_ID: &str = "421614";
+
+fn set_caller(account_index: usize) {
+    let mut context = VMContextBuilder::new();
+    context.predecessor_account_id(accounts(account_index));
+    testing_env!(context.build());
+}
+
+fn setup_atlas() -> (Atlas, String) {
+    set_caller(0);
+
+    let mut atlas = Atlas::new(
+        accounts(0),
+        accounts(1),
+        accounts(2),
+        accounts(3),
+        "treasury_address".to_string(),
+        false,
+    );
+
+    atlas.add_validator(accounts(4), CHAIN_ID.to_string());
+    atlas.add_validator(accounts(5), CHAIN_ID.to_string());
+    atlas.set_validator_weight(accounts(4), CHAIN_ID.to_string(), 3);
+
+    let txn_hash = format!("{},0xburn", CHAIN_ID);
+    set_caller(1);
+    atlas.insert_redemption_abtc(
+        txn_hash.clone(),
+        "0xf8b5b7c7c8f6a1b1f1f1f1f1f1f1f1f1f1f1f1f1".to_string(),
+        CHAIN_ID.to_string(),
+        "tb1qreceiver".to_string(),
+        5000,
+        1234567890,
+        1234567890,
+    );
+
+    (atlas, txn_hash)
+}
+
+fn verify_redemption(atlas: &mut Atlas, account_index: usize, txn_hash: &str) {
+    let redemption: RedemptionRecord = atlas
+        .get_redemption_by_txn_hash(txn_hash.to_string())
+        .unwrap();
+
+    set_caller(account_index);
+    assert!(atlas.increment_redemption_verified_count(redemption));
+}
+
+#[test]
+fn test_validator_weight_defaults_to_one() {
+    let (atlas, _) = setup_atlas();
+
+    assert_eq!(atlas.get_validator_weight(accounts(4), CHAIN_ID.to_string()), 3);
+    assert_eq!(atlas.get_validator_weight(accounts(5), CHAIN_ID.to_string()), 1);
+    assert_eq!(atlas.get_total_validator_weight(CHAIN_ID.to_string()), 4);
+}
+
+#[test]
+fn test_fraction_weight_threshold() {
+    let (mut atlas, txn_hash) = setup_atlas();
+
+    set_caller(0);
+    atlas.set_chain_weight_threshold(CHAIN_ID.to_string(), "fraction".to_string(), 7000);
+    assert_eq!(atlas.get_required_verified_weight(CHAIN_ID.to_string()), Some(3));
+
+    // A single heavy validator carries the threshold
+    verify_redemption(&mut atlas, 4, &txn_hash);
+    assert_eq!(atlas.get_verified_weight(txn_hash.clone()), 3);
+
+    set_caller(1);
+    atlas.update_redemption_start(txn_hash.clone());
+    let redemption = atlas.get_redemption_by_txn_hash(txn_hash).unwrap();
+    assert_eq!(
+        redemption.status,
+        RED_BTC_PENDING_REDEMPTION_FROM_ATLAS_TO_USER
+    );
+}
+
+#[test]
+fn test_absolute_weight_threshold_not_met() {
+    let (mut atlas, txn_hash) = setup_atlas();
+
+    set_caller(0);
+    atlas.set_chain_weight_threshold(CHAIN_ID.to_string(), "absolute".to_string(), 3);
+
+    // A single community validator does not reach the weight threshold
+    verify_redemption(&mut atlas, 5, &txn_hash);
+    assert_eq!(atlas.get_verified_weight(txn_hash.clone()), 1);
+
+    set_caller(1);
+    atlas.update_redemption_start(txn_hash.clone());
+    let redemption = atlas.get_redemption_by_txn_hash(txn_hash).unwrap();
+    assert_eq!(redemption.status, RED_ABTC_BURNT);
+}
+
+#[test]
+#[should_panic(expected = "Fraction threshold cannot exceed 10000 basis points")]
+fn test_fraction_weight_threshold_too_large() {
+    let (mut atlas, _) = setup_atlas();
+
+    set_caller(0);
+    atlas.set_chain_weight_threshold(CHAIN_ID.to_string(), "fraction".to_string(), 10001);
+}
@@ -28,6 +28,13 @@ fn setup_atlas() -> Atlas {
     atlas.add_validator(accounts(4), "11155420".to_string());
     atlas.add_validator(accounts(5), "11155420".to_string());
 
+    for validator in [accounts(4), accounts(5)] {
+        let mut context = VMContextBuilder::new();
+        context.predecessor_account_id(validator);
+        testing_env!(context.build());
+        atlas.verify_evm_nonce("421614".to_string(), 0);
+    }
+
     atlas
 }
 
@@ -258,7 +265,7 @@ fn setup_withdraw_fail_deposit(btc_amount: u64) -> (Atlas, WithDrawFailDepositRe
         .build());
 
     // first retry
-    atlas.create_mint_abtc_signed_tx(deposit.btc_txn_hash.clone(), 94, 5000000, 100000000, 0);
+    atlas.create_mint_abtc_signed_tx(deposit.btc_txn_hash.clone(), 5000000, Some(100000000), Some(0));
     atlas.update_deposit_remarks(
         btc_txn_hash.clone(),
         "oops, something went wrong at first try".to_string(),
@@ -266,7 +273,7 @@ fn setup_withdraw_fail_deposit(btc_amount: u64) -> (Atlas, WithDrawFailDepositRe
     atlas.rollback_deposit_status_by_btc_txn_hash(btc_txn_hash.clone());
 
     // second retry
-    atlas.create_mint_abtc_signed_tx(deposit.btc_txn_hash.clone(), 94, 5000000, 100000000, 0);
+    atlas.create_mint_abtc_signed_tx(deposit.btc_txn_hash.clone(), 5000000, Some(100000000), Some(0));
     atlas.update_deposit_remarks(
         btc_txn_hash.clone(),
         "oops, something went wrong at second try".to_string(),